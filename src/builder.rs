@@ -9,21 +9,34 @@
 //! `ruarango` connection builder
 
 use crate::{
-    conn::Connection as Conn,
+    conn::{Connection as Conn, Credentials, SettingsBuilder},
     error::RuarangoErr::InvalidConnectionUrl,
+    hook::{OnRequest, OnResponse},
     model::{auth::input::AuthBuilder, auth::output::AuthResponse},
-    utils::handle_response,
+    utils::{handle_response, DEFAULT_MAX_ERROR_BODY_LEN},
+    vpack,
 };
 use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
 use derive_builder::Builder;
 use futures::future::FutureExt;
+#[cfg(feature = "tls")]
+use reqwest::Certificate;
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, AUTHORIZATION},
-    ClientBuilder, Url,
+    Client, ClientBuilder, Url,
 };
+use serde::Serialize;
+use std::time::Duration;
+
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+const DEFAULT_MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+const JSON_MIME: &str = "application/json";
+pub(crate) const VELOCYPACK_MIME: &str = "application/x-velocypack";
 
 /// The kind of asynchronouse request you would like to make
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub enum AsyncKind {
     /// This will add the HTTP header `x-arango-async: true` to client requests
     /// and `ArangoDB` will put the request into an in-memory task queue and return an
@@ -33,12 +46,52 @@ pub enum AsyncKind {
     /// Clients can instruct the `ArangoDB` server to execute the operation
     /// asynchronously as with [`FireAndForget`](Self::FireAndForget), but also
     /// store the operation result in memory for a later retrieval.
+    #[default]
     Store,
 }
 
-impl Default for AsyncKind {
-    fn default() -> Self {
-        Self::Store
+/// How the connection authenticates with `ArangoDB`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AuthKind {
+    /// Exchange the username/password for a JWT via `/_open/auth`, and send
+    /// it as a bearer token on every request
+    #[default]
+    Jwt,
+    /// Send the username/password via HTTP Basic authentication on every
+    /// request.  Use this when `/_open/auth` is unavailable, i.e. behind a
+    /// proxy.
+    Basic,
+}
+
+/// The format of request and response bodies
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ContentType {
+    /// Send and accept `application/json` bodies
+    #[default]
+    Json,
+    /// Send and accept `application/x-velocypack` bodies, `ArangoDB`'s
+    /// binary format.  Authentication is still done with JSON.
+    VelocyPack,
+}
+
+impl ContentType {
+    /// The mime type sent in the `Content-Type` and `Accept` headers
+    pub(crate) fn mime(self) -> &'static str {
+        match self {
+            Self::Json => JSON_MIME,
+            Self::VelocyPack => VELOCYPACK_MIME,
+        }
+    }
+
+    /// Encode a request body in this format
+    pub(crate) fn encode<T>(self, body: &T) -> Result<Bytes>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self {
+            Self::Json => Ok(serde_json::to_vec(body)?.into()),
+            Self::VelocyPack => Ok(vpack::encode(&serde_json::to_value(body)?).into()),
+        }
     }
 }
 
@@ -52,18 +105,219 @@ pub struct Connection {
     /// The url used to connect to `ArangoDB`
     #[builder(setter(into))]
     url: String,
+    /// A path prefix the server is mounted under, i.e. `arango` when
+    /// `ArangoDB` sits behind a reverse proxy at `https://host/arango/`.
+    /// All requests, including authentication, are made below this prefix.
+    #[builder(setter(into, strip_option), default)]
+    base_path: Option<String>,
     /// An optional username, defaults to 'root'
     #[builder(setter(into, strip_option), default)]
     username: Option<String>,
     /// An optional password, defaults to ''
     #[builder(setter(into, strip_option), default)]
     password: Option<String>,
+    /// A JWT to send as the bearer token on every request, i.e. a superuser
+    /// token signed with the server JWT secret.  When set, the
+    /// username/password and [`auth_kind`](Self::auth_kind) are ignored and
+    /// `/_open/auth` is never called.  As there are no credentials to
+    /// re-authenticate with, a request rejected with a `401 Unauthorized`
+    /// results in a [`JwtRejected`](crate::Error::JwtRejected) error.
+    #[builder(setter(into, strip_option), default)]
+    jwt: Option<String>,
     /// An optional database to use, defaults to '' which will target the '_system' database
     #[builder(setter(into, strip_option), default)]
     database: Option<String>,
     /// Make this request asynchronously
     #[builder(setter(strip_option), default)]
     async_kind: Option<AsyncKind>,
+    /// How to authenticate with `ArangoDB` (default: [`Jwt`](AuthKind::Jwt))
+    #[builder(setter(strip_option), default)]
+    auth_kind: Option<AuthKind>,
+    /// Automatically re-authenticate and retry once when a request fails
+    /// with a `401 Unauthorized`, i.e. when the JWT has expired (default: true).
+    /// This has no effect with [`Basic`](AuthKind::Basic) authentication.
+    #[builder(setter(strip_option), default)]
+    auto_reauth: Option<bool>,
+    /// Allow all GET requests to be served by followers in cluster or
+    /// active-failover setups by adding the `x-arango-allow-dirty-read`
+    /// header, trading consistency for availability (default: false).
+    ///
+    /// This can be overridden per request, i.e. via
+    /// [`allow_dirty_read`](crate::doc::input::ReadConfigBuilder::allow_dirty_read).
+    #[builder(setter(strip_option), default)]
+    dirty_reads: Option<bool>,
+    /// Memoize the first successful [`current`](crate::Database::current)
+    /// response and serve it for every later call, saving a round-trip
+    /// (default: false).  The cache is cleared with
+    /// [`invalidate_current_cache`](crate::Connection::invalidate_current_cache).
+    #[builder(setter(strip_option), default)]
+    cache_current: Option<bool>,
+    /// The maximum number of times to retry idempotent requests (GET, HEAD, PUT
+    /// and DELETE) that fail with a connection error or a `502`/`503` (default: 0).
+    /// Timeouts are not retried, as the server may have applied the request.
+    ///
+    /// POST requests are never retried to avoid duplicate inserts.  Neither are
+    /// the PUTs that move server side state forward, i.e.
+    /// [`Cursor::next`](crate::Cursor::next) and
+    /// [`Job::fetch`](crate::Job::fetch).
+    #[builder(setter(strip_option), default)]
+    max_retries: Option<u32>,
+    /// The backoff before the first retry, doubled on each subsequent retry.
+    /// A `Retry-After` header on the response takes precedence, up to
+    /// `max_retry_after` (default: 100ms).
+    #[builder(setter(strip_option), default)]
+    retry_backoff: Option<Duration>,
+    /// The longest delay honored from a `Retry-After` header.  Larger values
+    /// are capped to this, so a proxy asking for a day does not park the
+    /// request for that long (default: 30s).
+    #[builder(setter(strip_option), default)]
+    max_retry_after: Option<Duration>,
+    /// Headers to add to every request, i.e. tracing ids.  Headers set on an
+    /// individual request take precedence over these.
+    #[builder(setter(strip_option), default)]
+    headers: Option<HeaderMap>,
+    /// The maximum time a request may wait in the server's queue before the
+    /// server rejects it, sent via the `x-arango-queue-time-seconds` header.
+    /// A rejected request results in a
+    /// [`QueueTimeViolation`](crate::Error::QueueTimeViolation) error.
+    ///
+    /// This can be overridden with
+    /// [`with_max_queue_time`](crate::Connection::with_max_queue_time).
+    #[builder(setter(strip_option), default)]
+    max_queue_time: Option<Duration>,
+    /// The maximum number of bytes of a raw response body kept in an error
+    /// when the body could not be deserialized, i.e. in
+    /// [`InvalidBody`](crate::Error::InvalidBody) (default: 1024).
+    #[builder(setter(strip_option), default)]
+    max_error_body_len: Option<usize>,
+    /// The maximum number of bytes in the body of a batched write, i.e.
+    /// [`creates`](crate::Document::creates) or [`import`](crate::Document::import)
+    /// (default: unlimited).  A larger body is not sent, and results in a
+    /// [`RequestBodyTooLarge`](crate::Error::RequestBodyTooLarge) error
+    /// instead, so the batch can be split up before the server rejects it.
+    #[builder(setter(strip_option), default)]
+    max_request_body_bytes: Option<usize>,
+    /// A hook called with the method and url of every request before it is
+    /// sent.  The initial authentication request is not included.
+    #[builder(setter(strip_option), default)]
+    on_request: Option<OnRequest>,
+    /// A hook called with the method, url, status and duration of every
+    /// request once it completes.  The initial authentication request is not
+    /// included.
+    #[builder(setter(strip_option), default)]
+    on_response: Option<OnResponse>,
+    /// Additional root certificates to trust, i.e. the certificate of a
+    /// private CA that signed the `ArangoDB` server certificate.
+    #[cfg(feature = "tls")]
+    #[builder(setter(each(name = "add_root_certificate")), default)]
+    root_certificates: Vec<Certificate>,
+    /// Accept invalid server certificates (default: false).
+    ///
+    /// # Warning
+    /// **You should think very carefully before using this!**  Any
+    /// certificate for any site will be trusted, including expired and
+    /// self-signed certificates.  This leaves the connection, including the
+    /// credentials it sends, open to man-in-the-middle attacks.  Only use this
+    /// for local testing, and prefer
+    /// [`add_root_certificate`](Self::add_root_certificate) otherwise.
+    #[cfg(feature = "tls")]
+    #[builder(setter(strip_option), default)]
+    danger_accept_invalid_certs: Option<bool>,
+    /// The maximum number of idle keep-alive connections kept per host
+    /// (default: unlimited).
+    ///
+    /// Idle connections are only reused by the same [`Connection`](crate::Connection)
+    /// and its clones, so build one connection and share it rather than
+    /// building a new one per task.
+    #[builder(setter(strip_option), default)]
+    pool_max_idle_per_host: Option<usize>,
+    /// How long an idle keep-alive connection is kept open (default: 90s).
+    #[builder(setter(strip_option), default)]
+    pool_idle_timeout: Option<Duration>,
+    /// Ask the server to compress responses, advertising the encodings
+    /// enabled by the `gzip` and `deflate` features via `Accept-Encoding`,
+    /// and decompress them transparently (default: false).
+    ///
+    /// Compression trades CPU time on both ends for fewer bytes on the wire.
+    /// It pays off for large responses, i.e. big cursor batches or
+    /// collection listings, over a slow or metered link, but mostly adds
+    /// latency for small responses or a server on the same host.
+    #[cfg(any(feature = "gzip", feature = "deflate"))]
+    #[builder(setter(strip_option), default)]
+    compression: Option<bool>,
+    /// The format of request and response bodies (default: [`Json`](ContentType::Json)).
+    ///
+    /// [`VelocyPack`](ContentType::VelocyPack) is more compact and cheaper
+    /// for the server to handle, which helps high throughput workloads.
+    /// Responses are decoded based on the `Content-Type` the server sends,
+    /// so endpoints that only answer with JSON keep working.
+    #[builder(setter(strip_option), default)]
+    content_type: Option<ContentType>,
+}
+
+/// Settings applied to every client the connection builds
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClientOptions {
+    #[cfg(feature = "tls")]
+    root_certificates: Vec<Certificate>,
+    #[cfg(feature = "tls")]
+    danger_accept_invalid_certs: bool,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    #[cfg(any(feature = "gzip", feature = "deflate"))]
+    compression: bool,
+    content_type: ContentType,
+}
+
+impl ClientOptions {
+    pub(crate) fn content_type(&self) -> ContentType {
+        self.content_type
+    }
+
+    #[cfg(feature = "tls")]
+    fn apply_tls(&self, builder: ClientBuilder) -> ClientBuilder {
+        // Fall back to ring if the application hasn't installed a default
+        // rustls crypto provider
+        #[cfg(feature = "rustls")]
+        let builder = {
+            let _ = rustls::crypto::ring::default_provider().install_default();
+            builder.use_rustls_tls()
+        };
+
+        self.root_certificates
+            .iter()
+            .cloned()
+            .fold(builder, ClientBuilder::add_root_certificate)
+            .danger_accept_invalid_certs(self.danger_accept_invalid_certs)
+    }
+
+    #[cfg(not(feature = "tls"))]
+    #[allow(clippy::unused_self)]
+    fn apply_tls(&self, builder: ClientBuilder) -> ClientBuilder {
+        builder
+    }
+
+    fn client_builder(&self, headers: HeaderMap) -> ClientBuilder {
+        let mut builder = self.apply_tls(ClientBuilder::new().default_headers(headers));
+
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+        // Set explicitly, as reqwest enables each encoding by default when
+        // its feature is on
+        #[cfg(feature = "gzip")]
+        {
+            builder = builder.gzip(self.compression);
+        }
+        #[cfg(feature = "deflate")]
+        {
+            builder = builder.deflate(self.compression);
+        }
+        builder
+    }
 }
 
 impl ConnectionBuilder {
@@ -72,21 +326,20 @@ impl ConnectionBuilder {
     /// # Errors
     /// An invalid url will cause the build to error.
     pub async fn build(self) -> Result<Conn> {
-        let mut headers = HeaderMap::new();
-        let _old = headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-
-        // Setup the client to grab a JWT
-        let tmp_client = ClientBuilder::new()
-            .default_headers(headers.clone())
-            .build()
-            .with_context(|| "Unable to build the JWT client")?;
-
-        // Generate the auth url
+        // Generate the base url
         let url = self.url.ok_or(InvalidConnectionUrl)?;
-        let base_url = Url::parse(&url).with_context(|| "Unable to parse the base url")?;
-        let auth_url = base_url
-            .join("_open/auth")
-            .with_context(|| "Unable to parse the auth url")?;
+        let mut base_url = Url::parse(&url).with_context(|| "Unable to parse the base url")?;
+
+        // Mount everything below the path prefix if necessary.  The trailing
+        // slash ensures the prefix is kept when the api suffixes are joined.
+        if let Some(Some(base_path)) = self.base_path {
+            let base_path = base_path.trim_matches('/');
+            if !base_path.is_empty() {
+                base_url = base_url
+                    .join(&format!("{base_path}/"))
+                    .with_context(|| "Unable to join the base path")?;
+            }
+        }
 
         // Make the request with the given username/password
         let username = self
@@ -94,17 +347,29 @@ impl ConnectionBuilder {
             .unwrap_or_else(|| Some("root".to_string()))
             .unwrap_or_default();
         let password = self.password.unwrap_or_default().unwrap_or_default();
-        let auth_res: AuthResponse = tmp_client
-            .post(auth_url)
-            .json(
-                &AuthBuilder::default()
-                    .username(username)
-                    .password(password)
-                    .build()?,
-            )
-            .send()
-            .then(handle_response)
-            .await?;
+        let auth_kind = self.auth_kind.unwrap_or_default().unwrap_or_default();
+        let client_options = ClientOptions {
+            #[cfg(feature = "tls")]
+            root_certificates: self.root_certificates.unwrap_or_default(),
+            #[cfg(feature = "tls")]
+            danger_accept_invalid_certs: self
+                .danger_accept_invalid_certs
+                .unwrap_or_default()
+                .unwrap_or_default(),
+            pool_max_idle_per_host: self.pool_max_idle_per_host.unwrap_or_default(),
+            pool_idle_timeout: self.pool_idle_timeout.unwrap_or_default(),
+            #[cfg(any(feature = "gzip", feature = "deflate"))]
+            compression: self.compression.unwrap_or_default().unwrap_or_default(),
+            content_type: self.content_type.unwrap_or_default().unwrap_or_default(),
+        };
+        let preset_jwt = self.jwt.unwrap_or_default();
+        let authorization = match (&preset_jwt, auth_kind) {
+            (Some(jwt), _) => bearer(jwt),
+            (None, AuthKind::Jwt) => {
+                bearer(&authenticate(&base_url, &username, &password, &client_options).await?)
+            }
+            (None, AuthKind::Basic) => basic(&username, &password),
+        };
 
         // Setup the db prefix if necessary
         let db_url = if let Some(Some(db)) = self.database {
@@ -113,49 +378,169 @@ impl ConnectionBuilder {
             base_url.clone()
         };
 
-        // Add any default headers
-        let bearer = format!("bearer {}", auth_res.jwt());
-        let _old = headers.insert(AUTHORIZATION, HeaderValue::from_bytes(bearer.as_bytes())?);
-
-        let mut is_async = false;
-        let mut async_headers = headers.clone();
-        if let Some(Some(async_kind)) = self.async_kind {
-            is_async = true;
-            match async_kind {
-                AsyncKind::FireAndForget => {
-                    let _old = async_headers.insert(
-                        HeaderName::from_static("x-arango-async"),
-                        HeaderValue::from_static("true"),
-                    );
-                }
-                AsyncKind::Store => {
-                    let _old = async_headers.insert(
-                        HeaderName::from_static("x-arango-async"),
-                        HeaderValue::from_static("store"),
-                    );
-                }
+        // Setup the clients
+        let async_kind = self.async_kind.unwrap_or_default();
+        let clients = build_clients(&authorization, async_kind, &client_options)?;
+
+        // Keep the credentials around if we should re-authenticate on expiry
+        let credentials = if preset_jwt.is_none()
+            && auth_kind == AuthKind::Jwt
+            && self.auto_reauth.unwrap_or_default().unwrap_or(true)
+        {
+            Some(Credentials::new(username, password))
+        } else {
+            None
+        };
+
+        let settings = SettingsBuilder::default()
+            .async_kind(async_kind)
+            .credentials(credentials)
+            .preset_jwt(preset_jwt.is_some())
+            .dirty_reads(self.dirty_reads.unwrap_or_default().unwrap_or_default())
+            .cache_current(self.cache_current.unwrap_or_default().unwrap_or_default())
+            .max_retries(self.max_retries.unwrap_or_default().unwrap_or_default())
+            .retry_backoff(
+                self.retry_backoff
+                    .unwrap_or_default()
+                    .unwrap_or(DEFAULT_RETRY_BACKOFF),
+            )
+            .max_retry_after(
+                self.max_retry_after
+                    .unwrap_or_default()
+                    .unwrap_or(DEFAULT_MAX_RETRY_AFTER),
+            )
+            .headers(self.headers.unwrap_or_default().unwrap_or_default())
+            .max_queue_time(self.max_queue_time.unwrap_or_default())
+            .max_error_body_len(
+                self.max_error_body_len
+                    .unwrap_or_default()
+                    .unwrap_or(DEFAULT_MAX_ERROR_BODY_LEN),
+            )
+            .max_request_body_bytes(self.max_request_body_bytes.unwrap_or_default())
+            .on_request(self.on_request.unwrap_or_default())
+            .on_response(self.on_response.unwrap_or_default())
+            .client_options(client_options)
+            .build()?;
+
+        Ok(Conn::new(base_url, db_url, clients, settings))
+    }
+}
+
+fn default_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    let _old = headers.insert(ACCEPT, HeaderValue::from_static(JSON_MIME));
+    headers
+}
+
+/// Grab a JWT from `ArangoDB` with the given username/password
+pub(crate) async fn authenticate(
+    base_url: &Url,
+    username: &str,
+    password: &str,
+    client_options: &ClientOptions,
+) -> Result<String> {
+    // Setup the client to grab a JWT
+    let tmp_client = client_options
+        .client_builder(default_headers())
+        .build()
+        .with_context(|| "Unable to build the JWT client")?;
+
+    // Generate the auth url
+    let auth_url = base_url
+        .join("_open/auth")
+        .with_context(|| "Unable to parse the auth url")?;
+
+    let auth_res: AuthResponse = tmp_client
+        .post(auth_url)
+        .json(
+            &AuthBuilder::default()
+                .username(username.to_string())
+                .password(password.to_string())
+                .build()?,
+        )
+        .send()
+        .then(handle_response)
+        .await?;
+
+    Ok(auth_res.jwt().clone())
+}
+
+/// The `Authorization` header value for the given JWT
+pub(crate) fn bearer(jwt: &str) -> String {
+    format!("bearer {jwt}")
+}
+
+/// The `Authorization` header value for HTTP Basic authentication
+fn basic(username: &str, password: &str) -> String {
+    format!(
+        "Basic {}",
+        STANDARD.encode(format!("{username}:{password}"))
+    )
+}
+
+/// Build the sync and async clients, authorized with the given `Authorization`
+/// header value
+pub(crate) fn build_clients(
+    authorization: &str,
+    async_kind: Option<AsyncKind>,
+    client_options: &ClientOptions,
+) -> Result<(Client, Client)> {
+    // Add any default headers
+    let mut headers = default_headers();
+    let _old = headers.insert(
+        ACCEPT,
+        HeaderValue::from_static(client_options.content_type.mime()),
+    );
+    let mut authorization = HeaderValue::from_bytes(authorization.as_bytes())?;
+    authorization.set_sensitive(true);
+    let _old = headers.insert(AUTHORIZATION, authorization);
+
+    let mut async_headers = headers.clone();
+    if let Some(async_kind) = async_kind {
+        match async_kind {
+            AsyncKind::FireAndForget => {
+                let _old = async_headers.insert(
+                    HeaderName::from_static("x-arango-async"),
+                    HeaderValue::from_static("true"),
+                );
+            }
+            AsyncKind::Store => {
+                let _old = async_headers.insert(
+                    HeaderName::from_static("x-arango-async"),
+                    HeaderValue::from_static("store"),
+                );
             }
         }
+    }
 
-        // Setup the client
-        let client = ClientBuilder::new()
-            .default_headers(headers)
-            .build()
-            .with_context(|| "Unable to build the client")?;
+    // Setup the client
+    let client = client_options
+        .client_builder(headers)
+        .build()
+        .with_context(|| "Unable to build the client")?;
 
-        let async_client = ClientBuilder::new()
-            .default_headers(async_headers)
-            .build()
-            .with_context(|| "Unable to build the async_client")?;
+    let async_client = client_options
+        .client_builder(async_headers)
+        .build()
+        .with_context(|| "Unable to build the async_client")?;
 
-        Ok(Conn::new(base_url, db_url, client, async_client, is_async))
-    }
+    Ok((client, async_client))
 }
 
 #[cfg(test)]
 mod test {
-    use crate::utils::{default_conn, mock_auth};
-    use wiremock::MockServer;
+    use super::{AuthKind, ConnectionBuilder};
+    use crate::{
+        db::output::Current,
+        model::auth::output::AuthResponse,
+        utils::{default_conn, mock_auth},
+        Database,
+    };
+    use anyhow::Result;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
 
     #[tokio::test]
     async fn test_builder() {
@@ -163,4 +548,183 @@ mod test {
         mock_auth(&mock_server).await;
         assert!(default_conn(mock_server.uri()).await.is_ok());
     }
+
+    #[tokio::test]
+    async fn basic_auth() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/_open/auth"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            // root:test
+            .and(header("authorization", "Basic cm9vdDp0ZXN0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(crate::common::output::Response::<Current>::default()),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("test")
+            .database("keti")
+            .auth_kind(AuthKind::Basic)
+            .build()
+            .await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+        assert!(!res.right_safe()?.error());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn preset_jwt() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/_open/auth"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .and(header("authorization", "bearer a preset jwt"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(crate::common::output::Response::<Current>::default()),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .database("keti")
+            .jwt("a preset jwt")
+            .build()
+            .await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+        assert!(!res.right_safe()?.error());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn base_path() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        let body: AuthResponse = "not a real jwt".into();
+        Mock::given(method("POST"))
+            .and(path("/arango/_open/auth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/arango/_db/keti/_api/database/current"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(crate::common::output::Response::<Current>::default()),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .base_path("/arango/")
+            .username("root")
+            .password("")
+            .database("keti")
+            .build()
+            .await?;
+        assert_eq!(
+            format!("{}/arango/_db/keti/", mock_server.uri()),
+            conn.db_url().as_str()
+        );
+        let res = conn.current().await?;
+        assert!(!res.right_safe()?.error());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    #[tokio::test]
+    async fn root_certificate_builds() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+
+        let cert = reqwest::Certificate::from_pem(include_bytes!("../tests/data/ca.pem"))?;
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .add_root_certificate(cert)
+            .danger_accept_invalid_certs(false)
+            .build()
+            .await;
+        assert!(conn.is_ok());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn gzip_response_decodes() -> Result<()> {
+        use flate2::{write::GzEncoder, Compression};
+        use serde_json::json;
+        use std::io::Write;
+        use wiremock::matchers::header_regex;
+
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+
+        let body = json!({
+            "code": 200,
+            "error": false,
+            "result": {
+                "name": "keti",
+                "id": "123",
+                "isSystem": false,
+                "path": "/data/keti"
+            }
+        });
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body.to_string().as_bytes())?;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .and(header_regex("accept-encoding", "gzip"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .insert_header("content-type", "application/json")
+                    .set_body_bytes(encoder.finish()?),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .compression(true)
+            .build()
+            .await?;
+        let current = conn.current().await?.right_safe()?;
+        assert_eq!("keti", current.result().name());
+        assert_eq!("/data/keti", current.result().path());
+
+        Ok(())
+    }
 }
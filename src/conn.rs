@@ -8,24 +8,153 @@
 
 //! An `ArangoDB` connection implementing the database operation traits
 
-use anyhow::Result;
-use futures::{Future, FutureExt};
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use derive_builder::Builder;
+use futures::{lock::Mutex, Future};
 use getset::Getters;
 use libeither::Either;
-use reqwest::{header::HeaderMap, Client, Error, Response, Url};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER},
+    Client, Error, Method, Request, RequestBuilder, Response, StatusCode, Url,
+};
 use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+use tokio::time::sleep;
 
-use crate::{utils::handle_job_response, ArangoResult};
+use crate::{
+    builder::{authenticate, bearer, build_clients, AsyncKind, ClientOptions},
+    common::output::Response as BaseResponse,
+    db::output::Current,
+    error::RuarangoErr::{JwtRejected, RequestBodyTooLarge},
+    hook::{OnRequest, OnResponse, Trace},
+    model::{ALLOW_DIRTY_READ, QUEUE_TIME_SECONDS},
+    utils::{empty, handle_job_response, MaxErrorBodyLen},
+    ArangoResult,
+};
+
+/// The timeout applied to a [`ping`](Connection::ping)
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub(crate) enum HttpVerb {
     Delete,
     Get,
+    Head,
     Patch,
     Post,
     Put,
 }
 
+/// The credentials used to re-authenticate when a JWT has expired
+#[derive(Clone)]
+pub(crate) struct Credentials {
+    username: String,
+    password: String,
+}
+
+impl Credentials {
+    pub(crate) fn new(username: String, password: String) -> Self {
+        Self { username, password }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Connection wide settings, fixed at build time
+#[derive(Builder, Clone, Debug, Default, Getters)]
+#[getset(get = "pub(crate)")]
+pub(crate) struct Settings {
+    /// The kind of asynchronous requests to make, if any
+    #[builder(default)]
+    async_kind: Option<AsyncKind>,
+    /// The credentials used to re-authenticate, if enabled
+    #[builder(default)]
+    credentials: Option<Credentials>,
+    /// Was the connection built with a preset JWT
+    #[builder(default)]
+    preset_jwt: bool,
+    /// Add the `x-arango-allow-dirty-read` header to all GET requests
+    #[builder(default)]
+    dirty_reads: bool,
+    /// Memoize the first successful `current` response
+    #[builder(default)]
+    cache_current: bool,
+    /// The maximum number of times to retry a transient failure
+    #[builder(default)]
+    max_retries: u32,
+    /// The initial backoff between retries
+    #[builder(default)]
+    retry_backoff: Duration,
+    /// The cap on a `Retry-After` delay
+    #[builder(default)]
+    max_retry_after: Duration,
+    /// Headers added to every request that doesn't already set them
+    #[builder(default)]
+    headers: HeaderMap,
+    /// The maximum time a request may wait in the server's queue
+    #[builder(default)]
+    max_queue_time: Option<Duration>,
+    /// The maximum length of a raw response body kept in an error
+    #[builder(default)]
+    max_error_body_len: usize,
+    /// The maximum size of a batched write body
+    #[builder(default)]
+    max_request_body_bytes: Option<usize>,
+    /// Called before every request is sent
+    #[builder(default)]
+    on_request: Option<OnRequest>,
+    /// Called once every request completes
+    #[builder(default)]
+    on_response: Option<OnResponse>,
+    /// The client settings used when rebuilding the clients
+    #[builder(default)]
+    client_options: ClientOptions,
+}
+
+#[derive(Clone, Debug)]
+struct Clients {
+    generation: usize,
+    client: Client,
+    async_client: Client,
+}
+
+impl Clients {
+    fn pick(&self, is_async: bool) -> &Client {
+        if is_async {
+            &self.async_client
+        } else {
+            &self.client
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Session {
+    clients: RwLock<Clients>,
+    refresh: Mutex<()>,
+    settings: Settings,
+    broken: AtomicBool,
+}
+
 /// An `ArangoDB` connection implementing the database operation traits
+///
+/// A connection is cheap to clone.  Clones share the authenticated clients,
+/// and with them the pool of keep-alive connections, so build a connection
+/// once and clone it into each task rather than building one per task.
 #[derive(Clone, Debug, Getters)]
 #[getset(get = "pub(crate)")]
 pub struct Connection {
@@ -34,56 +163,487 @@ pub struct Connection {
     #[doc(hidden)]
     db_url: Url,
     #[doc(hidden)]
-    client: Client,
+    is_async: bool,
     #[doc(hidden)]
-    async_client: Client,
+    max_queue_time: Option<Duration>,
     #[doc(hidden)]
-    is_async: bool,
+    timeout: Option<Duration>,
+    #[doc(hidden)]
+    #[getset(skip)]
+    session: Arc<Session>,
+    #[doc(hidden)]
+    #[getset(skip)]
+    current: Arc<RwLock<Option<BaseResponse<Current>>>>,
 }
 
 impl Connection {
     pub(crate) fn new(
         base_url: Url,
         db_url: Url,
-        client: Client,
-        async_client: Client,
-        is_async: bool,
+        (client, async_client): (Client, Client),
+        settings: Settings,
     ) -> Self {
+        let clients = Clients {
+            generation: 0,
+            client,
+            async_client,
+        };
         Self {
             base_url,
             db_url,
-            client,
-            async_client,
-            is_async,
+            is_async: settings.async_kind.is_some(),
+            max_queue_time: settings.max_queue_time,
+            timeout: None,
+            session: Arc::new(Session {
+                clients: RwLock::new(clients),
+                refresh: Mutex::new(()),
+                settings,
+                broken: AtomicBool::new(false),
+            }),
+            current: Arc::default(),
         }
     }
 
+    /// Get a connection to the given database that shares this connection's
+    /// authenticated clients and settings.
+    ///
+    /// No re-authentication occurs, so the user must have access to the
+    /// given database.
+    ///
+    /// # Errors
+    /// An error is returned if the database url cannot be built.
+    pub fn with_database(&self, name: &str) -> Result<Self> {
+        let db_url = self
+            .base_url
+            .join(&format!("_db/{name}/"))
+            .with_context(|| format!("Unable to build the url for database '{name}'"))?;
+        Ok(Self {
+            db_url,
+            current: Arc::default(),
+            ..self.clone()
+        })
+    }
+
+    /// Get a connection that sends the given maximum queue time with every
+    /// request, overriding the [`max_queue_time`](crate::ConnectionBuilder::max_queue_time)
+    /// the connection was built with.
+    ///
+    /// The connection shares this connection's authenticated clients and
+    /// settings.
+    #[must_use]
+    pub fn with_max_queue_time(&self, max_queue_time: Duration) -> Self {
+        Self {
+            max_queue_time: Some(max_queue_time),
+            ..self.clone()
+        }
+    }
+
+    /// Get a connection that applies the given timeout to every request,
+    /// i.e. for a large `truncate` or a heavy AQL query that needs longer
+    /// than usual.  The timeout covers the request from connecting until
+    /// the response body has been read.
+    ///
+    /// The connection shares this connection's authenticated clients and
+    /// settings, so no new client is built.
+    #[must_use]
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// Clear the memoized [`current`](crate::Database::current) response, so
+    /// the next call fetches it from the server again.  This only has an
+    /// effect if the connection was built with
+    /// [`cache_current`](crate::ConnectionBuilder::cache_current).
+    pub fn invalidate_current_cache(&self) {
+        match self.current.write() {
+            Ok(mut current) => *current = None,
+            Err(poisoned) => *poisoned.into_inner() = None,
+        }
+    }
+
+    pub(crate) fn cached_current(&self) -> Option<BaseResponse<Current>> {
+        match self.current.read() {
+            Ok(current) => current.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    pub(crate) fn set_cached_current(&self, response: &BaseResponse<Current>) {
+        if *self.session.settings.cache_current() {
+            match self.current.write() {
+                Ok(mut current) => *current = Some(response.clone()),
+                Err(poisoned) => *poisoned.into_inner() = Some(response.clone()),
+            }
+        }
+    }
+
+    /// Reject a batched write body larger than the configured
+    /// [`max_request_body_bytes`](crate::ConnectionBuilder::max_request_body_bytes)
+    /// before it is sent.  The `size` is only computed if a maximum is set.
+    pub(crate) fn check_body_size<F>(&self, operation: &str, size: F) -> Result<()>
+    where
+        F: FnOnce() -> Result<usize>,
+    {
+        if let Some(max) = *self.session.settings.max_request_body_bytes() {
+            let size = size()?;
+            if size > max {
+                return Err(RequestBodyTooLarge {
+                    operation: operation.to_string(),
+                    size,
+                    max,
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn clients(&self) -> Clients {
+        match self.session.clients.read() {
+            Ok(clients) => clients.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Re-run the auth flow and swap in freshly authenticated clients.
+    ///
+    /// The refresh is guarded so concurrent requests that all see a `401`
+    /// only hit the auth endpoint once.  Requests that were sent with an older
+    /// generation of the clients than the current one skip the refresh.
+    async fn reauth(&self, generation: usize) -> Result<()> {
+        if let Some(credentials) = self.session.settings.credentials() {
+            let _guard = self.session.refresh.lock().await;
+
+            if self.clients().generation == generation {
+                let settings = &self.session.settings;
+                let jwt = authenticate(
+                    &self.base_url,
+                    &credentials.username,
+                    &credentials.password,
+                    settings.client_options(),
+                )
+                .await?;
+                let (client, async_client) = build_clients(
+                    &bearer(&jwt),
+                    *settings.async_kind(),
+                    settings.client_options(),
+                )?;
+                let clients = Clients {
+                    generation: generation + 1,
+                    client,
+                    async_client,
+                };
+                match self.session.clients.write() {
+                    Ok(mut current) => *current = clients,
+                    Err(poisoned) => *poisoned.into_inner() = clients,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the request, applying any connection wide headers that haven't
+    /// been overridden on the request itself.
+    fn prepare(&self, rb: RequestBuilder) -> std::result::Result<Request, Error> {
+        let mut request = rb.build()?;
+
+        if *self.session.settings.dirty_reads() && request.method() == Method::GET {
+            let name = HeaderName::from_static(ALLOW_DIRTY_READ);
+            if !request.headers().contains_key(&name) {
+                let _old = request
+                    .headers_mut()
+                    .insert(name, HeaderValue::from_static("true"));
+            }
+        }
+
+        if let Some(max_queue_time) = self.max_queue_time {
+            let name = HeaderName::from_static(QUEUE_TIME_SECONDS);
+            if !request.headers().contains_key(&name) {
+                let value = max_queue_time.as_secs_f64().to_string();
+                if let Ok(value) = HeaderValue::from_str(&value) {
+                    let _old = request.headers_mut().insert(name, value);
+                }
+            }
+        }
+
+        if let Some(timeout) = self.timeout {
+            if request.timeout().is_none() {
+                *request.timeout_mut() = Some(timeout);
+            }
+        }
+
+        let headers = self.session.settings.headers();
+        for name in headers.keys() {
+            if !request.headers().contains_key(name) {
+                for value in headers.get_all(name) {
+                    let _ = request.headers_mut().append(name, value.clone());
+                }
+            }
+        }
+
+        Ok(request)
+    }
+
+    /// Execute the request, calling any configured hooks
+    async fn execute(
+        &self,
+        client: &Client,
+        request: Request,
+    ) -> std::result::Result<Response, Error> {
+        let settings = &self.session.settings;
+
+        if let Some(on_request) = settings.on_request() {
+            on_request.call(request.method(), request.url());
+        }
+
+        let res = if let Some(on_response) = settings.on_response() {
+            let method = request.method().clone();
+            let url = request.url().clone();
+            let start = Instant::now();
+            let res = client.execute(request).await;
+            let status = res.as_ref().ok().map(|res| res.status().as_u16());
+            on_response.call(&Trace::new(method, url, status, start.elapsed()));
+            res
+        } else {
+            client.execute(request).await
+        };
+
+        res.map(|mut res| {
+            let max_len = MaxErrorBodyLen(*settings.max_error_body_len());
+            let _old = res.extensions_mut().insert(max_len);
+            res
+        })
+    }
+
+    /// Send the request built by `f`, re-authenticating and retrying once
+    /// if the server responds with a `401 Unauthorized`.
+    async fn send_once<F>(
+        &self,
+        is_async: bool,
+        f: &F,
+    ) -> std::result::Result<(Method, std::result::Result<Response, Error>), Error>
+    where
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        let clients = self.clients();
+        let client = clients.pick(is_async);
+        let request = self.prepare(f(client))?;
+        let method = request.method().clone();
+        let res = self.execute(client, request).await;
+
+        let res = match &res {
+            Ok(response)
+                if response.status() == StatusCode::UNAUTHORIZED
+                    && self.session.settings.credentials().is_some() =>
+            {
+                if self.reauth(clients.generation).await.is_ok() {
+                    let clients = self.clients();
+                    let client = clients.pick(is_async);
+                    self.execute(client, self.prepare(f(client))?).await
+                } else {
+                    res
+                }
+            }
+            _ => res,
+        };
+        Ok((method, res))
+    }
+
+    /// Determine whether the given result should be retried, and if so, how
+    /// long to wait before doing so.
+    ///
+    /// Only idempotent methods are retried, and only if the caller marked the
+    /// request as `retryable`.  A `PUT` that moves server side state forward,
+    /// i.e. fetching the next batch of a cursor or the result of a job, is not
+    /// safe to repeat, as the first attempt may have reached the server.
+    /// Retries happen on connection errors or a
+    /// `502 Bad Gateway`/`503 Service Unavailable`.  A timeout is not retried,
+    /// as the request may already have been applied on the server.  A
+    /// `Retry-After` header (in seconds) is honored when present, up to
+    /// [`max_retry_after`](crate::ConnectionBuilder::max_retry_after),
+    /// otherwise the backoff doubles on each attempt.
+    fn retry_delay(
+        &self,
+        method: &Method,
+        retryable: bool,
+        res: &std::result::Result<Response, Error>,
+        attempt: u32,
+    ) -> Option<Duration> {
+        let settings = &self.session.settings;
+        let idempotent = *method == Method::GET
+            || *method == Method::HEAD
+            || *method == Method::PUT
+            || *method == Method::DELETE;
+
+        if attempt >= *settings.max_retries() || !retryable || !idempotent {
+            return None;
+        }
+
+        let backoff = settings
+            .retry_backoff()
+            .saturating_mul(2_u32.saturating_pow(attempt));
+
+        match res {
+            Ok(response)
+                if response.status() == StatusCode::BAD_GATEWAY
+                    || response.status() == StatusCode::SERVICE_UNAVAILABLE =>
+            {
+                let retry_after = response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|x| x.to_str().ok())
+                    .and_then(|x| x.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .map(|retry_after| retry_after.min(*settings.max_retry_after()));
+                Some(retry_after.unwrap_or(backoff))
+            }
+            Err(e) if e.is_connect() => Some(backoff),
+            _ => None,
+        }
+    }
+
+    /// Send the request built by `f`, retrying transient failures up to the
+    /// configured [`max_retries`](crate::ConnectionBuilder::max_retries).
+    /// Requests that are not `retryable` are sent once.
+    ///
+    /// A `401 Unauthorized` on a connection built with a preset
+    /// [`jwt`](crate::ConnectionBuilder::jwt) is a
+    /// [`JwtRejected`](crate::Error::JwtRejected) error, as it cannot be
+    /// refreshed.
+    pub(crate) async fn send<F>(
+        &self,
+        is_async: bool,
+        retryable: bool,
+        f: F,
+    ) -> Result<std::result::Result<Response, Error>>
+    where
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        let mut attempt = 0;
+
+        loop {
+            let (method, res) = self.send_once(is_async, &f).await?;
+
+            if let Some(delay) = self.retry_delay(&method, retryable, &res, attempt) {
+                sleep(delay).await;
+                attempt += 1;
+            } else {
+                let broken = matches!(&res, Err(e) if e.is_connect() || e.is_timeout());
+                self.session.broken.store(broken, Ordering::Relaxed);
+
+                if *self.session.settings.preset_jwt()
+                    && matches!(&res, Ok(response) if response.status() == StatusCode::UNAUTHORIZED)
+                {
+                    return Err(JwtRejected.into());
+                }
+                return Ok(res);
+            }
+        }
+    }
+
+    /// Check that the server is reachable by issuing a `GET` to the
+    /// `_api/version` endpoint.
+    ///
+    /// The ping uses a short timeout and skips async mode, retries and
+    /// re-authentication, so it is cheap enough to call from a pool manager's
+    /// `is_valid` or `has_broken` check, i.e. in an [`r2d2`](https://docs.rs/r2d2)
+    /// `ManageConnection` implementation
+    ///
+    /// ```text
+    /// fn is_valid(&self, conn: &mut Connection) -> Result<(), Error> {
+    ///     runtime.block_on(conn.ping())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// An error is returned if the server cannot be reached, or responds
+    /// with a non-success status.
+    pub async fn ping(&self) -> Result<()> {
+        let url = self
+            .db_url
+            .join("_api/version")
+            .with_context(|| "Unable to build the version url")?;
+        let clients = self.clients();
+        let request = self.prepare(clients.client.get(url).timeout(PING_TIMEOUT))?;
+        let res = self.execute(&clients.client, request).await;
+        let broken = matches!(&res, Err(e) if e.is_connect() || e.is_timeout());
+        self.session.broken.store(broken, Ordering::Relaxed);
+        empty(res).await
+    }
+
+    /// Did the last request fail to reach the server?
+    #[cfg_attr(not(feature = "r2d2"), allow(dead_code))]
+    pub(crate) fn is_broken(&self) -> bool {
+        self.session.broken.load(Ordering::Relaxed)
+    }
+
     pub(crate) async fn req<F, T, U, V>(
         &self,
         verb: &HttpVerb,
+        retryable: bool,
         url: Url,
         headers: Option<HeaderMap>,
         json: Option<U>,
         f: F,
     ) -> ArangoResult<T>
     where
-        T: DeserializeOwned + Send + Sync,
+        T: Send + Sync,
         U: Serialize + Send + Sync,
         F: FnOnce(std::result::Result<Response, Error>) -> V,
         V: Future<Output = Result<T>> + Send + Sync,
     {
-        if *self.is_async() {
-            let client = self.async_client();
-            Ok(Either::new_left(
-                req(client, verb, url, headers, json)
-                    .then(handle_job_response)
-                    .await?,
-            ))
+        let body = json.map(|json| self.encode(&json)).transpose()?;
+        self.exec(
+            retryable,
+            |client| {
+                let rb = req(client, verb, url.clone(), headers.clone());
+                match &body {
+                    Some(body) => self.with_body(rb, body),
+                    None => rb,
+                }
+            },
+            f,
+        )
+        .await
+    }
+
+    /// Encode a request body in the configured
+    /// [`content_type`](crate::ConnectionBuilder::content_type)
+    pub(crate) fn encode<T>(&self, body: &T) -> Result<Bytes>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.session
+            .settings
+            .client_options()
+            .content_type()
+            .encode(body)
+    }
+
+    /// Attach a body from [`encode`](Self::encode) to the request
+    pub(crate) fn with_body(&self, rb: RequestBuilder, body: &Bytes) -> RequestBuilder {
+        let content_type = self.session.settings.client_options().content_type();
+        rb.header(CONTENT_TYPE, content_type.mime())
+            .body(body.clone())
+    }
+
+    async fn exec<B, F, T, V>(&self, retryable: bool, build: B, f: F) -> ArangoResult<T>
+    where
+        B: Fn(&Client) -> RequestBuilder,
+        T: Send + Sync,
+        F: FnOnce(std::result::Result<Response, Error>) -> V,
+        V: Future<Output = Result<T>> + Send + Sync,
+    {
+        let is_async = *self.is_async();
+        let res = self.send(is_async, retryable, build).await?;
+
+        if is_async {
+            Ok(Either::new_left(handle_job_response(res).await?))
         } else {
-            let client = self.client();
-            Ok(Either::new_right(
-                req(client, verb, url, headers, json).then(f).await?,
-            ))
+            Ok(Either::new_right(f(res).await?))
         }
     }
 
@@ -100,7 +660,7 @@ impl Connection {
         F: FnOnce(std::result::Result<Response, Error>) -> V,
         V: Future<Output = Result<T>> + Send + Sync,
     {
-        self.req(&HttpVerb::Delete, url, headers, Some(json), f)
+        self.req(&HttpVerb::Delete, true, url, headers, Some(json), f)
             .await
     }
 
@@ -112,12 +672,27 @@ impl Connection {
         f: F,
     ) -> ArangoResult<T>
     where
-        T: DeserializeOwned + Send + Sync,
+        T: Send + Sync,
         U: Serialize + Send + Sync,
         F: FnOnce(std::result::Result<Response, Error>) -> V,
         V: Future<Output = Result<T>> + Send + Sync,
     {
-        self.req(&HttpVerb::Get, url, headers, json, f).await
+        self.req(&HttpVerb::Get, true, url, headers, json, f).await
+    }
+
+    pub(crate) async fn head<F, T, V>(
+        &self,
+        url: Url,
+        headers: Option<HeaderMap>,
+        f: F,
+    ) -> ArangoResult<T>
+    where
+        T: DeserializeOwned + Send + Sync,
+        F: FnOnce(std::result::Result<Response, Error>) -> V,
+        V: Future<Output = Result<T>> + Send + Sync,
+    {
+        self.req(&HttpVerb::Head, true, url, headers, None::<String>, f)
+            .await
     }
 
     pub(crate) async fn patch<F, T, U, V>(
@@ -133,7 +708,7 @@ impl Connection {
         F: FnOnce(std::result::Result<Response, Error>) -> V,
         V: Future<Output = Result<T>> + Send + Sync,
     {
-        self.req(&HttpVerb::Patch, url, headers, Some(json), f)
+        self.req(&HttpVerb::Patch, true, url, headers, Some(json), f)
             .await
     }
 
@@ -150,7 +725,35 @@ impl Connection {
         F: FnOnce(std::result::Result<Response, Error>) -> V,
         V: Future<Output = Result<T>> + Send + Sync,
     {
-        self.req(&HttpVerb::Post, url, headers, Some(json), f).await
+        self.req(&HttpVerb::Post, true, url, headers, Some(json), f)
+            .await
+    }
+
+    /// POST a pre-serialized body with the given content type, i.e. the JSON
+    /// lines used by the import API
+    pub(crate) async fn post_raw<F, T, V>(
+        &self,
+        url: Url,
+        headers: Option<HeaderMap>,
+        content_type: &'static str,
+        body: Bytes,
+        f: F,
+    ) -> ArangoResult<T>
+    where
+        T: DeserializeOwned + Send + Sync,
+        F: FnOnce(std::result::Result<Response, Error>) -> V,
+        V: Future<Output = Result<T>> + Send + Sync,
+    {
+        self.exec(
+            true,
+            |client| {
+                req(client, &HttpVerb::Post, url.clone(), headers.clone())
+                    .header(CONTENT_TYPE, content_type)
+                    .body(body.clone())
+            },
+            f,
+        )
+        .await
     }
 
     pub(crate) async fn put<F, T, U, V>(
@@ -166,23 +769,35 @@ impl Connection {
         F: FnOnce(std::result::Result<Response, Error>) -> V,
         V: Future<Output = Result<T>> + Send + Sync,
     {
-        self.req(&HttpVerb::Put, url, headers, Some(json), f).await
+        self.req(&HttpVerb::Put, true, url, headers, Some(json), f)
+            .await
+    }
+
+    /// PUT that is never retried, as it moves server side state forward,
+    /// i.e. fetching the next batch of a cursor
+    pub(crate) async fn put_no_retry<F, T, U, V>(
+        &self,
+        url: Url,
+        headers: Option<HeaderMap>,
+        json: U,
+        f: F,
+    ) -> ArangoResult<T>
+    where
+        T: DeserializeOwned + Send + Sync,
+        U: Serialize + Send + Sync,
+        F: FnOnce(std::result::Result<Response, Error>) -> V,
+        V: Future<Output = Result<T>> + Send + Sync,
+    {
+        self.req(&HttpVerb::Put, false, url, headers, Some(json), f)
+            .await
     }
 }
 
-fn req<T>(
-    client: &Client,
-    verb: &HttpVerb,
-    url: Url,
-    headers: Option<HeaderMap>,
-    json: Option<T>,
-) -> impl Future<Output = std::result::Result<Response, Error>>
-where
-    T: Serialize + Send + Sync,
-{
+fn req(client: &Client, verb: &HttpVerb, url: Url, headers: Option<HeaderMap>) -> RequestBuilder {
     let mut rb = match verb {
         HttpVerb::Delete => client.delete(url),
         HttpVerb::Get => client.get(url),
+        HttpVerb::Head => client.head(url),
         HttpVerb::Patch => client.patch(url),
         HttpVerb::Post => client.post(url),
         HttpVerb::Put => client.put(url),
@@ -192,9 +807,596 @@ where
         rb = rb.headers(headers);
     }
 
-    if let Some(json) = json {
-        rb = rb.json(&json);
+    rb
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        builder::ConnectionBuilder,
+        common::output::Response,
+        cursor::{input::NextConfigBuilder, output::CursorMeta},
+        db::{input::CreateBuilder, output::Current},
+        doc::{input::ReadConfigBuilder, output::OutputDoc},
+        error::RuarangoErr,
+        hook::{OnRequest, OnResponse},
+        model::auth::output::AuthResponse,
+        model::ALLOW_DIRTY_READ,
+        traits::{Database, Job},
+        types::ArangoResult,
+        utils::{
+            default_conn, mock_auth,
+            mocks::{
+                admin::mock_version,
+                db::{mock_current, mock_list},
+                doc::mock_read_merged_headers,
+            },
+        },
+    };
+    use anyhow::{anyhow, Result};
+    use reqwest::{
+        header::{HeaderMap, HeaderName, HeaderValue},
+        Error, Method,
+    };
+    use serde_json::json;
+    use std::{
+        sync::{Arc, Mutex as StdMutex},
+        time::Duration,
+    };
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mock_auth_n(mock_server: &MockServer, n: u64) {
+        let body: AuthResponse = "not a real jwt".into();
+        Mock::given(method("POST"))
+            .and(path("/_open/auth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(n)
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn mock_unauthorized(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(401))
+            .up_to_n_times(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn clones_share_session() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth_n(&mock_server, 1).await;
+        let mut body = Response::<Current>::default();
+        let _ = body.set_code(200);
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(4)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .pool_max_idle_per_host(2)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .build()
+            .await?;
+        let handles = (0..4)
+            .map(|_| {
+                let conn = conn.clone();
+                tokio::spawn(async move { conn.current().await })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert_eq!(*handle.await??.right_safe()?.code(), 200);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_database_reuses_session() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth_n(&mock_server, 1).await;
+        mock_list(&mock_server).await;
+        let mut body = Response::<Current>::default();
+        let _ = body.set_code(200);
+        Mock::given(method("GET"))
+            .and(path("_db/test/_api/database/current"))
+            .and(header("x-test", "yoda"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut headers = HeaderMap::new();
+        let _ = headers.insert(
+            HeaderName::from_static("x-test"),
+            HeaderValue::from_static("yoda"),
+        );
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .headers(headers)
+            .build()
+            .await?;
+        let databases = conn.list().await?.right_safe()?;
+        let db = &databases.result()[1];
+
+        let test_conn = conn.with_database(db)?;
+        assert_eq!(conn.is_async(), test_conn.is_async());
+        assert_eq!(conn.base_url(), test_conn.base_url());
+        assert!(test_conn.db_url().as_str().ends_with("/_db/test/"));
+        let res = test_conn.current().await?;
+        assert_eq!(*res.right_safe()?.code(), 200);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hooks_observe_requests() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_current(&mock_server).await;
+
+        let requests = Arc::new(StdMutex::new(vec![]));
+        let traces = Arc::new(StdMutex::new(vec![]));
+        let req_clone = requests.clone();
+        let trace_clone = traces.clone();
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .on_request(OnRequest::new(move |method, url| {
+                if let Ok(mut requests) = req_clone.lock() {
+                    requests.push((method.clone(), url.path().to_string()));
+                }
+            }))
+            .on_response(OnResponse::new(move |trace| {
+                if let Ok(mut traces) = trace_clone.lock() {
+                    traces.push(trace.clone());
+                }
+            }))
+            .build()
+            .await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+
+        let requests = requests.lock().map_err(|e| anyhow!("{}", e))?;
+        assert_eq!(
+            *requests,
+            vec![(Method::GET, "/_db/keti/_api/database/current".to_string())]
+        );
+        let traces = traces.lock().map_err(|e| anyhow!("{}", e))?;
+        assert_eq!(1, traces.len());
+        assert_eq!(traces[0].method(), Method::GET);
+        assert_eq!(traces[0].url().path(), "/_db/keti/_api/database/current");
+        assert_eq!(*traces[0].status(), Some(200));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_queue_time_adds_header() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let mut body = Response::<Current>::default();
+        let _ = body.set_code(200);
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .and(header("x-arango-queue-time-seconds", "1.5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .max_queue_time(Duration::from_millis(1500))
+            .build()
+            .await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_timeout() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let mut body = Response::<Current>::default();
+        let _ = body.set_code(200);
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(body)
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.with_timeout(Duration::from_secs(5)).current().await?;
+        assert!(res.is_right());
+
+        let err = conn
+            .with_timeout(Duration::from_millis(50))
+            .current()
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<Error>().map_or(false, Error::is_timeout));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_queue_time_violation() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .and(header("x-arango-queue-time-seconds", "0.25"))
+            .respond_with(ResponseTemplate::new(412).set_body_json(json!({
+                "error": true,
+                "code": 412,
+                "errorNum": 21004,
+                "errorMessage": "queue time violated"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri())
+            .await?
+            .with_max_queue_time(Duration::from_millis(250));
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let err = crate::Document::read::<OutputDoc>(&conn, config)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::QueueTimeViolation { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reauth_on_unauthorized() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth_n(&mock_server, 2).await;
+        mock_unauthorized(&mock_server).await;
+        mock_current(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+        let current = res.right_safe()?;
+        assert_eq!(*current.code(), 200);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_reauth_when_disabled() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth_n(&mock_server, 1).await;
+        mock_unauthorized(&mock_server).await;
+        mock_current(&mock_server).await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .auto_reauth(false)
+            .build()
+            .await?;
+        assert!(conn.current().await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn preset_jwt_rejected() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth_n(&mock_server, 0).await;
+        mock_unauthorized(&mock_server).await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .database("keti")
+            .jwt("an expired jwt")
+            .build()
+            .await?;
+        let err = conn.current().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::JwtRejected)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn debug_redacts_password() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("sup3r s3cret")
+            .database("keti")
+            .build()
+            .await?;
+        let debug = format!("{conn:?}");
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("sup3r s3cret"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dirty_reads_adds_header() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let mut body = Response::<Current>::default();
+        let _ = body.set_code(200);
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .and(header("x-arango-allow-dirty-read", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .dirty_reads(true)
+            .build()
+            .await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connection_headers_are_merged() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_merged_headers(&mock_server).await?;
+
+        let mut headers = HeaderMap::new();
+        let _ = headers.insert(
+            HeaderName::from_static("x-trace-id"),
+            HeaderValue::from_static("connection"),
+        );
+        let _ = headers.insert(
+            HeaderName::from_static(ALLOW_DIRTY_READ),
+            HeaderValue::from_static("false"),
+        );
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .headers(headers)
+            .build()
+            .await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .allow_dirty_read(true)
+            .build()?;
+        let res = crate::Document::read::<OutputDoc>(&conn, config).await?;
+        assert!(res.is_right());
+
+        Ok(())
+    }
+
+    async fn retry_conn(uri: String) -> Result<crate::Connection> {
+        ConnectionBuilder::default()
+            .url(uri)
+            .username("root")
+            .password("")
+            .database("keti")
+            .max_retries(3)
+            .retry_backoff(Duration::from_millis(1))
+            .build()
+            .await
+    }
+
+    #[tokio::test]
+    async fn retry_on_service_unavailable() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        mock_current(&mock_server).await;
+
+        let conn = retry_conn(mock_server.uri()).await?;
+        let res = conn.current().await?;
+        assert!(res.is_right());
+        assert_eq!(*res.right_safe()?.code(), 200);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_after_is_capped() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(503).insert_header("retry-after", "86400"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mock_current(&mock_server).await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .max_retries(1)
+            .max_retry_after(Duration::from_millis(10))
+            .build()
+            .await?;
+        let res = tokio::time::timeout(Duration::from_secs(5), conn.current()).await??;
+        assert!(res.is_right());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_exhausted() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(4)
+            .mount(&mock_server)
+            .await;
+
+        let conn = retry_conn(mock_server.uri()).await?;
+        assert!(conn.current().await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_retry_on_post() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_api/database"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = retry_conn(mock_server.uri()).await?;
+        let create = CreateBuilder::default().name("test_db").build()?;
+        assert!(conn.create(&create).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_retry_on_timeout() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = retry_conn(mock_server.uri())
+            .await?
+            .with_timeout(Duration::from_millis(50));
+        assert!(conn.current().await.is_err());
+
+        Ok(())
     }
 
-    rb.send()
+    #[tokio::test]
+    async fn no_retry_on_cursor_next() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/cursor/123"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = retry_conn(mock_server.uri()).await?;
+        let config = NextConfigBuilder::default().id("123").build()?;
+        let res: ArangoResult<CursorMeta<usize>> = crate::Cursor::next(&conn, config).await;
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_retry_on_job_fetch() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/job/123456"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = retry_conn(mock_server.uri()).await?;
+        let res: Result<usize> = conn.fetch("123456").await;
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ping() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_version(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        conn.ping().await?;
+        assert!(!conn.is_broken());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ping_server_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/version"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        assert!(conn.ping().await.is_err());
+
+        Ok(())
+    }
 }
@@ -14,6 +14,8 @@ use std::error::Error;
 #[cfg(test)]
 use std::num::ParseIntError;
 
+const QUEUE_TIME_VIOLATED: usize = 21004;
+
 /// When bad things happen
 #[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
 #[allow(variant_size_differences)]
@@ -35,32 +37,120 @@ pub enum RuarangoErr {
     /// Invalid connection url
     #[error("You have supplied an invalid connection url")]
     InvalidConnectionUrl,
+    /// The [`jwt`](crate::ConnectionBuilder::jwt) the connection was built
+    /// with was rejected, and there are no credentials to re-authenticate with
+    #[error("The preset JWT was rejected by the server, and there are no credentials to re-authenticate with")]
+    JwtRejected,
     /// invalid document response
-    #[error("Invalid document response: {}\n{}", status, doc_err(err))]
+    #[error(
+        "Invalid document response: {}\n{}{}",
+        status,
+        doc_err(err),
+        body_detail(body)
+    )]
     InvalidDocResponse {
         /// status
         status: u16,
         /// error
+        #[source]
         err: Option<DocErr>,
+        /// The raw response body, if it could not be parsed as an error
+        body: Option<String>,
     },
     /// Invalid cursor response
-    #[error("Invalid cursor response: {}", status)]
+    #[error("Invalid cursor response: {}{}", status, body_detail(body))]
     InvalidCursorResponse {
         /// status
         status: u16,
+        /// The raw response body, if it could not be parsed as an error
+        body: Option<String>,
     },
     /// Un-authorized
-    #[error("You are not authorized to perform the request action")]
+    #[error(
+        "You are not authorized to perform the request action{}",
+        err_detail(err)
+    )]
     Forbidden {
         /// error
+        #[source]
         err: Option<DocErr>,
     },
     /// The request resource is not found
-    #[error("The server can not find the requested resource.")]
+    #[error("The server can not find the requested resource.{}", err_detail(err))]
     NotFound {
         /// error
+        #[source]
         err: Option<DocErr>,
     },
+    /// The job info of an asynchronous request was returned where a
+    /// synchronous result was expected
+    #[error(
+        "Expected a synchronous result, but got asynchronous job '{}'",
+        job_id(id)
+    )]
+    AsyncJob {
+        /// The job id
+        id: Option<String>,
+    },
+    /// The AQL query to kill was not found, i.e. it has already finished
+    #[error("The query '{}' was not found", id)]
+    QueryNotFound {
+        /// The query id
+        id: String,
+    },
+    /// The job to cancel is not pending, i.e. it has already finished
+    #[error("The job '{}' cannot be cancelled", id)]
+    JobNotCancellable {
+        /// The job id
+        id: String,
+    },
+    /// A document in a batch has no `_key`, caught before the request was sent
+    #[error("The document at index {} has no '_key'", index)]
+    MissingKey {
+        /// The index of the document in the batch
+        index: usize,
+    },
+    /// The database given to [`info`](crate::Database::info) does not exist
+    #[error("The database '{}' does not exist", name)]
+    DatabaseNotFound {
+        /// The database name
+        name: String,
+    },
+    /// A document key was empty or contained a `/`
+    #[error("'{}' is not a valid document key", key)]
+    InvalidDocumentKey {
+        /// The invalid key
+        key: String,
+    },
+    /// A document handle was not of the form `collection/key`
+    #[error(
+        "'{}' is not a valid document handle, expected 'collection/key'",
+        handle
+    )]
+    InvalidDocumentHandle {
+        /// The invalid handle
+        handle: String,
+    },
+    /// An update patch was not a JSON object, caught before the request was
+    /// sent
+    #[error("The update patch must be a JSON object, not {}", kind)]
+    InvalidPatch {
+        /// The kind of JSON value given, i.e. `an array`
+        kind: String,
+    },
+    /// `update_with_retry` was called with `max_attempts` of zero, caught
+    /// before the document was read
+    #[error("max_attempts must be at least 1")]
+    ZeroMaxAttempts,
+    /// Cluster only collection options were set for a single server
+    #[error(
+        "The collection options '{}' are only valid in a cluster",
+        fields.join("', '")
+    )]
+    ClusterOnly {
+        /// The names of the cluster only options that were set
+        fields: Vec<String>,
+    },
     /// Unmodified document
     #[error("The document you requested has not been modified")]
     NotModified,
@@ -68,6 +158,17 @@ pub enum RuarangoErr {
     #[error("A precondition has failed: '{}'", doc_err(err))]
     PreconditionFailed {
         /// error
+        #[source]
+        err: Option<DocErr>,
+        /// The current revision of the document on the server, if reported
+        current_rev: Option<String>,
+    },
+    /// The request waited in the server's queue for longer than the
+    /// [`max_queue_time`](crate::ConnectionBuilder::max_queue_time) allowed
+    #[error("The request exceeded the maximum queue time{}", err_detail(err))]
+    QueueTimeViolation {
+        /// error
+        #[source]
         err: Option<DocErr>,
     },
     /// A bad request was made
@@ -77,18 +178,61 @@ pub enum RuarangoErr {
     )]
     BadRequest {
         /// error
+        #[source]
         err: Option<DocErr>,
     },
     /// A conflict has occurred
     #[error("A conflict has occurred: '{}'", doc_err(err))]
     Conflict {
         /// error
+        #[source]
+        err: Option<DocErr>,
+        /// The current revision of the document on the server, if reported
+        current_rev: Option<String>,
+    },
+    /// The server rejected the request body as too large
+    #[error("The request body was too large for the server{}", err_detail(err))]
+    PayloadTooLarge {
+        /// error
+        #[source]
         err: Option<DocErr>,
     },
+    /// The body of a batched write was larger than the
+    /// [`max_request_body_bytes`](crate::ConnectionBuilder::max_request_body_bytes)
+    /// allowed, so it was not sent
+    #[error(
+        "The '{}' request body is {} bytes, more than the maximum of {} bytes",
+        operation,
+        size,
+        max
+    )]
+    RequestBodyTooLarge {
+        /// The batched operation, i.e. `creates`
+        operation: String,
+        /// The size of the serialized body in bytes
+        size: usize,
+        /// The maximum body size in bytes
+        max: usize,
+    },
+    /// The server does not implement the request, i.e. a cluster only
+    /// operation was requested on a single server
+    #[error("The server does not implement this request: '{}'", doc_err(err))]
+    NotImplemented {
+        /// error
+        #[source]
+        err: Option<DocErr>,
+    },
+    /// A pooled connection could not be created or validated
+    #[error("Unable to manage the pooled connection: {}", err)]
+    Pool {
+        /// error
+        err: String,
+    },
     /// cursor request error
     #[error("A cursor request error has occurred: {}", base_err(err))]
     Cursor {
         /// Error
+        #[source]
         err: Option<BaseErr>,
     },
     #[cfg(test)]
@@ -102,6 +246,53 @@ pub enum RuarangoErr {
     InvalidMock,
 }
 
+impl RuarangoErr {
+    pub(crate) fn conflict(err: Option<DocErr>) -> Self {
+        let current_rev = current_rev(&err);
+        Self::Conflict { err, current_rev }
+    }
+
+    /// A `412`, or a [`QueueTimeViolation`](Self::QueueTimeViolation) if the
+    /// server reports the queue time was exceeded
+    pub(crate) fn precondition_failed(err: Option<DocErr>) -> Self {
+        if err.as_ref().map(|err| *err.error_num()) == Some(QUEUE_TIME_VIOLATED) {
+            Self::QueueTimeViolation { err }
+        } else {
+            let current_rev = current_rev(&err);
+            Self::PreconditionFailed { err, current_rev }
+        }
+    }
+
+    /// The HTTP status code associated with this error, if any
+    ///
+    /// # Example
+    /// ```
+    /// # use ruarango::Error;
+    /// assert_eq!(Some(304), Error::NotModified.status_code());
+    /// assert_eq!(None, Error::InvalidConnectionUrl.status_code());
+    /// ```
+    #[must_use]
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::InvalidDocResponse { status, .. }
+            | Self::InvalidCursorResponse { status, .. } => Some(*status),
+            Self::NotModified => Some(304),
+            Self::BadRequest { .. } | Self::JobNotCancellable { .. } => Some(400),
+            Self::JwtRejected => Some(401),
+            Self::Forbidden { .. } => Some(403),
+            Self::NotFound { .. } | Self::QueryNotFound { .. } | Self::DatabaseNotFound { .. } => {
+                Some(404)
+            }
+            Self::Conflict { .. } => Some(409),
+            Self::PreconditionFailed { .. } | Self::QueueTimeViolation { .. } => Some(412),
+            Self::PayloadTooLarge { .. } => Some(413),
+            Self::NotImplemented { .. } => Some(501),
+            Self::Cursor { err } => err.as_ref().map(|err| *err.code()),
+            _ => None,
+        }
+    }
+}
+
 impl Serialize for RuarangoErr {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -123,6 +314,24 @@ fn doc_err(err: &Option<DocErr>) -> String {
     )
 }
 
+fn current_rev(err: &Option<DocErr>) -> Option<String> {
+    err.as_ref().and_then(|err| err.rev().clone())
+}
+
+fn body_detail(body: &Option<String>) -> String {
+    body.as_ref()
+        .map_or_else(String::new, |body| format!("\nInvalid Body: {body}"))
+}
+
+fn err_detail(err: &Option<DocErr>) -> String {
+    err.as_ref()
+        .map_or_else(String::new, |err| format!(": '{err}'"))
+}
+
+fn job_id(id: &Option<String>) -> &str {
+    id.as_deref().unwrap_or("unknown")
+}
+
 fn base_err(err: &Option<BaseErr>) -> String {
     err.as_ref()
         .map_or_else(|| "cursor error".to_string(), ToString::to_string)
@@ -146,8 +355,256 @@ impl From<String> for RuarangoErr {
 
 #[cfg(test)]
 mod test {
-    use super::RuarangoErr::{self, TestError};
+    use super::RuarangoErr::{
+        self, AsyncJob, BadRequest, Conflict, DatabaseNotFound, Forbidden, InvalidConnectionUrl,
+        InvalidCursorResponse, InvalidDocResponse, JobNotCancellable, JwtRejected, NotFound,
+        NotImplemented, NotModified, PreconditionFailed, QueryNotFound, QueueTimeViolation,
+        TestError,
+    };
+    use crate::model::{doc::output::DocErr, BaseErr};
     use anyhow::Result;
+    use serde_json::json;
+    use std::error::Error;
+
+    fn doc_err(code: u16) -> Result<DocErr> {
+        Ok(serde_json::from_value(json!({
+            "error": true,
+            "code": code,
+            "errorNum": 1200,
+            "errorMessage": "test message",
+            "_key": "test_key"
+        }))?)
+    }
+
+    fn assert_details(err: &RuarangoErr, code: u16) {
+        let message = err.to_string();
+        assert!(message.contains(&format!("code: {code}")), "{}", message);
+        assert!(message.contains("error_num: 1200"), "{}", message);
+        assert!(
+            message.contains("error_message: test message"),
+            "{}",
+            message
+        );
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn display_with_details_works() -> Result<()> {
+        assert_details(
+            &InvalidDocResponse {
+                status: 500,
+                err: Some(doc_err(500)?),
+                body: None,
+            },
+            500,
+        );
+        assert_details(
+            &Forbidden {
+                err: Some(doc_err(403)?),
+            },
+            403,
+        );
+        assert_details(
+            &NotFound {
+                err: Some(doc_err(404)?),
+            },
+            404,
+        );
+        assert_details(&RuarangoErr::precondition_failed(Some(doc_err(412)?)), 412);
+        assert_details(
+            &BadRequest {
+                err: Some(doc_err(400)?),
+            },
+            400,
+        );
+        assert_details(&RuarangoErr::conflict(Some(doc_err(409)?)), 409);
+        assert_details(
+            &NotImplemented {
+                err: Some(doc_err(501)?),
+            },
+            501,
+        );
+        let base_err: BaseErr = serde_json::from_value(json!({
+            "error": true,
+            "code": 404,
+            "errorNum": 1200,
+            "errorMessage": "test message"
+        }))?;
+        assert_details(
+            &RuarangoErr::Cursor {
+                err: Some(base_err),
+            },
+            404,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn display_without_details_works() {
+        assert_eq!(
+            "You are not authorized to perform the request action",
+            Forbidden { err: None }.to_string()
+        );
+        assert_eq!(
+            "The server can not find the requested resource.",
+            NotFound { err: None }.to_string()
+        );
+        assert_eq!(
+            "A conflict has occurred: 'No matching document found'",
+            Conflict {
+                err: None,
+                current_rev: None
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "A cursor request error has occurred: cursor error",
+            RuarangoErr::Cursor { err: None }.to_string()
+        );
+        assert_eq!(
+            "Invalid cursor response: 503",
+            InvalidCursorResponse {
+                status: 503,
+                body: None
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Invalid cursor response: 503\nInvalid Body: <html>unavailable</html>",
+            InvalidCursorResponse {
+                status: 503,
+                body: Some("<html>unavailable</html>".to_string())
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Expected a synchronous result, but got asynchronous job '123'",
+            AsyncJob {
+                id: Some("123".to_string())
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "The query '123' was not found",
+            QueryNotFound {
+                id: "123".to_string()
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "The job '123' cannot be cancelled",
+            JobNotCancellable {
+                id: "123".to_string()
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "The document you requested has not been modified",
+            NotModified.to_string()
+        );
+        assert!(Conflict {
+            err: None,
+            current_rev: None
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn queue_time_violation_works() -> Result<()> {
+        let err: DocErr = serde_json::from_value(json!({
+            "error": true,
+            "code": 412,
+            "errorNum": 21004,
+            "errorMessage": "queue time violated"
+        }))?;
+        let err = RuarangoErr::precondition_failed(Some(err));
+        assert!(matches!(err, QueueTimeViolation { err: Some(_) }));
+        assert_eq!(Some(412), err.status_code());
+        assert!(err.to_string().contains("error_num: 21004"));
+        Ok(())
+    }
+
+    #[test]
+    fn status_code_works() {
+        assert_eq!(
+            Some(500),
+            InvalidDocResponse {
+                status: 500,
+                err: None,
+                body: None
+            }
+            .status_code()
+        );
+        assert_eq!(
+            Some(503),
+            InvalidCursorResponse {
+                status: 503,
+                body: None
+            }
+            .status_code()
+        );
+        assert_eq!(Some(304), NotModified.status_code());
+        assert_eq!(Some(400), BadRequest { err: None }.status_code());
+        assert_eq!(
+            Some(400),
+            JobNotCancellable {
+                id: "123".to_string()
+            }
+            .status_code()
+        );
+        assert_eq!(Some(401), JwtRejected.status_code());
+        assert_eq!(
+            Some(404),
+            DatabaseNotFound {
+                name: "test_db".to_string()
+            }
+            .status_code()
+        );
+        assert_eq!(Some(403), Forbidden { err: None }.status_code());
+        assert_eq!(Some(404), NotFound { err: None }.status_code());
+        assert_eq!(
+            Some(404),
+            QueryNotFound {
+                id: "123".to_string()
+            }
+            .status_code()
+        );
+        assert_eq!(
+            Some(409),
+            Conflict {
+                err: None,
+                current_rev: None
+            }
+            .status_code()
+        );
+        assert_eq!(
+            Some(412),
+            PreconditionFailed {
+                err: None,
+                current_rev: None
+            }
+            .status_code()
+        );
+        assert_eq!(Some(501), NotImplemented { err: None }.status_code());
+        assert_eq!(None, RuarangoErr::Cursor { err: None }.status_code());
+        assert_eq!(None, AsyncJob { id: None }.status_code());
+        assert_eq!(
+            None,
+            RuarangoErr::Pool {
+                err: "boom".to_string()
+            }
+            .status_code()
+        );
+        assert_eq!(None, InvalidConnectionUrl.status_code());
+        assert_eq!(
+            None,
+            TestError {
+                val: "test".to_string()
+            }
+            .status_code()
+        );
+    }
 
     #[test]
     fn serialize_with_source_works() -> Result<()> {
@@ -0,0 +1,107 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Request/response hooks for observing the requests a connection makes
+
+use getset::Getters;
+use reqwest::{Method, Url};
+use std::{fmt, sync::Arc, time::Duration};
+
+type RequestFn = dyn Fn(&Method, &Url) + Send + Sync;
+type ResponseFn = dyn Fn(&Trace) + Send + Sync;
+
+/// A completed request, as observed by an [`OnResponse`] hook
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct Trace {
+    /// The request method
+    method: Method,
+    /// The request url
+    url: Url,
+    /// The response status, or `None` if the request failed to get a response,
+    /// i.e. on a connection error
+    status: Option<u16>,
+    /// The time taken to get the response
+    elapsed: Duration,
+}
+
+impl Trace {
+    pub(crate) fn new(method: Method, url: Url, status: Option<u16>, elapsed: Duration) -> Self {
+        Self {
+            method,
+            url,
+            status,
+            elapsed,
+        }
+    }
+}
+
+/// A callback invoked with the method and url of every request before it is
+/// sent
+///
+/// # Example
+///
+/// ```
+/// # use ruarango::OnRequest;
+/// let on_request = OnRequest::new(|method, url| println!("{method} {url}"));
+/// ```
+#[derive(Clone)]
+pub struct OnRequest(Arc<RequestFn>);
+
+impl OnRequest {
+    /// Create a new request hook
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Method, &Url) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub(crate) fn call(&self, method: &Method, url: &Url) {
+        (self.0)(method, url);
+    }
+}
+
+impl fmt::Debug for OnRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OnRequest")
+    }
+}
+
+/// A callback invoked with a [`Trace`] of every request once it completes
+///
+/// # Example
+///
+/// ```
+/// # use ruarango::OnResponse;
+/// let on_response = OnResponse::new(|trace| {
+///     println!("{} {} {:?} in {:?}", trace.method(), trace.url(), trace.status(), trace.elapsed())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct OnResponse(Arc<ResponseFn>);
+
+impl OnResponse {
+    /// Create a new response hook
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Trace) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub(crate) fn call(&self, trace: &Trace) {
+        (self.0)(trace);
+    }
+}
+
+impl fmt::Debug for OnResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OnResponse")
+    }
+}
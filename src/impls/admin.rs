@@ -0,0 +1,327 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` admin trait implementation
+
+use super::EMPTY_BODY;
+use crate::{
+    admin::{
+        output::{Echo, Endpoints, Status, Version},
+        LogLevel,
+    },
+    api_get_async, api_get_right, api_post_async, api_post_right, api_put_async, api_put_right,
+    conn::Connection,
+    traits::{Admin, JobInfo},
+    types::{ArangoEitherExt, ArangoResult},
+    utils::{handle_response, map_resp, text_resp},
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use const_format::concatcp;
+use std::collections::HashMap;
+
+const VERSION_SUFFIX: &str = "_api/version";
+const VERSION_DETAILS_SUFFIX: &str = concatcp!(VERSION_SUFFIX, "?details=true");
+const STATUS_SUFFIX: &str = "_admin/status";
+const LOG_LEVEL_SUFFIX: &str = "_admin/log/level";
+const METRICS_SUFFIX: &str = "_admin/metrics/v2";
+const ENDPOINTS_SUFFIX: &str = "_api/cluster/endpoints";
+const ECHO_SUFFIX: &str = "_admin/echo";
+
+#[async_trait]
+#[allow(unused_qualifications)]
+impl Admin for Connection {
+    async fn version(&self, details: bool) -> ArangoResult<Version> {
+        let suffix = if details {
+            VERSION_DETAILS_SUFFIX
+        } else {
+            VERSION_SUFFIX
+        };
+
+        if *self.is_async() {
+            api_get_async!(self, db_url, suffix)
+        } else {
+            api_get_right!(self, db_url, suffix, Version)
+        }
+    }
+
+    async fn status(&self) -> ArangoResult<Status> {
+        if *self.is_async() {
+            api_get_async!(self, db_url, STATUS_SUFFIX)
+        } else {
+            api_get_right!(self, db_url, STATUS_SUFFIX, Status)
+        }
+    }
+
+    async fn log_level(&self) -> ArangoResult<HashMap<String, LogLevel>> {
+        if *self.is_async() {
+            api_get_async!(self, db_url, LOG_LEVEL_SUFFIX)
+        } else {
+            api_get_right!(self, db_url, LOG_LEVEL_SUFFIX, HashMap<String, LogLevel> => map_resp)
+        }
+    }
+
+    async fn metrics(&self) -> ArangoResult<String> {
+        if *self.is_async() {
+            api_get_async!(self, db_url, METRICS_SUFFIX)
+        } else {
+            api_get_right!(self, db_url, METRICS_SUFFIX, String => text_resp)
+        }
+    }
+
+    async fn endpoints(&self) -> ArangoResult<Vec<String>> {
+        if *self.is_async() {
+            api_get_async!(self, db_url, ENDPOINTS_SUFFIX)
+        } else {
+            let res: ArangoResult<Endpoints> =
+                api_get_right!(self, db_url, ENDPOINTS_SUFFIX, Endpoints => map_resp);
+            Ok(res?.map_sync(Endpoints::urls))
+        }
+    }
+
+    async fn set_log_level(
+        &self,
+        levels: HashMap<String, LogLevel>,
+    ) -> ArangoResult<HashMap<String, LogLevel>> {
+        if *self.is_async() {
+            api_put_async!(self, db_url, LOG_LEVEL_SUFFIX, &levels)
+        } else {
+            api_put_right!(self, db_url, LOG_LEVEL_SUFFIX, HashMap<String, LogLevel>, &levels => map_resp)
+        }
+    }
+
+    async fn echo(&self) -> ArangoResult<Echo> {
+        if *self.is_async() {
+            api_post_async!(self, db_url, ECHO_SUFFIX, &EMPTY_BODY)
+        } else {
+            api_post_right!(self, db_url, ECHO_SUFFIX, Echo, &EMPTY_BODY => map_resp)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Admin;
+    use crate::{
+        admin::{output::Metric, LogLevel},
+        error::RuarangoErr,
+        utils::{
+            default_conn, default_conn_async, mock_auth,
+            mocks::admin::{
+                mock_echo, mock_endpoints, mock_endpoints_forbidden, mock_log_level,
+                mock_log_level_forbidden, mock_metrics, mock_set_log_level, mock_status,
+                mock_version, mock_version_async, mock_version_details,
+            },
+        },
+    };
+    use anyhow::{anyhow, Result};
+    use std::collections::HashMap;
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn version() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_version(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.version(false).await?;
+        assert!(res.is_right());
+        let version = res.right_safe()?;
+        assert_eq!(version.server(), "arango");
+        assert_eq!(version.version(), "3.11.0");
+        assert_eq!(version.license(), "community");
+        assert!(version.details().is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn version_details() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_version_details(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.version(true).await?;
+        assert!(res.is_right());
+        let version = res.right_safe()?;
+        assert_eq!(version.server(), "arango");
+        let details = version
+            .details()
+            .as_ref()
+            .ok_or_else(|| anyhow!("invalid details"))?;
+        assert_eq!(details.get("mode"), Some(&"server".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn version_async() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_version_async(&mock_server).await;
+
+        let conn = default_conn_async(mock_server.uri()).await?;
+        let res = conn.version(false).await?;
+        assert!(res.is_left());
+        let left = res.left_safe()?;
+        assert_eq!(*left.code(), 202);
+        let job_id = left
+            .id()
+            .as_ref()
+            .ok_or_else(|| anyhow!("invalid job_id"))?;
+        assert_eq!(job_id, "123456");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn status() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_status(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.status().await?;
+        assert!(res.is_right());
+        let status = res.right_safe()?;
+        assert_eq!(status.server(), "arango");
+        assert_eq!(status.mode(), "server");
+        assert!(status.foxx_api());
+        assert_eq!(status.server_info().role(), "SINGLE");
+        assert!(!status.server_info().read_only());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn log_level() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_log_level(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.log_level().await?;
+        assert!(res.is_right());
+        let levels = res.right_safe()?;
+        assert_eq!(levels.get("queries"), Some(&LogLevel::Info));
+        assert_eq!(levels.get("requests"), Some(&LogLevel::Warning));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn metrics() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_metrics(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.metrics().await?;
+        assert!(res.is_right());
+        let body = res.right_safe()?;
+        assert!(body.contains("arangodb_scheduler_num_worker_threads"));
+        let metrics = Metric::parse(&body);
+        assert_eq!(1, metrics.len());
+        assert_eq!(4.0, *metrics[0].value());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_log_level() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_set_log_level(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let mut levels = HashMap::new();
+        let _ = levels.insert("queries".to_string(), LogLevel::Debug);
+        let res = conn.set_log_level(levels).await?;
+        assert!(res.is_right());
+        let levels = res.right_safe()?;
+        assert_eq!(levels.get("queries"), Some(&LogLevel::Debug));
+        assert_eq!(levels.get("general"), Some(&LogLevel::Info));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn log_level_forbidden() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_log_level_forbidden(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let err = conn.log_level().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::Forbidden { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn endpoints() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_endpoints(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.endpoints().await?;
+        assert!(res.is_right());
+        let endpoints = res.right_safe()?;
+        assert_eq!(
+            vec!["tcp://10.0.0.1:8529", "tcp://10.0.0.2:8529"],
+            endpoints
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn endpoints_forbidden() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_endpoints_forbidden(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let err = conn.endpoints().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::Forbidden { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn echo() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_echo(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.echo().await?;
+        assert!(res.is_right());
+        let echo = res.right_safe()?;
+        assert_eq!(echo.database(), "keti");
+        assert_eq!(echo.url(), "/_admin/echo");
+        assert_eq!(echo.path(), "/");
+        assert_eq!(echo.request_type(), "POST");
+        assert_eq!(
+            echo.headers().get("x-forwarded-for"),
+            Some(&"10.0.0.1".to_string())
+        );
+        assert_eq!(echo.user().as_deref(), Some("root"));
+
+        Ok(())
+    }
+}
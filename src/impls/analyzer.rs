@@ -0,0 +1,149 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Analyzer trait implementation
+
+use super::EMPTY_BODY;
+use crate::{
+    analyzer::{
+        input::AnalyzerConfig,
+        output::{AnalyzerMeta, Delete},
+        BASE_ANALYZER_SUFFIX,
+    },
+    common::output::Response,
+    traits::Analyzer,
+    utils::map_resp,
+    ArangoResult, Connection,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Url;
+
+impl Connection {
+    fn analyzer_url(&self, suffix: Option<&str>) -> Result<Url> {
+        let suffix = suffix.map_or_else(
+            || BASE_ANALYZER_SUFFIX.to_string(),
+            |suffix| format!("{BASE_ANALYZER_SUFFIX}/{suffix}"),
+        );
+        self.db_url()
+            .join(&suffix)
+            .with_context(|| format!("Unable to build '{suffix}' url"))
+    }
+}
+
+#[async_trait]
+#[allow(unused_qualifications)]
+impl Analyzer for Connection {
+    async fn create(&self, config: AnalyzerConfig) -> ArangoResult<AnalyzerMeta> {
+        let url = self.analyzer_url(None)?;
+        self.post(url, None, &config, map_resp).await
+    }
+
+    async fn list(&self) -> ArangoResult<Response<Vec<AnalyzerMeta>>> {
+        let url = self.analyzer_url(None)?;
+        self.get(url, None, EMPTY_BODY, map_resp).await
+    }
+
+    async fn delete(&self, name: &str, force: bool) -> ArangoResult<Delete> {
+        let suffix = if force {
+            format!("{name}?force=true")
+        } else {
+            name.to_string()
+        };
+        let url = self.analyzer_url(Some(&suffix))?;
+        self.delete(url, None, EMPTY_BODY, map_resp).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Analyzer;
+    use crate::{
+        analyzer::{input::AnalyzerConfigBuilder, AnalyzerKind, Feature},
+        error::RuarangoErr,
+        utils::{
+            default_conn, mock_auth,
+            mocks::analyzer::{
+                mock_create_analyzer, mock_delete_analyzer, mock_delete_analyzer_in_use,
+                mock_list_analyzers,
+            },
+        },
+    };
+    use anyhow::Result;
+    use serde_json::json;
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn create_text_analyzer() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create_analyzer(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = AnalyzerConfigBuilder::default()
+            .name("text_test")
+            .kind(AnalyzerKind::Text)
+            .properties(json!({ "locale": "en", "stemming": true }))
+            .features(vec![Feature::Frequency, Feature::Norm, Feature::Position])
+            .build()?;
+        let res = conn.create(config).await?;
+        assert!(res.is_right());
+        let analyzer = res.right_safe()?;
+        assert_eq!(analyzer.name(), "keti::text_test");
+        assert_eq!(*analyzer.kind(), AnalyzerKind::Text);
+        assert_eq!(analyzer.features().len(), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_analyzers() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_list_analyzers(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.list().await?;
+        assert!(res.is_right());
+        assert_eq!(res.right_safe()?.result().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_analyzer_force() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_delete_analyzer(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = Analyzer::delete(&conn, "text_test", true).await?;
+        assert!(res.is_right());
+        assert_eq!(res.right_safe()?.name(), "keti::text_test");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_analyzer_in_use() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_delete_analyzer_in_use(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let err = Analyzer::delete(&conn, "text_test", false)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::Conflict { err: Some(_), .. })
+        ));
+
+        Ok(())
+    }
+}
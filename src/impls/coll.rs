@@ -12,40 +12,232 @@ use crate::{
     api_delete_async, api_delete_right, api_get_async, api_get_right, api_post_async,
     api_post_right, api_put_async, api_put_right,
     coll::{
-        input::{Config, NewNameBuilder, Props, ShouldCountBuilder},
+        input::{
+            AllConfig, Config, DropConfig, DropConfigBuilder, ExportConfig, NewNameBuilder, Props,
+            ShouldCountBuilder, TruncateConfig,
+        },
         output::{
-            Checksum, Collection as Coll, Collections, Count, Create, Drop, Figures, Load,
-            LoadIndexes, ModifyProps, RecalculateCount, Rename, Revision, Truncate, Unload,
+            Checksum, Collection as Coll, Collections, Count, Create, Drop, ExportBatch, Figures,
+            Load, LoadIndexes, ModifyProps, Properties, RecalculateCount, Rename, ResponsibleShard,
+            Revision, Truncate, Unload,
         },
     },
     common::output::Response,
     conn::Connection,
-    traits::{Collection, JobInfo},
-    types::ArangoResult,
-    utils::handle_response,
+    cursor::{input::CreateConfig, output::CursorMeta, BASE_CURSOR_SUFFIX},
+    error::RuarangoErr::{self, ClusterOnly},
+    impls::cursor::{cursor_all, cursor_batch},
+    model::{
+        add_qp,
+        QueryParam::{Compact, Details, IsSystem, WaitForSync, WithData, WithRevisions},
+    },
+    query::AqlBuilder,
+    traits::{Admin, Collection, Cursor, JobInfo},
+    types::{ArangoEitherExt, ArangoResult},
+    utils::{cursor_resp, handle_response, map_resp},
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use const_format::concatcp;
-use futures::FutureExt;
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
+use libeither::Either;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Deserialize, Serialize)]
+struct CollectionCount {
+    name: String,
+    count: usize,
+}
+
+/// Fill in the document count of each collection with a single query
+async fn add_counts(
+    conn: &Connection,
+    collections: &mut [Collections],
+    exclude_system: bool,
+) -> Result<()> {
+    let mut aql = AqlBuilder::for_("c").in_("COLLECTIONS()");
+    if exclude_system {
+        aql = aql.filter("!STARTS_WITH(c.name, '_')");
+    }
+    let config = aql
+        .ret("{ name: c.name, count: COLLECTION_COUNT(c.name) }")
+        .build()?;
+    let counts: HashMap<String, usize> = cursor_all::<CollectionCount>(conn, config)
+        .await?
+        .right_safe()?
+        .into_iter()
+        .map(|cc| (cc.name, cc.count))
+        .collect();
+
+    for collection in collections {
+        let count = counts.get(collection.name()).copied();
+        *collection.count_mut() = count;
+    }
+    Ok(())
+}
+
+/// The next step of a collection scan, see [`all`](Collection::all)
+enum Scan {
+    Create(Box<CreateConfig>),
+    Next(String),
+    Done,
+}
+
+/// Fetch the next batch of a collection scan
+async fn scan_batch<T>(conn: &Connection, scan: Scan) -> Result<Option<(Vec<T>, Scan)>>
+where
+    T: Serialize + DeserializeOwned + Send + Sync,
+{
+    let mut cursor_meta: CursorMeta<T> = match scan {
+        Scan::Create(config) => Cursor::create(conn, *config).await?.into_result()?,
+        Scan::Next(id) => cursor_batch(conn, BASE_CURSOR_SUFFIX, &id).await?,
+        Scan::Done => return Ok(None),
+    };
+
+    let scan = match cursor_meta.id() {
+        Some(id) if *cursor_meta.has_more() => Scan::Next(id.clone()),
+        _ => Scan::Done,
+    };
+    Ok(Some((cursor_meta.take_result().unwrap_or_default(), scan)))
+}
+
+/// The next step of a collection export, see [`export`](Collection::export)
+enum Export {
+    Create(Box<ExportConfig>),
+    Next(String),
+    Done,
+}
+
+/// Fetch the next batch of a collection export
+async fn export_batch<T>(conn: &Connection, export: Export) -> Result<Option<(Vec<T>, Export)>>
+where
+    T: Serialize + DeserializeOwned + Send + Sync,
+{
+    let mut batch: ExportBatch<T> = match export {
+        Export::Create(config) => {
+            let mut url = conn
+                .db_url()
+                .join(EXPORT_SUFFIX)
+                .with_context(|| format!("Unable to build '{EXPORT_SUFFIX}' url"))?;
+            let _ = url
+                .query_pairs_mut()
+                .append_pair("collection", config.collection());
+            conn.post(url, None, *config, cursor_resp)
+                .await?
+                .into_result()?
+        }
+        Export::Next(id) => cursor_batch(conn, EXPORT_SUFFIX, &id).await?,
+        Export::Done => return Ok(None),
+    };
+
+    let export = match batch.id() {
+        Some(id) if *batch.has_more() => Export::Next(id.clone()),
+        _ => Export::Done,
+    };
+    Ok(Some((batch.take_result(), export)))
+}
+
+fn truncate_suffix(name: &str, config: TruncateConfig) -> String {
+    let mut url = format!("{BASE_SUFFIX}/{name}/truncate");
+    let mut has_qp = false;
+
+    add_qp(*config.wait_for_sync(), &mut url, &mut has_qp, WaitForSync);
+    add_qp(*config.compact(), &mut url, &mut has_qp, Compact);
+    url
+}
+
+fn drop_suffix(name: &str, config: DropConfig) -> String {
+    let mut url = format!("{BASE_SUFFIX}/{name}");
+    let mut has_qp = false;
+
+    add_qp(*config.is_system(), &mut url, &mut has_qp, IsSystem);
+    url
+}
+
+fn figures_suffix(name: &str, details: bool) -> String {
+    let mut url = format!("{BASE_SUFFIX}/{name}/figures");
+    let mut has_qp = false;
+
+    add_qp(details.then_some(true), &mut url, &mut has_qp, Details);
+    url
+}
+
+fn checksum_suffix(name: &str, with_revisions: bool, with_data: bool) -> String {
+    let mut url = format!("{BASE_SUFFIX}/{name}/checksum");
+    let mut has_qp = false;
+
+    add_qp(
+        with_revisions.then_some(true),
+        &mut url,
+        &mut has_qp,
+        WithRevisions,
+    );
+    add_qp(with_data.then_some(true), &mut url, &mut has_qp, WithData);
+
+    url
+}
+
+/// Reject the cluster only options set in `config` if it asks for the check
+/// and the server is a single server
+async fn check_cluster_only(conn: &Connection, config: &Config) -> Result<()> {
+    if !config.cluster_only_checked().unwrap_or_default() {
+        return Ok(());
+    }
+
+    let fields = config.cluster_only_fields();
+    if !fields.is_empty() {
+        let status = conn.status().await?.into_result()?;
+        if status.server_info().role() == "SINGLE" {
+            return Err(ClusterOnly { fields }.into());
+        }
+    }
+    Ok(())
+}
+
+fn is_duplicate_name(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<RuarangoErr>(),
+        Some(RuarangoErr::Conflict { err: Some(err), .. }) if *err.error_num() == DUPLICATE_NAME
+    )
+}
 
 const BASE_SUFFIX: &str = "_api/collection";
+const DUPLICATE_NAME: usize = 1207;
+const EXPORT_SUFFIX: &str = "_api/export";
 const EXCLUDE_SUFFIX: &str = concatcp!(BASE_SUFFIX, "?excludeSystem=true");
 
 #[async_trait]
 #[allow(unused_qualifications)]
 impl Collection for Connection {
-    async fn collections(&self, exclude_system: bool) -> ArangoResult<Response<Vec<Collections>>> {
+    async fn collections(
+        &self,
+        exclude_system: bool,
+        with_count: bool,
+    ) -> ArangoResult<Response<Vec<Collections>>> {
         if *self.is_async() {
             if exclude_system {
                 api_get_async!(self, db_url, EXCLUDE_SUFFIX)
             } else {
                 api_get_async!(self, db_url, BASE_SUFFIX)
             }
-        } else if exclude_system {
-            api_get_right!(self, db_url, EXCLUDE_SUFFIX, Response<Vec<Collections>>)
         } else {
-            api_get_right!(self, db_url, BASE_SUFFIX, Response<Vec<Collections>>)
+            let res: ArangoResult<Response<Vec<Collections>>> = if exclude_system {
+                api_get_right!(self, db_url, EXCLUDE_SUFFIX, Response<Vec<Collections>>)
+            } else {
+                api_get_right!(self, db_url, BASE_SUFFIX, Response<Vec<Collections>>)
+            };
+
+            if with_count {
+                let mut collections = res?.right_safe()?;
+                add_counts(self, collections.result_mut(), exclude_system).await?;
+                Ok(Either::new_right(collections))
+            } else {
+                res
+            }
         }
     }
 
@@ -62,21 +254,42 @@ impl Collection for Connection {
         if *self.is_async() {
             api_post_async!(self, db_url, BASE_SUFFIX, config)
         } else {
+            check_cluster_only(self, config).await?;
             api_post_right!(self, db_url, BASE_SUFFIX, Create, config)
         }
     }
 
-    async fn drop(&self, name: &str, is_system: bool) -> ArangoResult<Drop> {
-        let url = &format!("{BASE_SUFFIX}/{name}");
-        let is_system_url = &format!("{BASE_SUFFIX}/{name}?isSystem=true");
+    async fn create_if_absent(&self, config: &Config) -> ArangoResult<Coll> {
         if *self.is_async() {
-            if is_system {
-                api_delete_async!(self, db_url, is_system_url)
-            } else {
-                api_delete_async!(self, db_url, url)
+            api_post_async!(self, db_url, BASE_SUFFIX, config)
+        } else {
+            check_cluster_only(self, config).await?;
+            let res: ArangoResult<Create> =
+                async { api_post_right!(self, db_url, BASE_SUFFIX, Create, config => map_resp) }
+                    .await;
+
+            match res {
+                Ok(create) => Ok(Either::new_right(create.right_safe()?.into())),
+                Err(e) if is_duplicate_name(&e) => self.collection(config.name()).await,
+                Err(e) => Err(e),
             }
-        } else if is_system {
-            api_delete_right!(self, db_url, is_system_url, Drop)
+        }
+    }
+
+    async fn drop(&self, name: &str, is_system: bool) -> ArangoResult<Drop> {
+        let mut builder = DropConfigBuilder::default();
+        if is_system {
+            let _ = builder.is_system(true);
+        }
+        let config = builder.build()?;
+        self.drop_with(name, config).await
+    }
+
+    async fn drop_with(&self, name: &str, config: DropConfig) -> ArangoResult<Drop> {
+        let url = &drop_suffix(name, config);
+
+        if *self.is_async() {
+            api_delete_async!(self, db_url, url)
         } else {
             api_delete_right!(self, db_url, url, Drop)
         }
@@ -88,20 +301,7 @@ impl Collection for Connection {
         with_revisions: bool,
         with_data: bool,
     ) -> ArangoResult<Checksum> {
-        let mut url = format!("{BASE_SUFFIX}/{name}/checksum");
-        let mut has_qp = false;
-        if with_revisions {
-            url += "?withRevisions=true";
-            has_qp = true;
-        }
-        if with_data {
-            if has_qp {
-                url += "&";
-            } else {
-                url += "?";
-            }
-            url += "withData=true";
-        }
+        let url = checksum_suffix(name, with_revisions, with_data);
 
         if *self.is_async() {
             api_get_async!(self, db_url, &url)
@@ -119,8 +319,8 @@ impl Collection for Connection {
         }
     }
 
-    async fn figures(&self, name: &str) -> ArangoResult<Figures> {
-        let url = &format!("{BASE_SUFFIX}/{name}/figures");
+    async fn figures(&self, name: &str, details: bool) -> ArangoResult<Figures> {
+        let url = &figures_suffix(name, details);
         if *self.is_async() {
             api_get_async!(self, db_url, url)
         } else {
@@ -156,6 +356,15 @@ impl Collection for Connection {
         }
     }
 
+    async fn properties(&self, name: &str) -> ArangoResult<Properties> {
+        let url = &format!("{BASE_SUFFIX}/{name}/properties");
+        if *self.is_async() {
+            api_get_async!(self, db_url, url)
+        } else {
+            api_get_right!(self, db_url, url, Properties)
+        }
+    }
+
     async fn modify_props(&self, name: &str, props: Props) -> ArangoResult<ModifyProps> {
         let url = &format!("{BASE_SUFFIX}/{name}/properties");
         if *self.is_async() {
@@ -187,7 +396,11 @@ impl Collection for Connection {
     }
 
     async fn truncate(&self, name: &str) -> ArangoResult<Truncate> {
-        let url = &format!("{BASE_SUFFIX}/{name}/truncate");
+        self.truncate_with(name, TruncateConfig::default()).await
+    }
+
+    async fn truncate_with(&self, name: &str, config: TruncateConfig) -> ArangoResult<Truncate> {
+        let url = &truncate_suffix(name, config);
 
         if *self.is_async() {
             api_put_async!(self, db_url, url)
@@ -196,6 +409,58 @@ impl Collection for Connection {
         }
     }
 
+    async fn compact(&self, name: &str) -> ArangoResult<Coll> {
+        let url = &format!("{BASE_SUFFIX}/{name}/compact");
+
+        if *self.is_async() {
+            api_put_async!(self, db_url, url)
+        } else {
+            api_put_right!(self, db_url, url, Coll)
+        }
+    }
+
+    async fn responsible_shard<T>(&self, name: &str, document: &T) -> ArangoResult<ResponsibleShard>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = &format!("{BASE_SUFFIX}/{name}/responsibleShard");
+
+        if *self.is_async() {
+            api_put_async!(self, db_url, url, document)
+        } else {
+            api_put_right!(self, db_url, url, ResponsibleShard, document => map_resp)
+        }
+    }
+
+    fn all<'a, T>(&'a self, name: &str, config: AllConfig) -> BoxStream<'a, Result<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'a,
+    {
+        match config.cursor_config(name) {
+            Ok(cursor_config) => {
+                stream::try_unfold(Scan::Create(Box::new(cursor_config)), move |scan| {
+                    scan_batch(self, scan)
+                })
+                .map_ok(|batch| stream::iter(batch.into_iter().map(Ok)))
+                .try_flatten()
+                .boxed()
+            }
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+        }
+    }
+
+    fn export<'a, T>(&'a self, config: ExportConfig) -> BoxStream<'a, Result<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'a,
+    {
+        stream::try_unfold(Export::Create(Box::new(config)), move |export| {
+            export_batch(self, export)
+        })
+        .map_ok(|batch| stream::iter(batch.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
     async fn unload(&self, name: &str) -> ArangoResult<Unload> {
         let url = &format!("{BASE_SUFFIX}/{name}/unload");
 
@@ -209,26 +474,39 @@ impl Collection for Connection {
 
 #[cfg(test)]
 mod test {
-    use super::Collection;
+    use super::{checksum_suffix, drop_suffix, figures_suffix, truncate_suffix, Collection};
     use crate::{
         coll::{CollectionKind, Status},
+        error::RuarangoErr,
         mock_test_async, mock_test_right,
-        model::coll::input::{ConfigBuilder, PropsBuilder},
+        model::coll::input::{
+            AllConfigBuilder, ConfigBuilder, DropConfig, DropConfigBuilder, ExportConfigBuilder,
+            PropsBuilder, Restrict, TruncateConfig, TruncateConfigBuilder,
+        },
         utils::{
             default_conn, default_conn_async, mock_auth,
+            mocks::admin::mock_status,
             mocks::collection::{
-                mock_checksum, mock_collection, mock_collection_async, mock_collections,
-                mock_collections_async, mock_collections_exclude, mock_collections_exclude_async,
-                mock_count, mock_create, mock_drop, mock_figures, mock_load, mock_load_indexes,
-                mock_modify_props, mock_recalculate, mock_rename, mock_revision, mock_truncate,
-                mock_unload,
+                mock_checksum, mock_collection, mock_collection_async, mock_collection_counts,
+                mock_collections, mock_collections_async, mock_collections_exclude,
+                mock_collections_exclude_async, mock_compact, mock_count, mock_create,
+                mock_create_bad_request, mock_create_duplicate, mock_drop, mock_figures, mock_load,
+                mock_load_indexes, mock_modify_props, mock_properties, mock_properties_async,
+                mock_recalculate, mock_rename, mock_responsible_shard,
+                mock_responsible_shard_single_server, mock_revision, mock_truncate,
+                mock_truncate_with, mock_unload,
             },
         },
     };
     use anyhow::{anyhow, Result};
-    use wiremock::MockServer;
+    use futures::TryStreamExt;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{body_partial_json, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
 
-    mock_test_async!(get_collections_async, res; collections(true); mock_collections_exclude_async => {
+    mock_test_async!(get_collections_async, res; collections(true, false); mock_collections_exclude_async => {
         let left = res.left_safe()?;
         assert_eq!(*left.code(), 202);
         assert!(left.id().is_some());
@@ -236,11 +514,11 @@ mod test {
         assert_eq!(job_id, "123456");
     });
 
-    mock_test_right!(get_collections, res; collections(true); mock_collections_exclude => {
+    mock_test_right!(get_collections, res; collections(true, false); mock_collections_exclude => {
         assert!(!res.result().is_empty());
     });
 
-    mock_test_async!(get_collections_with_sys_async, res; collections(true); mock_collections_async => {
+    mock_test_async!(get_collections_with_sys_async, res; collections(true, false); mock_collections_async => {
         let left = res.left_safe()?;
         assert_eq!(*left.code(), 202);
         assert!(left.id().is_some());
@@ -248,8 +526,14 @@ mod test {
         assert_eq!(job_id, "123456");
     });
 
-    mock_test_right!(get_collections_with_sys_works, res; collections(false); mock_collections => {
+    mock_test_right!(get_collections_with_sys_works, res; collections(false, false); mock_collections => {
         assert!(!res.result().is_empty());
+        assert!(res.result()[0].count().is_none());
+    });
+
+    mock_test_right!(get_collections_with_count, res; collections(false, true); mock_collections, mock_collection_counts => {
+        assert_eq!(1, res.result().len());
+        assert_eq!(Some(3), *res.result()[0].count());
     });
 
     mock_test_async!(get_collection_async, res; collection("keti"); mock_collection_async => {
@@ -269,6 +553,114 @@ mod test {
         assert_eq!(res.globally_unique_id(), "hD4537D142F4C/5847");
     });
 
+    #[tokio::test]
+    async fn create_if_absent_creates() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let create = ConfigBuilder::default().name("test_coll").build()?;
+        let res = conn.create_if_absent(&create).await?.right_safe()?;
+        assert_eq!(res.name(), "test_coll");
+        assert_eq!(res.id(), "abc");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_if_absent_exists() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create_duplicate(&mock_server).await;
+        mock_collection(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let create = ConfigBuilder::default().name("keti").build()?;
+        let res = conn.create_if_absent(&create).await?.right_safe()?;
+        assert_eq!(res.name(), "keti");
+        assert_eq!(res.id(), "5847");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_cluster_only_single_server() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_status(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let create = ConfigBuilder::default()
+            .name("test_coll")
+            .number_of_shards(3)
+            .cluster_only_checked(true)
+            .build()?;
+        let err = conn.create(&create).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::ClusterOnly { fields }) if fields == &["number_of_shards"]
+        ));
+        assert_eq!(
+            "The collection options 'number_of_shards' are only valid in a cluster",
+            format!("{err}")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_cluster_only_unchecked() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let create = ConfigBuilder::default()
+            .name("test_coll")
+            .number_of_shards(3)
+            .build()?;
+        let res = conn.create(&create).await?.right_safe()?;
+        assert_eq!(res.name(), "test_coll");
+
+        Ok(())
+    }
+
+    #[test]
+    fn cluster_only_checked_not_serialized() -> Result<()> {
+        let create = ConfigBuilder::default()
+            .name("test_coll")
+            .shard_keys(vec!["a".to_string()])
+            .distribute_shards_like("other")
+            .cluster_only_checked(true)
+            .build()?;
+        let value = serde_json::to_value(&create)?;
+        assert!(value.get("cluster_only_checked").is_none());
+        assert!(value.get("clusterOnlyChecked").is_none());
+        assert_eq!(
+            vec!["distribute_shards_like", "shard_keys"],
+            create.cluster_only_fields()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_if_absent_other_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create_bad_request(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let create = ConfigBuilder::default().name("bad/name").build()?;
+        let err = conn.create_if_absent(&create).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::BadRequest { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn create_then_drop() -> Result<()> {
         let mock_server = MockServer::start().await;
@@ -286,7 +678,7 @@ mod test {
         assert!(!res.error());
         assert_eq!(res.name(), "test_coll");
 
-        let either = conn.drop("test_coll", false).await?;
+        let either = conn.drop_with("test_coll", DropConfig::default()).await?;
         assert!(either.is_right());
         let res = either.right_safe()?;
         assert_eq!(*res.code(), 200);
@@ -294,6 +686,70 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn checksum_suffix_works() {
+        assert_eq!(
+            "_api/collection/test_coll/checksum",
+            checksum_suffix("test_coll", false, false)
+        );
+        assert_eq!(
+            "_api/collection/test_coll/checksum?withData=true",
+            checksum_suffix("test_coll", false, true)
+        );
+        assert_eq!(
+            "_api/collection/test_coll/checksum?withRevisions=true&withData=true",
+            checksum_suffix("test_coll", true, true)
+        );
+    }
+
+    #[test]
+    fn figures_suffix_works() {
+        assert_eq!(
+            "_api/collection/test_coll/figures",
+            figures_suffix("test_coll", false)
+        );
+        assert_eq!(
+            "_api/collection/test_coll/figures?details=true",
+            figures_suffix("test_coll", true)
+        );
+    }
+
+    #[test]
+    fn drop_suffix_works() -> Result<()> {
+        assert_eq!(
+            "_api/collection/test_coll",
+            drop_suffix("test_coll", DropConfig::default())
+        );
+        let config = DropConfigBuilder::default().is_system(true).build()?;
+        assert_eq!(
+            "_api/collection/test_coll?isSystem=true",
+            drop_suffix("test_coll", config)
+        );
+        let config = DropConfigBuilder::default().is_system(false).build()?;
+        assert_eq!(
+            "_api/collection/test_coll?isSystem=false",
+            drop_suffix("test_coll", config)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn truncate_suffix_works() -> Result<()> {
+        assert_eq!(
+            "_api/collection/test_coll/truncate",
+            truncate_suffix("test_coll", TruncateConfig::default())
+        );
+        let config = TruncateConfigBuilder::default()
+            .wait_for_sync(true)
+            .compact(false)
+            .build()?;
+        assert_eq!(
+            "_api/collection/test_coll/truncate?waitForSync=true&compact=false",
+            truncate_suffix("test_coll", config)
+        );
+        Ok(())
+    }
+
     mock_test_right!(get_checksum, res; checksum("test_coll", false, false); mock_checksum => {
         assert_eq!(res.checksum(), "0");
     });
@@ -302,7 +758,7 @@ mod test {
         assert_eq!(*res.count(), 10);
     });
 
-    mock_test_right!(get_figures, res; figures("test_coll"); mock_figures => {
+    mock_test_right!(get_figures, res; figures("test_coll", false); mock_figures => {
         assert_eq!(*res.figures().indexes().count(), 1);
         assert_eq!(*res.figures().indexes().size(), 0);
         assert_eq!(*res.figures().documents_size(), 0);
@@ -311,6 +767,46 @@ mod test {
         assert_eq!(*res.figures().cache_usage(), 0);
     });
 
+    #[tokio::test]
+    async fn get_figures_details() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let body = json!({
+            "error": false,
+            "code": 200,
+            "figures": {
+                "indexes": { "count": 1, "size": 0 },
+                "documentsSize": 0,
+                "cacheInUse": false,
+                "cacheSize": 0,
+                "cacheUsage": 0,
+                "engine": {
+                    "documents": 3,
+                    "indexes": [{ "type": "primary", "id": 0, "count": 3 }]
+                }
+            }
+        });
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/collection/test_coll/figures"))
+            .and(query_param("details", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.figures("test_coll", true).await?.right_safe()?;
+        let engine = res
+            .figures()
+            .engine()
+            .as_ref()
+            .ok_or_else(|| anyhow!("missing engine figures"))?;
+        assert_eq!(Some(3), engine["documents"].as_u64());
+        assert_eq!("primary", engine["indexes"][0]["type"]);
+
+        Ok(())
+    }
+
     mock_test_right!(get_revision, res; revision("test_coll"); mock_revision => {});
 
     mock_test_right!(put_load, res; load("test_coll", true); mock_load => {
@@ -322,6 +818,25 @@ mod test {
         assert!(res.result());
     });
 
+    mock_test_async!(get_properties_async, res; properties("test_coll"); mock_properties_async => {
+        let left = res.left_safe()?;
+        assert_eq!(*left.code(), 202);
+        assert!(left.id().is_some());
+        let job_id = left.id().as_ref().ok_or_else(|| anyhow!("invalid job_id"))?;
+        assert_eq!(job_id, "123456");
+    });
+
+    mock_test_right!(get_properties, res; properties("test_coll"); mock_properties => {
+        assert_eq!(res.name(), "test_coll");
+        assert!(res.wait_for_sync());
+        assert!(!res.is_system());
+        assert_eq!(res.key_options().kind(), "traditional");
+        assert!(res.schema().is_none());
+        assert!(res.number_of_shards().is_none());
+        assert!(res.replication_factor().is_none());
+        assert!(res.write_concern().is_none());
+    });
+
     #[tokio::test]
     async fn put_props() -> Result<()> {
         let mock_server = MockServer::start().await;
@@ -351,5 +866,230 @@ mod test {
 
     mock_test_right!(put_truncate, res; truncate("test_coll"); mock_truncate => {});
 
+    #[tokio::test]
+    async fn put_truncate_with() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_truncate_with(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = TruncateConfigBuilder::default()
+            .wait_for_sync(true)
+            .compact(true)
+            .build()?;
+        let res = conn.truncate_with("test_coll", config).await?;
+        assert!(res.is_right());
+        let res = res.right_safe()?;
+        assert!(!res.error());
+        assert_eq!(*res.code(), 200);
+
+        Ok(())
+    }
+
     mock_test_right!(put_unload, res; unload("test_coll"); mock_unload => {});
+
+    mock_test_right!(put_compact, res; compact("keti"); mock_compact => {
+        assert_eq!(res.name(), "keti");
+        assert_eq!(*res.status(), Status::Loaded);
+    });
+
+    mock_test_right!(put_responsible_shard, res; responsible_shard("test_coll", &json!({ "_key": "test_key" })); mock_responsible_shard => {
+        assert_eq!(res.shard_id(), "s100001");
+    });
+
+    #[tokio::test]
+    async fn put_responsible_shard_single_server() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_responsible_shard_single_server(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let document = json!({ "_key": "test_key" });
+        let err = conn
+            .responsible_shard("single_coll", &document)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::NotImplemented { err: Some(_) })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn all() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let body = json!({
+            "id": "123", "result": [{ "a": 1 }, { "a": 2 }], "hasMore": true, "cached": false, "code": 201, "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .and(body_partial_json(json!({
+                "query": "FOR d IN @@coll RETURN d",
+                "bindVars": { "@coll": "test_coll" },
+                "batchSize": 2,
+                "options": { "stream": true }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let body = json!({
+            "id": "123", "result": [{ "a": 3 }], "hasMore": false, "cached": false, "code": 200, "error": false
+        });
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/cursor/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = AllConfigBuilder::default().batch_size(2).build()?;
+        let docs: Vec<serde_json::Value> = conn.all("test_coll", config).try_collect().await?;
+        assert_eq!(
+            vec![json!({ "a": 1 }), json!({ "a": 2 }), json!({ "a": 3 })],
+            docs
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn all_async() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .respond_with(ResponseTemplate::new(202).insert_header("x-arango-async-id", "123456"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn_async(mock_server.uri()).await?;
+        let res = conn
+            .all::<serde_json::Value>("test_coll", AllConfigBuilder::default().build()?)
+            .try_collect::<Vec<_>>()
+            .await;
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::AsyncJob { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let body = json!({
+            "id": "123", "result": [{ "a": 1 }, { "a": 2 }], "hasMore": true, "code": 201, "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/export"))
+            .and(query_param("collection", "test_coll"))
+            .and(body_partial_json(json!({
+                "batchSize": 2,
+                "restrict": { "type": "include", "fields": ["a"] }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let body = json!({
+            "result": [{ "a": 3 }], "hasMore": false, "code": 200, "error": false
+        });
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/export/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ExportConfigBuilder::default()
+            .collection("test_coll")
+            .batch_size(2)
+            .restrict(Restrict::include(vec!["a"]))
+            .build()?;
+        let docs: Vec<serde_json::Value> = conn.export(config).try_collect().await?;
+        assert_eq!(
+            vec![json!({ "a": 1 }), json!({ "a": 2 }), json!({ "a": 3 })],
+            docs
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export_encodes_collection() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let body = json!({
+            "result": [{ "a": 1 }], "hasMore": false, "code": 201, "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/export"))
+            .and(query_param("collection", "test coll&x=1"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ExportConfigBuilder::default()
+            .collection("test coll&x=1")
+            .build()?;
+        let docs: Vec<serde_json::Value> = conn.export(config).try_collect().await?;
+        assert_eq!(vec![json!({ "a": 1 })], docs);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export_deletes_on_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let body = json!({
+            "id": "123", "result": [{ "a": 1 }], "hasMore": true, "code": 201, "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/export"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let body = json!({
+            "code": 404, "error": true, "errorMessage": "cursor not found", "errorNum": 1600
+        });
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/export/123"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("_db/keti/_api/export/123"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ExportConfigBuilder::default()
+            .collection("test_coll")
+            .build()?;
+        let res = conn
+            .export::<serde_json::Value>(config)
+            .try_collect::<Vec<_>>()
+            .await;
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::Cursor { .. })
+        ));
+
+        Ok(())
+    }
 }
@@ -15,12 +15,75 @@ use crate::{
         cursor::input::{CreateConfig, DeleteConfig, NextConfig},
         BuildUrl,
     },
+    types::ArangoEitherExt,
     utils::{cursor_resp, empty},
-    ArangoResult, Connection, Cursor,
+    ArangoEither, ArangoResult, Connection, Cursor,
 };
+use anyhow::{Context, Result};
 use async_trait::async_trait;
+use libeither::Either;
 use serde::{de::DeserializeOwned, Serialize};
 
+/// Fetch the next batch of the cursor with the given id.  The `base` suffix,
+/// i.e. `_api/cursor` or `_api/export`, is used to build both the next and the
+/// delete url.  If fetching the batch fails, the cursor is deleted rather
+/// than left open on the server until it times out.
+pub(crate) async fn cursor_batch<B>(conn: &Connection, base: &str, id: &str) -> Result<B>
+where
+    B: DeserializeOwned + Send + Sync,
+{
+    let suffix = format!("{base}/{id}");
+    let url = conn
+        .db_url()
+        .join(&suffix)
+        .with_context(|| format!("Unable to build '{suffix}' url"))?;
+    let next = async {
+        conn.put_no_retry(url.clone(), None, EMPTY_BODY, cursor_resp)
+            .await?
+            .into_result()
+    }
+    .await;
+
+    match next {
+        Ok(next) => Ok(next),
+        Err(e) => {
+            let _res: Result<_> = conn.delete(url, None, EMPTY_BODY, empty).await;
+            Err(e)
+        }
+    }
+}
+
+/// Run the given query, following the cursor until all of the results
+/// have been fetched.  For an asynchronous connection the job info of the
+/// initial request is returned.  If fetching a batch fails, the cursor is
+/// deleted rather than left open on the server until it times out.
+pub(crate) async fn cursor_all<T>(conn: &Connection, config: CreateConfig) -> ArangoResult<Vec<T>>
+where
+    T: Serialize + DeserializeOwned + Send + Sync,
+{
+    let res: ArangoEither<CursorMeta<T>> = Cursor::create(conn, config).await?;
+    if res.is_left() {
+        return Ok(Either::new_left(res.left_safe()?));
+    }
+    let mut cursor_meta = res.right_safe()?;
+    let mut results = Vec::new();
+
+    loop {
+        let has_more = *cursor_meta.has_more();
+        let id = cursor_meta.id().clone();
+        results.extend(cursor_meta.take_result().unwrap_or_default());
+
+        match id {
+            Some(id) if has_more => {
+                cursor_meta = cursor_batch(conn, BASE_CURSOR_SUFFIX, &id).await?;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(Either::new_right(results))
+}
+
 #[async_trait]
 #[allow(unused_qualifications)]
 impl Cursor for Connection {
@@ -42,6 +105,158 @@ impl Cursor for Connection {
         T: Serialize + DeserializeOwned + Send + Sync,
     {
         let url = config.build_url(BASE_CURSOR_SUFFIX, self)?;
-        self.put(url, None, EMPTY_BODY, cursor_resp).await
+        self.put_no_retry(url, None, EMPTY_BODY, cursor_resp).await
+    }
+
+    async fn next_batch<T>(&self, id: &str, batch_id: &str) -> ArangoResult<CursorMeta<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let suffix = format!("{BASE_CURSOR_SUFFIX}/{id}/{batch_id}");
+        let url = self
+            .db_url()
+            .join(&suffix)
+            .with_context(|| format!("Unable to build '{suffix}' url"))?;
+        self.post(url, None, EMPTY_BODY, cursor_resp).await
+    }
+
+    async fn query_all<T>(&self, config: CreateConfig) -> ArangoResult<Vec<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+    {
+        cursor_all(self, config).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cursor::{input::CreateConfigBuilder, output::CursorMeta},
+        error::RuarangoErr,
+        utils::{default_conn, default_conn_async, mock_auth},
+        ArangoEither, ArangoEitherExt, Cursor,
+    };
+    use anyhow::Result;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mock_first_batch(mock_server: &MockServer) {
+        let body = json!({
+            "id": "123", "result": [1, 2], "hasMore": true, "cached": false, "code": 201, "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn create_async() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .respond_with(ResponseTemplate::new(202).insert_header("x-arango-async-id", "123456"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn_async(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .query("FOR i IN 1..3 RETURN i")
+            .build()?;
+        let res: ArangoEither<CursorMeta<usize>> = Cursor::create(&conn, config).await?;
+        assert!(res.is_left());
+        assert_eq!(Some("123456"), res.job_id());
+        assert_eq!(202, *res.left_safe()?.code());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_unexpected_error_body() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("upstream exploded"))
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .query("FOR i IN 1..3 RETURN i")
+            .build()?;
+        let res: anyhow::Result<ArangoEither<CursorMeta<usize>>> =
+            Cursor::create(&conn, config).await;
+        let err = res.unwrap_err();
+        match err.downcast_ref::<RuarangoErr>() {
+            Some(RuarangoErr::InvalidCursorResponse {
+                status: 500,
+                body: Some(body),
+            }) => assert_eq!("upstream exploded", body),
+            _ => panic!("expected an invalid cursor response, got: {}", err),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_all() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_first_batch(&mock_server).await;
+        let body = json!({
+            "id": "123", "result": [3], "hasMore": false, "cached": false, "code": 200, "error": false
+        });
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/cursor/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .query("FOR i IN 1..3 RETURN i")
+            .batch_size(2)
+            .build()?;
+        let res: Vec<usize> = conn.query_all(config).await?.right_safe()?;
+        assert_eq!(vec![1, 2, 3], res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_all_deletes_cursor_on_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_first_batch(&mock_server).await;
+        let body = json!({ "error": true, "code": 500, "errorNum": 4, "errorMessage": "internal" });
+        Mock::given(method("PUT"))
+            .and(path("_db/keti/_api/cursor/123"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(body))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("_db/keti/_api/cursor/123"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .query("FOR i IN 1..3 RETURN i")
+            .batch_size(2)
+            .build()?;
+        let res = conn.query_all::<usize>(config).await;
+        assert!(res.is_err());
+
+        Ok(())
     }
 }
@@ -13,19 +13,24 @@ use crate::{
     api_post_right,
     common::output::Response,
     conn::Connection,
-    db::{input::Create, output::Current},
+    db::{
+        input::Create,
+        output::{Current, Engine},
+    },
+    error::RuarangoErr::{self, DatabaseNotFound},
     traits::{Database, JobInfo},
     types::ArangoResult,
-    utils::handle_response,
+    utils::{handle_response, map_resp},
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use const_format::concatcp;
-use futures::FutureExt;
+use libeither::Either;
 
 const BASE_SUFFIX: &str = "_api/database";
 const USER_SUFFIX: &str = concatcp!(BASE_SUFFIX, "/user");
 const CURRENT_SUFFIX: &str = concatcp!(BASE_SUFFIX, "/current");
+const ENGINE_SUFFIX: &str = "_api/engine";
 
 #[async_trait]
 #[allow(unused_qualifications)]
@@ -33,8 +38,38 @@ impl Database for Connection {
     async fn current(&self) -> ArangoResult<Response<Current>> {
         if *self.is_async() {
             api_get_async!(self, db_url, CURRENT_SUFFIX)
+        } else if let Some(current) = self.cached_current() {
+            Ok(Either::new_right(current))
         } else {
-            api_get_right!(self, db_url, CURRENT_SUFFIX, Response<Current>)
+            let res: ArangoResult<Response<Current>> =
+                api_get_right!(self, db_url, CURRENT_SUFFIX, Response<Current>);
+            if let Ok(Ok(current)) = res.as_ref().map(Either::right_ref) {
+                self.set_cached_current(current);
+            }
+            res
+        }
+    }
+
+    async fn info(&self, name: &str) -> ArangoResult<Response<Current>> {
+        let conn = self.with_database(name)?;
+
+        if *conn.is_async() {
+            api_get_async!(conn, db_url, CURRENT_SUFFIX)
+        } else {
+            let res: ArangoResult<Response<Current>> = async {
+                api_get_right!(conn, db_url, CURRENT_SUFFIX, Response<Current> => map_resp)
+            }
+            .await;
+
+            match res {
+                Err(e) if matches!(e.downcast_ref(), Some(RuarangoErr::NotFound { .. })) => {
+                    Err(DatabaseNotFound {
+                        name: name.to_string(),
+                    }
+                    .into())
+                }
+                res => res,
+            }
         }
     }
 
@@ -46,11 +81,15 @@ impl Database for Connection {
         }
     }
 
+    async fn list_accessible(&self) -> ArangoResult<Response<Vec<String>>> {
+        self.user().await
+    }
+
     async fn list(&self) -> ArangoResult<Response<Vec<String>>> {
         if *self.is_async() {
             api_get_async!(self, base_url, BASE_SUFFIX)
         } else {
-            api_get_right!(self, base_url, BASE_SUFFIX, Response<Vec<String>>)
+            api_get_right!(self, base_url, BASE_SUFFIX, Response<Vec<String>> => map_resp)
         }
     }
 
@@ -74,25 +113,58 @@ impl Database for Connection {
             )
         }
     }
+
+    async fn engine(&self) -> ArangoResult<Engine> {
+        if *self.is_async() {
+            api_get_async!(self, db_url, ENGINE_SUFFIX)
+        } else {
+            api_get_right!(self, db_url, ENGINE_SUFFIX, Engine)
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::Database;
     use crate::{
-        db::input::{CreateBuilder, OptionsBuilder, UserBuilder},
+        builder::ConnectionBuilder,
+        common::output::Response,
+        db::{
+            input::{Create, CreateBuilder, OptionsBuilder, UserBuilder},
+            output::Current,
+        },
+        error::RuarangoErr,
         mock_test_async, mock_test_right,
+        traits::Job,
         utils::{
             default_conn, default_conn_async, mock_auth,
             mocks::db::{
-                mock_create, mock_current, mock_current_async, mock_drop, mock_list,
-                mock_list_async, mock_user, mock_user_async,
+                mock_create, mock_create_async, mock_create_job, mock_current, mock_current_async,
+                mock_drop, mock_drop_async, mock_drop_job, mock_engine, mock_engine_indexes,
+                mock_list, mock_list_async, mock_list_forbidden, mock_user, mock_user_async,
             },
             no_db_conn, no_db_conn_async,
         },
     };
     use anyhow::{anyhow, Result};
-    use wiremock::MockServer;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn create_config() -> Result<Create> {
+        let options = OptionsBuilder::default().build()?;
+        let users = UserBuilder::default()
+            .username("test")
+            .password("test")
+            .active(true)
+            .build()?;
+        Ok(CreateBuilder::default()
+            .name("test_db")
+            .options(options)
+            .users(vec![users])
+            .build()?)
+    }
 
     mock_test_async!(test_current_async, res; current(); mock_current_async => {
         let left = res.left_safe()?;
@@ -124,6 +196,10 @@ mod test {
         assert!(!res.result().is_empty());
     });
 
+    mock_test_right!(test_list_accessible, res; list_accessible(); mock_user => {
+        assert!(!res.result().is_empty());
+    });
+
     mock_test_async!(no_db_conn_async, test_list_async, res; list(); mock_list_async => {
         let left = res.left_safe()?;
         assert_eq!(*left.code(), 202);
@@ -137,6 +213,74 @@ mod test {
         assert!(res.result().contains(&"_system".to_string()));
     });
 
+    #[tokio::test]
+    async fn test_list_forbidden() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_list_forbidden(&mock_server).await?;
+
+        let conn = no_db_conn(mock_server.uri()).await?;
+        let err = conn.list().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::Forbidden { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
+    mock_test_right!(no_db_conn, 201, test_create, res; create(&create_config()?); mock_create => {
+        assert!(res.result());
+    });
+
+    mock_test_right!(no_db_conn, 200, test_drop, res; drop("test_db"); mock_drop => {
+        assert!(res.result());
+    });
+
+    #[tokio::test]
+    async fn test_create_async() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create_async(&mock_server).await;
+        mock_create_job(&mock_server).await;
+
+        let conn = no_db_conn_async(mock_server.uri()).await?;
+        let either = conn.create(&create_config()?).await?;
+        assert!(either.is_left());
+        let job_id = either
+            .left_safe()?
+            .id()
+            .clone()
+            .ok_or_else(|| anyhow!("invalid job_id"))?;
+        let res: Response<bool> = conn.fetch(&job_id).await?;
+        assert_eq!(*res.code(), 201);
+        assert!(!res.error());
+        assert!(res.result());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_drop_async() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_drop_async(&mock_server).await;
+        mock_drop_job(&mock_server).await;
+
+        let conn = no_db_conn_async(mock_server.uri()).await?;
+        let either = conn.drop("test_db").await?;
+        assert!(either.is_left());
+        let job_id = either
+            .left_safe()?
+            .id()
+            .clone()
+            .ok_or_else(|| anyhow!("invalid job_id"))?;
+        let res: Response<bool> = conn.fetch(&job_id).await?;
+        assert_eq!(*res.code(), 200);
+        assert!(!res.error());
+        assert!(res.result());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_create_drop() -> Result<()> {
         let mock_server = MockServer::start().await;
@@ -145,19 +289,7 @@ mod test {
         mock_drop(&mock_server).await;
 
         let conn = no_db_conn(mock_server.uri()).await?;
-        let options = OptionsBuilder::default().build()?;
-        let users = UserBuilder::default()
-            .username("test")
-            .password("test")
-            .active(true)
-            .build()?;
-        let create = CreateBuilder::default()
-            .name("test_db")
-            .options(options)
-            .users(vec![users])
-            .build()?;
-
-        let either = conn.create(&create).await?;
+        let either = conn.create(&create_config()?).await?;
         assert!(either.is_right());
         let res = either.right_safe()?;
         assert_eq!(*res.code(), 201);
@@ -172,4 +304,131 @@ mod test {
         assert!(res.result());
         Ok(())
     }
+
+    async fn mock_current_n(mock_server: &MockServer, n: u64) {
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/database/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Response::<Current>::default()))
+            .up_to_n_times(n)
+            .expect(n)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_current_cached() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_current_n(&mock_server, 1).await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .cache_current(true)
+            .build()
+            .await?;
+        let first = conn.current().await?.right_safe()?;
+        let second = conn.current().await?.right_safe()?;
+        assert_eq!(first.result().id(), second.result().id());
+
+        conn.invalidate_current_cache();
+        assert!(conn.current().await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_current_not_cached() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_current_n(&mock_server, 2).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        assert!(conn.current().await?.is_right());
+        assert!(conn.current().await?.is_right());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_engine() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_engine(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.engine().await?;
+        assert!(res.is_right());
+        let engine = res.right_safe()?;
+        assert_eq!(engine.name(), "rocksdb");
+        assert!(!engine.is_mmfiles());
+        assert!(engine.supports().indexes().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_engine_indexes() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_engine_indexes(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.engine().await?;
+        assert!(res.is_right());
+        let engine = res.right_safe()?;
+        assert!(engine
+            .supports()
+            .indexes()
+            .contains(&"persistent".to_string()));
+        let aliases = engine.supports().aliases().get("indexes");
+        assert_eq!(
+            Some(&"persistent".to_string()),
+            aliases.and_then(|a| a.get("hash"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_info() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/test_db/_api/database/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Response::<Current>::default()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.info("test_db").await?;
+        assert!(res.is_right());
+        assert!(!res.right_safe()?.error());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_info_not_found() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/missing/_api/database/current"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": 404, "error": true, "errorMessage": "database not found", "errorNum": 1228
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let err = conn.info("missing").await.unwrap_err();
+        assert_eq!(
+            Some(&RuarangoErr::DatabaseNotFound {
+                name: "missing".to_string()
+            }),
+            err.downcast_ref::<RuarangoErr>()
+        );
+        Ok(())
+    }
 }
@@ -10,21 +10,79 @@
 
 use super::EMPTY_BODY;
 use crate::{
+    coll::input::ConfigBuilder as CollConfigBuilder,
+    cursor::output::CursorMeta,
     doc::{
         input::{
-            CreateConfig, CreatesConfig, DeleteConfig, DeletesConfig, ReadConfig, ReadsConfig,
-            ReplaceConfig, ReplacesConfig, UpdateConfig, UpdatesConfig,
+            CreateConfig, CreatesConfig, DeleteByQueryConfig, DeleteConfig, DeletesConfig,
+            ImportConfig, ReadByConfig, ReadConfig, ReadConfigBuilder, ReadProjectedConfig,
+            ReadsConfig, ReplaceConfig, ReplacesConfig, UpdateConfig, UpdateConfigBuilder,
+            UpdatesConfig,
         },
-        BASE_DOC_SUFFIX,
+        output::{DocHeader, DocMetaOnly, ImportResult, RawDoc},
+        BASE_DOC_SUFFIX, BASE_IMPORT_SUFFIX,
     },
+    error::RuarangoErr::{self, InvalidPatch, NotFound, ZeroMaxAttempts},
     model::{AddHeaders, BuildUrl},
-    traits::Document,
-    types::{ArangoResult, ArangoVecResult, DocMetaResult, DocMetaVecResult},
-    utils::{doc_resp, doc_vec_resp},
+    traits::{Collection, Cursor, Document},
+    types::{
+        ArangoEither, ArangoEitherExt, ArangoMap, ArangoMapResult, ArangoResult, ArangoVecResult,
+        DocMetaResult, DocMetaVecResult,
+    },
+    utils::{doc_header_resp, doc_raw_resp, doc_resp, doc_single_resp, doc_vec_resp, json_len},
     Connection,
 };
+use anyhow::anyhow;
 use async_trait::async_trait;
-use serde::{de::DeserializeOwned, Serialize};
+use bytes::Bytes;
+use libeither::Either;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::future::Future;
+
+/// A document along with its revision, used to make an update conditional
+#[derive(Deserialize, Serialize)]
+struct Revisioned<T> {
+    #[serde(rename = "_rev")]
+    rev: String,
+    #[serde(flatten)]
+    doc: T,
+}
+
+const COLLECTION_NOT_FOUND: usize = 1203;
+
+fn is_collection_not_found(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<RuarangoErr>(),
+        Some(NotFound { err: Some(err) }) if *err.error_num() == COLLECTION_NOT_FOUND
+    )
+}
+
+/// Run the `write`, and if it fails because `collection` doesn't exist and
+/// `create_collection` was requested, create the collection and run the
+/// `write` once more
+async fn with_collection<T, F, Fut>(
+    conn: &Connection,
+    collection: &str,
+    create_collection: Option<bool>,
+    write: F,
+) -> ArangoResult<T>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = ArangoResult<T>> + Send,
+    T: Send,
+{
+    match write().await {
+        Err(e) if create_collection.unwrap_or_default() && is_collection_not_found(&e) => {
+            let config = CollConfigBuilder::default().name(collection).build()?;
+            let _coll = Collection::create_if_absent(conn, &config)
+                .await?
+                .into_result()?;
+            write().await
+        }
+        res => res,
+    }
+}
 
 #[async_trait]
 #[allow(unused_qualifications)]
@@ -36,7 +94,46 @@ impl Document for Connection {
         V: Serialize + DeserializeOwned + Send + Sync,
     {
         let url = config.build_url(BASE_DOC_SUFFIX, self)?;
-        self.post(url, None, config.document(), doc_resp).await
+        with_collection(
+            self,
+            config.collection(),
+            *config.create_collection(),
+            || self.post(url.clone(), None, config.document(), doc_resp),
+        )
+        .await
+    }
+
+    async fn create_meta<T>(&self, config: CreateConfig<T>) -> ArangoResult<DocMetaOnly>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = config.build_url(BASE_DOC_SUFFIX, self)?;
+        with_collection(
+            self,
+            config.collection(),
+            *config.create_collection(),
+            || self.post(url.clone(), None, config.document(), doc_resp),
+        )
+        .await
+    }
+
+    async fn create_raw<T, U, V>(&self, config: CreateConfig<T>) -> DocMetaResult<U, V>
+    where
+        T: Clone + Into<Bytes> + Send + Sync,
+        U: Serialize + DeserializeOwned + Send + Sync,
+        V: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let url = config.build_url(BASE_DOC_SUFFIX, self)?;
+        with_collection(
+            self,
+            config.collection(),
+            *config.create_collection(),
+            || {
+                let body = config.document().clone().into();
+                self.post_raw(url.clone(), None, "application/json", body, doc_resp)
+            },
+        )
+        .await
     }
 
     async fn creates<T, U, V>(&self, config: CreatesConfig<T>) -> DocMetaVecResult<U, V>
@@ -45,8 +142,15 @@ impl Document for Connection {
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync,
     {
+        self.check_body_size("creates", || json_len(config.document()))?;
         let url = config.build_url(BASE_DOC_SUFFIX, self)?;
-        self.post(url, None, config.document(), doc_vec_resp).await
+        with_collection(
+            self,
+            config.collection(),
+            *config.create_collection(),
+            || self.post(url.clone(), None, config.document(), doc_vec_resp),
+        )
+        .await
     }
 
     async fn read<T>(&self, config: ReadConfig) -> ArangoResult<T>
@@ -58,13 +162,89 @@ impl Document for Connection {
         self.get(url, headers, EMPTY_BODY, doc_resp).await
     }
 
+    async fn read_borrowed(&self, config: ReadConfig) -> ArangoResult<RawDoc> {
+        let url = config.build_url(BASE_DOC_SUFFIX, self)?;
+        let headers = config.add_headers()?;
+        self.get(url, headers, EMPTY_BODY, doc_raw_resp).await
+    }
+
+    async fn read_header(&self, config: ReadConfig) -> ArangoResult<DocHeader> {
+        let url = config.build_url(BASE_DOC_SUFFIX, self)?;
+        let headers = config.add_headers()?;
+        let collection = config.collection().clone();
+        let key = config.key().clone();
+        self.head(url, headers, |res| doc_header_resp(res, collection, key))
+            .await
+    }
+
+    async fn read_by<T, U>(&self, config: ReadByConfig<T>) -> ArangoResult<U>
+    where
+        T: Serialize + Send + Sync,
+        U: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let url = config.build_url(BASE_DOC_SUFFIX, self)?;
+        let headers = config.add_headers()?;
+        self.put(url, headers, config.documents(), doc_single_resp)
+            .await
+    }
+
+    async fn read_projected<T>(&self, config: ReadProjectedConfig) -> ArangoResult<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let res: ArangoEither<CursorMeta<Option<T>>> =
+            Cursor::create(self, config.cursor_config()?).await?;
+        if res.is_left() {
+            return Ok(Either::new_left(res.left_safe()?));
+        }
+        res.right_safe()?
+            .take_result()
+            .and_then(|docs| docs.into_iter().next().flatten())
+            .map(Either::new_right)
+            .ok_or_else(|| NotFound { err: None }.into())
+    }
+
     async fn reads<T, U>(&self, config: ReadsConfig<T>) -> ArangoVecResult<U>
     where
         T: Serialize + Send + Sync,
         U: Serialize + DeserializeOwned + Send + Sync,
     {
         let url = config.build_url(BASE_DOC_SUFFIX, self)?;
-        self.put(url, None, config.documents(), doc_vec_resp).await
+        let headers = config.add_headers()?;
+        self.put(url, headers, config.documents(), doc_vec_resp)
+            .await
+    }
+
+    async fn reads_map<T, U>(&self, config: ReadsConfig<T>) -> ArangoMapResult<U>
+    where
+        T: Serialize + Send + Sync,
+        U: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let keys = config.keys()?;
+        let res = self.reads(config).await?;
+
+        if res.is_left() {
+            return Ok(res.map_sync(|_| ArangoMap::new()));
+        }
+        let docs = res.right_safe()?;
+        if docs.len() != keys.len() {
+            return Err(anyhow!(
+                "expected {} read results, got {}",
+                keys.len(),
+                docs.len()
+            ));
+        }
+
+        let mut map = ArangoMap::new();
+        for (key, doc) in keys.into_iter().zip(docs) {
+            let doc = if doc.is_right() {
+                Ok(doc.right_safe()?)
+            } else {
+                Err(doc.left_safe()?)
+            };
+            let _ = map.insert(key, doc);
+        }
+        Ok(Either::new_right(map))
     }
 
     async fn replace<T, U, V>(&self, config: ReplaceConfig<T>) -> DocMetaResult<U, V>
@@ -84,6 +264,8 @@ impl Document for Connection {
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync,
     {
+        config.check_keys()?;
+        self.check_body_size("replaces", || json_len(config.documents()))?;
         let url = config.build_url(BASE_DOC_SUFFIX, self)?;
         self.put(url, None, config.documents(), doc_vec_resp).await
     }
@@ -99,12 +281,82 @@ impl Document for Connection {
         self.patch(url, headers, config.document(), doc_resp).await
     }
 
+    async fn update_value<U, V>(&self, config: UpdateConfig<Value>) -> DocMetaResult<U, V>
+    where
+        U: Serialize + DeserializeOwned + Send + Sync,
+        V: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let kind = match config.document() {
+            Value::Object(_) => return self.update(config).await,
+            Value::Null => "null",
+            Value::Bool(_) => "a boolean",
+            Value::Number(_) => "a number",
+            Value::String(_) => "a string",
+            Value::Array(_) => "an array",
+        };
+        Err(InvalidPatch {
+            kind: kind.to_string(),
+        }
+        .into())
+    }
+
+    async fn update_with_retry<T, U, F>(
+        &self,
+        collection: &str,
+        key: &str,
+        max_attempts: usize,
+        mut f: F,
+    ) -> DocMetaResult<(), ()>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        U: Clone + Serialize + Send + Sync,
+        F: FnMut(&T) -> U + Send + Sync,
+    {
+        if max_attempts == 0 {
+            return Err(ZeroMaxAttempts.into());
+        }
+        let mut attempt = 1;
+
+        loop {
+            let read_config = ReadConfigBuilder::default()
+                .collection(collection)
+                .key(key)
+                .build()?;
+            let res: ArangoEither<Revisioned<T>> = self.read(read_config).await?;
+            if res.is_left() {
+                return Ok(Either::new_left(res.left_safe()?));
+            }
+            let current = res.right_safe()?;
+
+            let config = UpdateConfigBuilder::default()
+                .collection(collection)
+                .key(key)
+                .document(f(&current.doc))
+                .if_match(current.rev)
+                .build()?;
+            match self.update(config).await {
+                Err(e)
+                    if attempt < max_attempts
+                        && matches!(
+                            e.downcast_ref::<RuarangoErr>(),
+                            Some(RuarangoErr::PreconditionFailed { .. })
+                        ) =>
+                {
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
     async fn updates<T, U, V>(&self, config: UpdatesConfig<T>) -> DocMetaVecResult<U, V>
     where
         T: Serialize + Send + Sync,
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync,
     {
+        config.check_keys()?;
+        self.check_body_size("updates", || json_len(config.documents()))?;
         let url = config.build_url(BASE_DOC_SUFFIX, self)?;
         self.patch(url, None, config.documents(), doc_vec_resp)
             .await
@@ -126,40 +378,76 @@ impl Document for Connection {
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync,
     {
+        config.check_keys()?;
+        self.check_body_size("deletes", || json_len(config.documents()))?;
         let url = config.build_url(BASE_DOC_SUFFIX, self)?;
         self.delete(url, None, config.documents(), doc_vec_resp)
             .await
     }
+
+    async fn delete_by_query(&self, config: DeleteByQueryConfig) -> ArangoResult<usize> {
+        let res: ArangoEither<CursorMeta<()>> =
+            Cursor::create(self, config.cursor_config()?).await?;
+        Ok(res.map_sync(|cursor_meta| {
+            cursor_meta
+                .extra()
+                .as_ref()
+                .map_or(0, |extra| *extra.stats().writes_executed())
+        }))
+    }
+
+    async fn import<T>(&self, config: ImportConfig<T>) -> ArangoResult<ImportResult>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = config.build_url(BASE_IMPORT_SUFFIX, self)?;
+        let body = config.body()?;
+        self.check_body_size("import", || Ok(body.len()))?;
+        self.post_raw(url, None, "text/plain", body.into(), doc_resp)
+            .await
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
+        builder::{ConnectionBuilder, ContentType, VELOCYPACK_MIME},
         doc::{
-            input::{CreateConfigBuilder, ReadConfigBuilder},
-            output::{DocMeta, OutputDoc},
+            input::{
+                CreateConfigBuilder, CreatesConfigBuilder, DeleteByQueryConfigBuilder,
+                DeletesConfigBuilder, ImportConfigBuilder, ReadByConfigBuilder, ReadConfigBuilder,
+                ReadProjectedConfigBuilder, ReadsConfigBuilder, UpdateConfigBuilder,
+                UpdatesConfigBuilder,
+            },
+            output::{DocHeader, DocMeta, ImportResult, OutputDoc, RawDoc},
         },
         error::RuarangoErr,
         traits::Document,
-        types::{ArangoEither, ArangoResult},
+        types::{ArangoEither, ArangoMap, ArangoResult, ArangoVec},
         utils::{
             default_conn, mock_auth,
+            mocks::collection::mock_create as mock_create_coll,
             mocks::doc::{
-                mock_create, mock_create_1, mock_create_2, mock_read, mock_read_if_match,
-                mock_return_new, mock_return_old,
+                mock_create, mock_create_1, mock_create_2, mock_create_raw,
+                mock_deletes_rev_mismatch, mock_deletes_silent, mock_import, mock_read,
+                mock_read_by, mock_read_by_rev_mismatch, mock_read_header, mock_read_if_match,
+                mock_reads, mock_return_new, mock_return_old, mock_updates_silent,
             },
         },
+        vpack::{decode, encode},
     };
     use anyhow::Result;
     use getset::{Getters, Setters};
     use libeither::Either;
     use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::collections::HashMap;
     use wiremock::{
-        matchers::{header_exists, method, path},
+        matchers::{body_string_contains, header, header_exists, method, path, query_param},
         Mock, MockServer, ResponseTemplate,
     };
 
-    #[derive(Clone, Deserialize, Getters, Serialize, Setters)]
+    #[derive(Clone, Debug, Deserialize, Getters, Serialize, Setters)]
     #[getset(get, set)]
     struct TestDoc {
         #[serde(rename = "_key", skip_serializing_if = "Option::is_none")]
@@ -182,6 +470,238 @@ mod test {
         }
     }
 
+    #[tokio::test]
+    async fn create_collection_on_demand() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/document/test_coll"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "code": 404, "error": true, "errorMessage": "collection or view not found", "errorNum": 1203
+            })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mock_create_coll(&mock_server).await;
+        mock_create(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .collection("test_coll")
+            .document(TestDoc::default())
+            .create_collection(true)
+            .build()?;
+        let res: ArangoEither<DocMeta<(), ()>> = conn.create(config).await?;
+        assert!(res.is_right());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_collection_only_on_collection_not_found() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/document/test_coll"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "code": 404, "error": true, "errorMessage": "document not found", "errorNum": 1202
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/collection"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .collection("test_coll")
+            .document(TestDoc::default())
+            .create_collection(true)
+            .build()?;
+        let res: ArangoResult<DocMeta<(), ()>> = conn.create(config).await;
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::NotFound { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn creates_body_too_large() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/document/test_coll"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .max_request_body_bytes(64)
+            .build()
+            .await?;
+        let config = CreatesConfigBuilder::default()
+            .collection("test_coll")
+            .document(vec![TestDoc::default(); 10])
+            .build()?;
+        let res: ArangoResult<ArangoVec<DocMeta<(), ()>>> = conn.creates(config).await;
+        match res.unwrap_err().downcast_ref::<RuarangoErr>() {
+            Some(RuarangoErr::RequestBodyTooLarge {
+                operation,
+                size,
+                max,
+            }) => {
+                assert_eq!("creates", operation);
+                assert!(*size > 64);
+                assert_eq!(64, *max);
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn creates_payload_too_large() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/document/test_coll"))
+            .respond_with(ResponseTemplate::new(413).set_body_json(json!({
+                "code": 413, "error": true, "errorMessage": "request too large", "errorNum": 413
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreatesConfigBuilder::default()
+            .collection("test_coll")
+            .document(vec![TestDoc::default(); 10])
+            .build()?;
+        let res: ArangoResult<ArangoVec<DocMeta<(), ()>>> = conn.creates(config).await;
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::PayloadTooLarge { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_raw() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create_raw(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .collection("test_coll")
+            .document(r#"{"_key":"abc","test":"raw"}"#)
+            .build()?;
+        let res: ArangoEither<DocMeta<(), ()>> = conn.create_raw(config).await?;
+        let meta = res.right_safe()?;
+        assert_eq!(meta.key(), "abc");
+        assert!(!meta.id().is_empty());
+        assert!(!meta.rev().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_value() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("PATCH"))
+            .and(path("_db/keti/_api/document/test_coll/abc"))
+            .and(query_param("returnNew", "true"))
+            .and(body_string_contains(r#""new_attr":42"#))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "_id": "test_coll/abc",
+                "_key": "abc",
+                "_rev": "_2",
+                "_oldRev": "_1",
+                "new": { "_id": "test_coll/abc", "_key": "abc", "_rev": "_2", "test": "test", "new_attr": 42 }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = UpdateConfigBuilder::default()
+            .collection("test_coll")
+            .key("abc")
+            .document(json!({ "new_attr": 42 }))
+            .return_new(true)
+            .build()?;
+        let res: ArangoEither<DocMeta<serde_json::Value, ()>> = conn.update_value(config).await?;
+        let meta = res.right_safe()?;
+        let new_doc = meta
+            .new_doc()
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("missing new document"))?;
+        assert_eq!(json!(42), new_doc["new_attr"]);
+        assert_eq!(json!("test"), new_doc["test"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_value_not_an_object() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = UpdateConfigBuilder::default()
+            .collection("test_coll")
+            .key("abc")
+            .document(json!([{ "new_attr": 42 }]))
+            .build()?;
+        let res: ArangoResult<DocMeta<(), ()>> = conn.update_value(config).await;
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::InvalidPatch { .. })
+        ));
+        assert_eq!(
+            "The update patch must be a JSON object, not an array",
+            format!("{err}")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_meta() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default()
+            .collection("test_coll")
+            .document(TestDoc::default())
+            .build()?;
+        let res = conn.create_meta(config).await?.right_safe()?;
+        assert_eq!(res.key(), "abc");
+        assert_eq!(res.id(), "def");
+        assert_eq!(res.rev(), "ghi");
+        assert!(res.old_rev().is_none());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn basic_create() -> Result<()> {
         let mock_server = MockServer::start().await;
@@ -349,87 +869,500 @@ mod test {
     }
 
     #[tokio::test]
-    async fn read() -> Result<()> {
+    async fn read_by() -> Result<()> {
         let mock_server = MockServer::start().await;
         mock_auth(&mock_server).await;
-        mock_read(&mock_server).await?;
+        mock_read_by(&mock_server).await?;
 
         let conn = default_conn(mock_server.uri()).await?;
-        let config = ReadConfigBuilder::default()
+        let config = ReadByConfigBuilder::default()
             .collection("test_coll")
-            .key("test_doc")
+            .document(json!({ "_key": "test_doc" }))
             .build()?;
-        let outer_either: ArangoEither<OutputDoc> = conn.read(config).await?;
-        assert!(outer_either.is_right());
-        let doc = outer_either.right_safe()?;
+        let either: ArangoEither<OutputDoc> = conn.read_by(config).await?;
+        assert!(either.is_right());
+        let doc = either.right_safe()?;
         assert_eq!(doc.key(), "abc");
-        assert!(!doc.id().is_empty());
-        assert!(!doc.rev().is_empty());
         assert_eq!(doc.test(), "test");
 
         Ok(())
     }
 
-    async fn mock_read_if_none_match(mock_server: &MockServer) -> Result<()> {
-        let mock_response = ResponseTemplate::new(304);
+    #[tokio::test]
+    async fn read_by_rev_mismatch() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_by_rev_mismatch(&mock_server).await?;
 
-        let mock_builder = Mock::given(method("GET"))
-            .and(path("_db/keti/_api/document/test_coll/test_doc"))
-            .and(header_exists("if-none-match"));
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadByConfigBuilder::default()
+            .collection("test_coll")
+            .document(json!({ "_key": "test_doc", "_rev": "bad_rev" }))
+            .ignore_revs(false)
+            .build()?;
+        let res: ArangoResult<OutputDoc> = conn.read_by(config).await;
+        match res {
+            Ok(_) => panic!("The read should fail!"),
+            Err(e) => assert!(matches!(
+                e.downcast_ref::<RuarangoErr>(),
+                Some(RuarangoErr::PreconditionFailed { err: Some(_), .. })
+            )),
+        }
 
-        mock_builder
-            .respond_with(mock_response)
-            .up_to_n_times(1)
-            .mount(mock_server)
-            .await;
         Ok(())
     }
 
     #[tokio::test]
-    async fn read_if_none_match() -> Result<()> {
+    async fn read_if_match_current_rev() -> Result<()> {
         let mock_server = MockServer::start().await;
         mock_auth(&mock_server).await;
-        mock_read_if_none_match(&mock_server).await?;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .and(header("if-match", "_stale"))
+            .respond_with(ResponseTemplate::new(412).set_body_json(json!({
+                "error": true,
+                "code": 412,
+                "errorNum": 1200,
+                "errorMessage": "conflict",
+                "_key": "test_doc",
+                "_id": "test_coll/test_doc",
+                "_rev": "_current"
+            })))
+            .mount(&mock_server)
+            .await;
 
         let conn = default_conn(mock_server.uri()).await?;
         let config = ReadConfigBuilder::default()
             .collection("test_coll")
             .key("test_doc")
-            .if_none_match("_cIw-YT6---")
+            .if_match("_stale")
             .build()?;
         let res: ArangoResult<OutputDoc> = conn.read(config).await;
-        assert!(res.is_err());
+        let err = res.unwrap_err();
+        match err.downcast_ref::<RuarangoErr>() {
+            Some(RuarangoErr::PreconditionFailed { current_rev, .. }) => {
+                assert_eq!(current_rev.as_deref(), Some("_current"));
+            }
+            _ => panic!("Incorrect error!"),
+        }
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn read_if_match() -> Result<()> {
+    async fn velocypack_round_trip() -> Result<()> {
         let mock_server = MockServer::start().await;
         mock_auth(&mock_server).await;
-        mock_read_if_match(&mock_server).await?;
+        let meta = json!({ "_key": "abc", "_id": "test_coll/abc", "_rev": "_a" });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/document/test_coll"))
+            .and(header("content-type", VELOCYPACK_MIME))
+            .and(header("accept", VELOCYPACK_MIME))
+            .respond_with(ResponseTemplate::new(201).set_body_raw(encode(&meta), VELOCYPACK_MIME))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let doc = json!({ "_key": "abc", "_id": "test_coll/abc", "_rev": "_a", "test": "test" });
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/abc"))
+            .and(header("accept", VELOCYPACK_MIME))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(encode(&doc), VELOCYPACK_MIME))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .content_type(ContentType::VelocyPack)
+            .build()
+            .await?;
+        let mut test_doc = TestDoc::default();
+        let _ = test_doc.set_key(Some("abc".to_string()));
+        let config = CreateConfigBuilder::default()
+            .collection("test_coll")
+            .document(test_doc)
+            .build()?;
+        let res: ArangoEither<DocMeta<(), ()>> = conn.create(config).await?;
+        assert_eq!("abc", res.right_safe()?.key());
+
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        let create = requests
+            .iter()
+            .find(|request| {
+                request.method.as_str() == "POST" && request.url.path().ends_with("test_coll")
+            })
+            .map(|request| decode(&request.body))
+            .transpose()?;
+        assert_eq!(Some(json!({ "_key": "abc", "test": "test" })), create);
 
-        let conn = default_conn(mock_server.uri()).await?;
         let config = ReadConfigBuilder::default()
             .collection("test_coll")
-            .key("test_doc")
-            .if_match("_cIw-YT6---")
+            .key("abc")
             .build()?;
-        let outer_either: ArangoEither<OutputDoc> = conn.read(config).await?;
-        assert!(outer_either.is_right());
-        let doc = outer_either.right_safe()?;
+        let res: ArangoEither<OutputDoc> = conn.read(config).await?;
+        let doc = res.right_safe()?;
         assert_eq!(doc.key(), "abc");
-        assert!(!doc.id().is_empty());
-        assert!(!doc.rev().is_empty());
+        assert_eq!(doc.rev(), "_a");
         assert_eq!(doc.test(), "test");
 
         Ok(())
     }
 
-    async fn mock_read_if_match_fail(mock_server: &MockServer) -> Result<()> {
-        let mock_response = ResponseTemplate::new(412);
-
-        let mock_builder = Mock::given(method("GET"))
+    #[tokio::test]
+    async fn read() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let outer_either: ArangoEither<OutputDoc> = conn.read(config).await?;
+        assert!(outer_either.is_right());
+        let doc = outer_either.right_safe()?;
+        assert_eq!(doc.key(), "abc");
+        assert!(!doc.id().is_empty());
+        assert!(!doc.rev().is_empty());
+        assert_eq!(doc.test(), "test");
+
+        Ok(())
+    }
+
+    async fn mock_read_raw(mock_server: &MockServer, body: &str) {
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn read_large_integer() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_raw(
+            &mock_server,
+            r#"{"_key":"test_doc","big":12345678901234567}"#,
+        )
+        .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let doc: serde_json::Value = conn.read(config).await?.right_safe()?;
+        assert_eq!(Some(12_345_678_901_234_567), doc["big"].as_u64());
+        assert_eq!("12345678901234567", doc["big"].to_string());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_unexpected_shape() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_raw(&mock_server, r#"{"_key":"test_doc","unexpected":[1,2,3]}"#).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let err = conn.read::<TestDoc>(config).await.unwrap_err();
+        match err.downcast_ref::<RuarangoErr>() {
+            Some(RuarangoErr::InvalidBody { err, body }) => {
+                assert!(err.contains("missing field `test`"));
+                assert_eq!(r#"{"_key":"test_doc","unexpected":[1,2,3]}"#, body);
+            }
+            _ => panic!("expected an invalid body error, got: {}", err),
+        }
+        assert!(err.to_string().contains(r#""unexpected":[1,2,3]"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_unexpected_shape_truncated() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_raw(&mock_server, r#"{"_key":"test_doc","unexpected":[1,2,3]}"#).await;
+
+        let conn = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti")
+            .max_error_body_len(18)
+            .build()
+            .await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let err = conn.read::<TestDoc>(config).await.unwrap_err();
+        match err.downcast_ref::<RuarangoErr>() {
+            Some(RuarangoErr::InvalidBody { body, .. }) => {
+                assert_eq!(r#"{"_key":"test_doc"..."#, body);
+            }
+            _ => panic!("expected an invalid body error, got: {}", err),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_unexpected_error_body() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .respond_with(ResponseTemplate::new(502).set_body_string("<html>bad gateway</html>"))
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let err = conn.read::<TestDoc>(config).await.unwrap_err();
+        match err.downcast_ref::<RuarangoErr>() {
+            Some(RuarangoErr::InvalidDocResponse {
+                status: 502,
+                err: None,
+                body: Some(body),
+            }) => assert_eq!("<html>bad gateway</html>", body),
+            _ => panic!("expected an invalid document response, got: {}", err),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[tokio::test]
+    async fn read_arbitrary_precision() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_raw(
+            &mock_server,
+            r#"{"_key":"test_doc","huge":123456789012345678901234567890,"precise":0.12345678901234567890123}"#,
+        )
+        .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let doc: serde_json::Value = conn.read(config).await?.right_safe()?;
+        assert_eq!("123456789012345678901234567890", doc["huge"].to_string());
+        assert_eq!("0.12345678901234567890123", doc["precise"].to_string());
+        assert_eq!(
+            r#"{"_key":"test_doc","huge":123456789012345678901234567890,"precise":0.12345678901234567890123}"#,
+            serde_json::to_string(&doc)?
+        );
+
+        Ok(())
+    }
+
+    #[derive(Deserialize)]
+    struct BorrowedDoc<'a> {
+        #[serde(rename = "_key")]
+        key: &'a str,
+        test: &'a str,
+    }
+
+    #[tokio::test]
+    async fn read_borrowed() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let either: ArangoEither<RawDoc> = conn.read_borrowed(config).await?;
+        assert!(either.is_right());
+        let raw = either.right_safe()?;
+        let doc: BorrowedDoc<'_> = raw.doc()?;
+        assert_eq!(doc.key, "abc");
+        assert_eq!(doc.test, "test");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_borrowed_not_found() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": true,
+                "code": 404,
+                "errorNum": 1202,
+                "errorMessage": "document not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let err = conn.read_borrowed(config).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::NotFound { err: Some(_) })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_header() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_header(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .build()?;
+        let either: ArangoEither<DocHeader> = conn.read_header(config).await?;
+        assert!(either.is_right());
+        let header = either.right_safe()?;
+        assert_eq!(header.id(), "test_coll/test_doc");
+        assert_eq!(header.key(), "test_doc");
+        assert_eq!(header.rev(), "_cIw-YT6---");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn import() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_import(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ImportConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec![TestDoc::default(), TestDoc::default()])
+            .build()?;
+        let either: ArangoEither<ImportResult> = conn.import(config).await?;
+        assert!(either.is_right());
+        let res = either.right_safe()?;
+        assert_eq!(*res.created(), 2);
+        assert_eq!(*res.errors(), 0);
+        assert!(res.details().is_none());
+
+        Ok(())
+    }
+
+    async fn mock_read_dirty(mock_server: &MockServer) -> Result<()> {
+        let mock_response = ResponseTemplate::new(200).set_body_json(OutputDoc::default());
+
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .and(header("x-arango-allow-dirty-read", "true"))
+            .respond_with(mock_response)
+            .up_to_n_times(1)
+            .mount(mock_server)
+            .await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_allow_dirty_read() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_dirty(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .allow_dirty_read(true)
+            .build()?;
+        let outer_either: ArangoEither<OutputDoc> = conn.read(config).await?;
+        assert!(outer_either.is_right());
+        let doc = outer_either.right_safe()?;
+        assert_eq!(doc.key(), "abc");
+
+        Ok(())
+    }
+
+    async fn mock_read_if_none_match(mock_server: &MockServer) -> Result<()> {
+        let mock_response = ResponseTemplate::new(304);
+
+        let mock_builder = Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_doc"))
+            .and(header_exists("if-none-match"));
+
+        mock_builder
+            .respond_with(mock_response)
+            .up_to_n_times(1)
+            .mount(mock_server)
+            .await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_if_none_match() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_if_none_match(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .if_none_match("_cIw-YT6---")
+            .build()?;
+        let res: ArangoResult<OutputDoc> = conn.read(config).await;
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_if_match() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_if_match(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_doc")
+            .if_match("_cIw-YT6---")
+            .build()?;
+        let outer_either: ArangoEither<OutputDoc> = conn.read(config).await?;
+        assert!(outer_either.is_right());
+        let doc = outer_either.right_safe()?;
+        assert_eq!(doc.key(), "abc");
+        assert!(!doc.id().is_empty());
+        assert!(!doc.rev().is_empty());
+        assert_eq!(doc.test(), "test");
+
+        Ok(())
+    }
+
+    async fn mock_read_if_match_fail(mock_server: &MockServer) -> Result<()> {
+        let mock_response = ResponseTemplate::new(412);
+
+        let mock_builder = Mock::given(method("GET"))
             .and(path("_db/keti/_api/document/test_coll/test_doc"))
             .and(header_exists("if-match"));
 
@@ -458,4 +1391,379 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn deletes_rev_mismatch() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_deletes_rev_mismatch(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = DeletesConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec![
+                json!({ "_key": "abc", "_rev": "_fresh" }),
+                json!({ "_key": "def", "_rev": "_stale" }),
+            ])
+            .ignore_revs(false)
+            .build()?;
+        let res: ArangoEither<ArangoVec<DocMeta<(), ()>>> = conn.deletes(config).await?;
+        assert!(res.is_right());
+        let results = res.right_safe()?;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_right());
+        assert_eq!(results[0].right_ref()?.key(), "abc");
+        assert!(results[1].is_left());
+        assert_eq!(*results[1].left_ref()?.error_num(), 1200);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deletes_silent() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_deletes_silent(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = DeletesConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec!["abc", "def"])
+            .silent(true)
+            .build()?;
+        let res: ArangoEither<ArangoVec<DocMeta<(), ()>>> = conn.deletes(config).await?;
+        assert!(res.is_right());
+        assert!(res.right_safe()?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn updates_silent() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_updates_silent(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = UpdatesConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec![json!({ "_key": "abc", "test": "updated" })])
+            .silent(true)
+            .return_new(true)
+            .build()?;
+        let res: ArangoEither<ArangoVec<DocMeta<(), ()>>> = conn.updates(config).await?;
+        assert!(res.is_right());
+        assert!(res.right_safe()?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reads_in_request_order() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_reads(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadsConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec!["a", "b", "c"])
+            .build()?;
+        let res: ArangoEither<ArangoVec<TestDoc>> = conn.reads(config).await?;
+        let docs = res.right_safe()?;
+        assert_eq!(3, docs.len());
+        assert_eq!(Some(&"a".to_string()), docs[0].right_ref()?.key().as_ref());
+        assert_eq!(1202, *docs[1].left_ref()?.error_num());
+        assert_eq!(Some(&"c".to_string()), docs[2].right_ref()?.key().as_ref());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reads_map() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_reads(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadsConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec![
+                json!({ "_key": "a" }),
+                json!("b"),
+                json!("test_coll/c"),
+            ])
+            .build()?;
+        let res: ArangoEither<ArangoMap<TestDoc>> = conn.reads_map(config).await?;
+        let docs = res.right_safe()?;
+        assert_eq!(3, docs.len());
+        assert_eq!("a", docs["a"].as_ref().map(TestDoc::test).unwrap());
+        assert_eq!(1202, *docs["b"].as_ref().unwrap_err().error_num());
+        assert_eq!("c", docs["c"].as_ref().map(TestDoc::test).unwrap());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn updates_validate_keys() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = UpdatesConfigBuilder::default()
+            .collection("test_coll")
+            .documents(vec![
+                json!({ "_key": "abc", "test": "updated" }),
+                json!({ "test": "updated" }),
+            ])
+            .validate_keys(true)
+            .build()?;
+        let res: ArangoResult<ArangoVec<DocMeta<(), ()>>> = conn.updates(config).await;
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::MissingKey { index: 1 })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_by_query() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        let body = json!({
+            "result": [],
+            "hasMore": false,
+            "cached": false,
+            "extra": {
+                "stats": {
+                    "writesExecuted": 2,
+                    "writesIgnored": 0,
+                    "scannedFull": 5,
+                    "scannedIndex": 0,
+                    "filtered": 3,
+                    "httpRequests": 0,
+                    "executionTime": 0.001,
+                    "peakMemoryUsage": 0
+                },
+                "warnings": []
+            },
+            "code": 201,
+            "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .and(body_string_contains(
+                "FOR d IN @@collection FILTER d.test == @test REMOVE d IN @@collection",
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert("test".to_string(), json!("delete"));
+        let config = DeleteByQueryConfigBuilder::default()
+            .collection("test_coll")
+            .filter("d.test == @test")
+            .bind_vars(bind_vars)
+            .build()?;
+        let res = conn.delete_by_query(config).await?;
+        assert_eq!(2, res.right_safe()?);
+
+        Ok(())
+    }
+
+    async fn mock_read_projected(mock_server: &MockServer, doc: serde_json::Value) {
+        let body = json!({
+            "result": [doc],
+            "hasMore": false,
+            "cached": false,
+            "code": 201,
+            "error": false
+        });
+        Mock::given(method("POST"))
+            .and(path("_db/keti/_api/cursor"))
+            .and(body_string_contains("KEEP(d, @attr0)"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(body))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Projected {
+        test: String,
+    }
+
+    #[tokio::test]
+    async fn read_projected() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_projected(&mock_server, json!({ "test": "projected" })).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadProjectedConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_key")
+            .attribute("test")
+            .build()?;
+        let res: ArangoEither<Projected> = conn.read_projected(config).await?;
+        assert_eq!("projected", res.right_safe()?.test);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_projected_not_found() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_read_projected(&mock_server, serde_json::Value::Null).await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = ReadProjectedConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_key")
+            .attribute("test")
+            .build()?;
+        let err = conn.read_projected::<Projected>(config).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::NotFound { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_with_retry() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        for rev in &["_a", "_b"] {
+            Mock::given(method("GET"))
+                .and(path("_db/keti/_api/document/test_coll/test_key"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!({ "_key": "test_key", "_rev": rev, "test": "test" })),
+                )
+                .up_to_n_times(1)
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("PATCH"))
+            .and(path("_db/keti/_api/document/test_coll/test_key"))
+            .and(header("if-match", "_a"))
+            .respond_with(ResponseTemplate::new(412).set_body_json(json!({
+                "error": true,
+                "code": 412,
+                "errorNum": 1200,
+                "errorMessage": "conflict",
+                "_rev": "_b"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("_db/keti/_api/document/test_coll/test_key"))
+            .and(header("if-match", "_b"))
+            .and(body_string_contains("updated"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+                "_key": "test_key",
+                "_id": "test_coll/test_key",
+                "_rev": "_c",
+                "_oldRev": "_b"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let mut calls = 0;
+        let res = conn
+            .update_with_retry("test_coll", "test_key", 3, |doc: &TestDoc| {
+                calls += 1;
+                json!({ "test": format!("{} updated", doc.test()) })
+            })
+            .await?;
+        let doc_meta = res.right_safe()?;
+        assert_eq!("_c", doc_meta.rev());
+        assert_eq!(2, calls);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_with_retry_gives_up() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_key"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "_key": "test_key", "_rev": "_a", "test": "test" })),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("_db/keti/_api/document/test_coll/test_key"))
+            .respond_with(ResponseTemplate::new(412).set_body_json(json!({
+                "error": true,
+                "code": 412,
+                "errorNum": 1200,
+                "errorMessage": "conflict"
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let err = conn
+            .update_with_retry(
+                "test_coll",
+                "test_key",
+                2,
+                |_doc: &TestDoc| json!({ "test": "updated" }),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::PreconditionFailed { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_with_retry_zero_attempts() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        Mock::given(method("GET"))
+            .and(path("_db/keti/_api/document/test_coll/test_key"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let err = conn
+            .update_with_retry(
+                "test_coll",
+                "test_key",
+                0,
+                |_doc: &TestDoc| json!({ "test": "updated" }),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::ZeroMaxAttempts)
+        ));
+
+        Ok(())
+    }
 }
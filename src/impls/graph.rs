@@ -8,7 +8,7 @@
 
 //! Graph trait implementation
 
-use super::EMPTY_BODY;
+use super::{cursor::cursor_all, EMPTY_BODY};
 use crate::{
     cursor::BASE_CURSOR_SUFFIX,
     graph::{
@@ -16,23 +16,26 @@ use crate::{
             CreateConfig, CreateEdgeDefConfig, CreateVertexCollConfig, CreateVertexConfig,
             DeleteConfig, DeleteEdgeDefConfig, DeleteVertexCollConfig, DeleteVertexConfig,
             EdgeCreateConfig, EdgeDeleteConfig, EdgeReadConfig, EdgeReplaceConfig,
-            EdgeUpdateConfig, ReadConfig, ReadEdgeDefsConfig, ReadVertexCollsConfig,
-            ReadVertexConfig, ReplaceEdgeDefConfig, UpdateVertexConfig,
+            EdgeUpdateConfig, ReadConfig, ReadConfigBuilder, ReadEdgeDefsConfig,
+            ReadVertexCollsConfig, ReadVertexConfig, ReplaceEdgeDefConfig, ShortestPathConfig,
+            TraversalConfig, UpdateVertexConfig,
         },
         output::{
-            CreateEdge, DeleteEdge, DeleteVertexMeta, EdgesMeta, GraphMeta, List, ReadEdge,
-            ReadVertexMeta, ReplaceEdge, UpdateEdge, UpdateVertexMeta, VertexColls, VertexMeta,
+            CreateEdge, DeleteEdge, DeleteVertexMeta, EdgesMeta, GraphMeta, List, Path, PathStep,
+            ReadEdge, ReadVertexMeta, ReplaceEdge, UpdateEdge, UpdateVertexMeta, VertexColls,
+            VertexMeta,
         },
         BASE_GRAPH_SUFFIX,
     },
     model::{AddHeaders, BuildUrl},
     traits::Graph,
     utils::{empty, handle_response, map_resp},
-    ArangoResult, Connection,
+    ArangoEither, ArangoEitherExt, ArangoResult, Connection,
 };
 use anyhow::Context;
 use async_trait::async_trait;
-use serde::Serialize;
+use libeither::Either;
+use serde::{de::DeserializeOwned, Serialize};
 
 #[async_trait]
 #[allow(unused_qualifications)]
@@ -67,17 +70,31 @@ impl Graph for Connection {
 
     async fn read_edge_defs(&self, config: ReadEdgeDefsConfig) -> ArangoResult<EdgesMeta> {
         let url = config.build_url(BASE_GRAPH_SUFFIX, self)?;
-        self.get(url, None, EMPTY_BODY, handle_response).await
+        let res: ArangoEither<EdgesMeta> = self.get(url, None, EMPTY_BODY, handle_response).await?;
+
+        if res.is_left() {
+            return Ok(res);
+        }
+
+        // The endpoint only returns the collection names, so the `from` and
+        // `to` vertex collections are taken from the graph itself
+        let mut edges_meta = res.right_safe()?;
+        let read_config = ReadConfigBuilder::default().name(config.name()).build()?;
+        let graph_meta = self.read(read_config).await?.into_result()?;
+        edges_meta.set_edge_definitions(graph_meta.graph().edge_definitions().clone());
+        Ok(Either::new_right(edges_meta))
     }
 
     async fn delete_edge_def(&self, config: DeleteEdgeDefConfig) -> ArangoResult<GraphMeta> {
         let url = config.build_url(BASE_GRAPH_SUFFIX, self)?;
-        self.delete(url, None, EMPTY_BODY, handle_response).await
+        let headers = config.add_headers()?;
+        self.delete(url, headers, EMPTY_BODY, handle_response).await
     }
 
     async fn replace_edge_def(&self, config: ReplaceEdgeDefConfig) -> ArangoResult<GraphMeta> {
         let url = config.build_url(BASE_GRAPH_SUFFIX, self)?;
-        self.put(url, None, config.edge_def(), handle_response)
+        let headers = config.add_headers()?;
+        self.put(url, headers, config.edge_def(), handle_response)
             .await
     }
 
@@ -96,7 +113,7 @@ impl Graph for Connection {
     async fn read_edge(&self, config: EdgeReadConfig) -> ArangoResult<ReadEdge> {
         let url = config.build_url(BASE_GRAPH_SUFFIX, self)?;
         let headers = config.add_headers()?;
-        self.get(url, headers, EMPTY_BODY, handle_response).await
+        self.get(url, headers, EMPTY_BODY, map_resp).await
     }
 
     async fn update_edge<T>(&self, config: EdgeUpdateConfig<T>) -> ArangoResult<UpdateEdge>
@@ -176,4 +193,25 @@ impl Graph for Connection {
         let headers = config.add_headers()?;
         self.put(url, headers, config.vertex(), map_resp).await
     }
+
+    async fn traverse<V, E>(&self, config: TraversalConfig) -> ArangoResult<Vec<Path<V, E>>>
+    where
+        V: Serialize + DeserializeOwned + Send + Sync,
+        E: Serialize + DeserializeOwned + Send + Sync,
+    {
+        cursor_all(self, config.cursor_config()?).await
+    }
+
+    async fn shortest_path<V, E>(
+        &self,
+        config: ShortestPathConfig,
+    ) -> ArangoResult<Option<Path<V, E>>>
+    where
+        V: Serialize + DeserializeOwned + Send + Sync,
+        E: Serialize + DeserializeOwned + Send + Sync,
+    {
+        let res: ArangoEither<Vec<PathStep<V, E>>> =
+            cursor_all(self, config.cursor_config()?).await?;
+        Ok(res.map_right(Path::from_steps)?)
+    }
 }
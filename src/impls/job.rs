@@ -9,19 +9,38 @@
 //! Job trait implementation
 
 use crate::{
-    api_get, api_put,
+    api_delete, api_get,
     conn::Connection,
     traits::Job,
-    utils::{doc_resp, handle_response},
+    utils::{cancel_resp, doc_resp, handle_response},
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use const_format::concatcp;
-use futures::FutureExt;
-use serde::{de::DeserializeOwned, Serialize};
+use reqwest::{Error, Response};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 const BASE_SUFFIX: &str = "_api/job";
 const DONE_SUFFIX: &str = concatcp!(BASE_SUFFIX, "/done#by-type");
+const LIST_PENDING_SUFFIX: &str = concatcp!(BASE_SUFFIX, "/pending");
+const LIST_DONE_SUFFIX: &str = concatcp!(BASE_SUFFIX, "/done");
+
+#[derive(Deserialize)]
+struct JobResult {
+    result: bool,
+}
+
+/// Fetch the result of the job with the given id.  The server hands out the
+/// result only once, so the request is never retried.
+async fn fetch_job(conn: &Connection, id: &str) -> Result<std::result::Result<Response, Error>> {
+    let job_id_url = format!("{BASE_SUFFIX}/{id}");
+    let current_url = conn
+        .db_url()
+        .join(&job_id_url)
+        .with_context(|| format!("Unable to build '{job_id_url}' url"))?;
+    conn.send(false, false, |client| client.put(current_url.clone()))
+        .await
+}
 
 #[async_trait]
 #[allow(unused_qualifications)]
@@ -32,7 +51,9 @@ impl Job for Connection {
             .db_url()
             .join(&job_id_url)
             .with_context(|| format!("Unable to build '{job_id_url}' url"))?;
-        let res = self.client().get(current_url).send().await?;
+        let res = self
+            .send(false, true, |client| client.get(current_url.clone()))
+            .await??;
         Ok(res.status().as_u16())
     }
 
@@ -40,17 +61,101 @@ impl Job for Connection {
     where
         T: Serialize + DeserializeOwned + Send + Sync,
     {
-        api_put!(self, db_url, &format!("{BASE_SUFFIX}/{id}"))
+        handle_response(fetch_job(self, id).await?).await
     }
 
     async fn fetch_doc_job<T>(&self, id: &str) -> Result<T>
     where
         T: Serialize + DeserializeOwned + Send + Sync,
     {
-        api_put!(self, db_url, &format!("{BASE_SUFFIX}/{id}") => doc_resp)
+        doc_resp(fetch_job(self, id).await?).await
     }
 
     async fn jobs(&self, _kind: &str) -> Result<Vec<String>> {
         api_get!(self, db_url, DONE_SUFFIX)
     }
+
+    async fn list_pending(&self) -> Result<Vec<String>> {
+        api_get!(self, db_url, LIST_PENDING_SUFFIX)
+    }
+
+    async fn list_done(&self) -> Result<Vec<String>> {
+        api_get!(self, db_url, LIST_DONE_SUFFIX)
+    }
+
+    async fn cancel(&self, id: &str) -> Result<bool> {
+        let cancel_url = format!("{BASE_SUFFIX}/{id}/cancel");
+        let current_url = self
+            .db_url()
+            .join(&cancel_url)
+            .with_context(|| format!("Unable to build '{cancel_url}' url"))?;
+        let id = id.to_string();
+        let res = self
+            .send(false, true, |client| client.put(current_url.clone()))
+            .await?;
+        let res: JobResult = cancel_resp(res, id).await?;
+        Ok(res.result)
+    }
+
+    async fn delete_all_jobs(&self, kind: &str) -> Result<bool> {
+        let res: Result<JobResult> = api_delete!(self, db_url, &format!("{BASE_SUFFIX}/{kind}"));
+        Ok(res?.result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Job;
+    use crate::{
+        error::RuarangoErr,
+        utils::{
+            default_conn, mock_auth,
+            mocks::job::{mock_cancel, mock_cancel_not_cancellable, mock_list_pending},
+        },
+    };
+    use anyhow::Result;
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn list_pending() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_list_pending(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let pending = conn.list_pending().await?;
+        assert_eq!(pending, vec!["123456".to_string(), "123457".to_string()]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_cancel(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        assert!(conn.cancel("123456").await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_not_cancellable() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_cancel_not_cancellable(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        match conn.cancel("123457").await {
+            Ok(_) => panic!("The cancel should fail!"),
+            Err(e) => assert!(matches!(
+                e.downcast_ref::<RuarangoErr>(),
+                Some(RuarangoErr::JobNotCancellable { id }) if id == "123457"
+            )),
+        }
+
+        Ok(())
+    }
 }
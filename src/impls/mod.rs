@@ -8,12 +8,16 @@
 
 //! Trait impls for `[Connection](crate::Connection)`
 
+mod admin;
+mod analyzer;
 mod coll;
 mod cursor;
 mod db;
 mod doc;
 mod graph;
 mod job;
+mod query;
+mod view;
 
 pub(crate) const EMPTY_BODY: Option<String> = None;
 
@@ -22,28 +26,34 @@ pub(crate) const EMPTY_BODY: Option<String> = None;
 macro_rules! api_request {
     () => {};
     ($self:ident, $url:ident, GET, $headers:expr) => {
-        Ok($self.client().get($url).headers($headers).send().then(handle_response).await?)
+        Ok(handle_response($self.send(false, true, |client| client.get($url.clone()).headers($headers.clone())).await?).await?)
     };
     ($self:ident, $url:ident, GET) => {
-        Ok($self.client().get($url).send().then(handle_response).await?)
+        Ok(handle_response($self.send(false, true, |client| client.get($url.clone())).await?).await?)
     };
     ($self:ident, $url:ident, DELETE) => {
-        Ok($self.client().delete($url).send().then(handle_response).await?)
+        Ok(handle_response($self.send(false, true, |client| client.delete($url.clone())).await?).await?)
     };
     ($self:ident, $url:ident, PUT) => {
-        Ok($self.client().put($url).send().then(handle_response).await?)
+        Ok(handle_response($self.send(false, true, |client| client.put($url.clone())).await?).await?)
     };
     ($self:ident, $url:ident, PUT => $handler:ident) => {
-        Ok($self.client().put($url).send().then($handler).await?)
+        Ok($handler($self.send(false, true, |client| client.put($url.clone())).await?).await?)
     };
     ($self:ident, $url:ident, PUT, $json:expr) => {
-        Ok($self.client().put($url).json($json).send().then(handle_response).await?)
+        {
+            let body = $self.encode($json)?;
+            Ok(handle_response($self.send(false, true, |client| $self.with_body(client.put($url.clone()), &body)).await?).await?)
+        }
     };
     ($self:ident, $url:ident, DELETE) => {
-        Ok($self.client().delete($url).send().then(handle_response).await?)
+        Ok(handle_response($self.send(false, true, |client| client.delete($url.clone())).await?).await?)
     };
     ($self:ident, $url:ident, POST, $json:expr) => {
-        Ok($self.client().post($url).json($json).send().then(handle_response).await?)
+        {
+            let body = $self.encode($json)?;
+            Ok(handle_response($self.send(false, true, |client| $self.with_body(client.post($url.clone()), &body)).await?).await?)
+        }
     };
     ($self:ident, $url:ident, $suffix:expr, $($tail:tt)*) => {
         {
@@ -100,25 +110,31 @@ macro_rules! api_put {
 macro_rules! api_request_async {
     () => {};
     ($self:ident, $url:ident, GET, $headers:expr) => {
-        $self.async_client().get($url).headers($headers).send().await?
+        $self.send(true, true, |client| client.get($url.clone()).headers($headers.clone())).await??
     };
     ($self:ident, $url:ident, GET) => {
-        $self.async_client().get($url).send().await?
+        $self.send(true, true, |client| client.get($url.clone())).await??
     };
     ($self:ident, $url:ident, DELETE) => {
-        $self.async_client().delete($url).send().await?
+        $self.send(true, true, |client| client.delete($url.clone())).await??
     };
     ($self:ident, $url:ident, PUT) => {
-        $self.async_client().put($url).send().await?
+        $self.send(true, true, |client| client.put($url.clone())).await??
     };
     ($self:ident, $url:ident, PUT, $json:expr) => {
-        $self.async_client().put($url).json($json).send().await?
+        {
+            let body = $self.encode($json)?;
+            $self.send(true, true, |client| $self.with_body(client.put($url.clone()), &body)).await??
+        }
     };
     ($self:ident, $url:ident, DELETE) => {
-        $self.async_client().delete($url).send().await?
+        $self.send(true, true, |client| client.delete($url.clone())).await??
     };
     ($self:ident, $url:ident, POST, $json:expr) => {
-        $self.async_client().post($url).json($json).send().await?
+        {
+            let body = $self.encode($json)?;
+            $self.send(true, true, |client| $self.with_body(client.post($url.clone()), &body)).await??
+        }
     };
     ($self:ident, $url:ident, $suffix:expr, $($tail:tt)*) => {
         {
@@ -180,22 +196,43 @@ macro_rules! api_delete_async {
 macro_rules! api_request_right {
     () => {};
     ($self:ident, $url:ident, GET, $headers:expr) => {
-        $self.client().get($url).headers($headers).send().then(handle_response).await
+        handle_response($self.send(false, true, |client| client.get($url.clone()).headers($headers.clone())).await?).await
+    };
+    ($self:ident, $url:ident, GET => $handler:ident) => {
+        $handler($self.send(false, true, |client| client.get($url.clone())).await?).await
     };
     ($self:ident, $url:ident, GET) => {
-        $self.client().get($url).send().then(handle_response).await
+        handle_response($self.send(false, true, |client| client.get($url.clone())).await?).await
     };
     ($self:ident, $url:ident, DELETE) => {
-        $self.client().delete($url).send().then(handle_response).await
+        handle_response($self.send(false, true, |client| client.delete($url.clone())).await?).await
     };
     ($self:ident, $url:ident, PUT) => {
-        $self.client().put($url).send().then(handle_response).await
+        handle_response($self.send(false, true, |client| client.put($url.clone())).await?).await
+    };
+    ($self:ident, $url:ident, PUT, $json:expr => $handler:ident) => {
+        {
+            let body = $self.encode($json)?;
+            $handler($self.send(false, true, |client| $self.with_body(client.put($url.clone()), &body)).await?).await
+        }
     };
     ($self:ident, $url:ident, PUT, $json:expr) => {
-        $self.client().put($url).json($json).send().then(handle_response).await
+        {
+            let body = $self.encode($json)?;
+            handle_response($self.send(false, true, |client| $self.with_body(client.put($url.clone()), &body)).await?).await
+        }
+    };
+    ($self:ident, $url:ident, POST, $json:expr => $handler:ident) => {
+        {
+            let body = $self.encode($json)?;
+            $handler($self.send(false, true, |client| $self.with_body(client.post($url.clone()), &body)).await?).await
+        }
     };
     ($self:ident, $url:ident, POST, $json:expr) => {
-        $self.client().post($url).json($json).send().then(handle_response).await
+        {
+            let body = $self.encode($json)?;
+            handle_response($self.send(false, true, |client| $self.with_body(client.post($url.clone()), &body)).await?).await
+        }
     };
     ($self:ident, $url:ident, $suffix:expr, $kind:ty, $($tail:tt)*) => {
         {
@@ -213,6 +250,9 @@ macro_rules! api_request_right {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! api_get_right {
+    ($self:ident, $url:ident, $suffix:expr, $kind:ty => $handler:ident) => {
+        $crate::api_request_right!($self, $url, $suffix, $kind, GET => $handler)
+    };
     ($self:ident, $url:ident, $suffix:expr, $kind:ty) => {
         $crate::api_request_right!($self, $url, $suffix, $kind, GET)
     };
@@ -221,6 +261,9 @@ macro_rules! api_get_right {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! api_post_right {
+    ($self:ident, $url:ident, $suffix:expr, $kind:ty, $json:expr => $handler:ident) => {
+        $crate::api_request_right!($self, $url, $suffix, $kind, POST, $json => $handler)
+    };
     ($self:ident, $url:ident, $suffix:expr, $kind:ty, $json:expr) => {
         $crate::api_request_right!($self, $url, $suffix, $kind, POST, $json)
     };
@@ -229,6 +272,9 @@ macro_rules! api_post_right {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! api_put_right {
+    ($self:ident, $url:ident, $suffix:expr, $kind:ty, $json:expr => $handler:ident) => {
+        $crate::api_request_right!($self, $url, $suffix, $kind, PUT, $json => $handler)
+    };
     ($self:ident, $url:ident, $suffix:expr, $kind:ty, $json:expr) => {
         $crate::api_request_right!($self, $url, $suffix, $kind, PUT, $json)
     };
@@ -0,0 +1,126 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Query trait implementation
+
+use super::EMPTY_BODY;
+use crate::{
+    query::{
+        output::{Deleted, RunningQuery},
+        BASE_QUERY_SUFFIX,
+    },
+    traits::Query,
+    utils::{handle_response, kill_resp},
+    ArangoResult, Connection,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Url;
+
+impl Connection {
+    fn query_url(&self, suffix: &str) -> Result<Url> {
+        let suffix = format!("{BASE_QUERY_SUFFIX}/{suffix}");
+        self.db_url()
+            .join(&suffix)
+            .with_context(|| format!("Unable to build '{suffix}' url"))
+    }
+}
+
+#[async_trait]
+#[allow(unused_qualifications)]
+impl Query for Connection {
+    async fn current_queries(&self) -> ArangoResult<Vec<RunningQuery>> {
+        let url = self.query_url("current")?;
+        self.get(url, None, EMPTY_BODY, handle_response).await
+    }
+
+    async fn slow_queries(&self) -> ArangoResult<Vec<RunningQuery>> {
+        let url = self.query_url("slow")?;
+        self.get(url, None, EMPTY_BODY, handle_response).await
+    }
+
+    async fn clear_slow_queries(&self) -> ArangoResult<Deleted> {
+        let url = self.query_url("slow")?;
+        self.delete(url, None, EMPTY_BODY, handle_response).await
+    }
+
+    async fn kill_query(&self, id: &str) -> ArangoResult<Deleted> {
+        let url = self.query_url(id)?;
+        let id = id.to_string();
+        self.delete(url, None, EMPTY_BODY, |res| kill_resp(res, id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Query;
+    use crate::{
+        error::RuarangoErr,
+        utils::{
+            default_conn, mock_auth,
+            mocks::query::{mock_current_queries, mock_kill_query, mock_kill_query_not_found},
+        },
+    };
+    use anyhow::{anyhow, Result};
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn current_queries() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_current_queries(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.current_queries().await?;
+        assert!(res.is_right());
+        let queries = res.right_safe()?;
+        assert_eq!(queries.len(), 1);
+        let query = queries.first().ok_or_else(|| anyhow!("no query"))?;
+        assert_eq!(query.id(), "123");
+        assert_eq!(query.query(), "FOR d IN @@coll RETURN d");
+        assert_eq!(query.state(), "executing");
+        assert!(query.bind_vars().contains_key("@coll"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn kill_query() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_kill_query(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.kill_query("123").await?;
+        assert!(res.is_right());
+        let killed = res.right_safe()?;
+        assert!(!killed.error());
+        assert_eq!(*killed.code(), 200);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn kill_query_not_found() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_kill_query_not_found(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        match conn.kill_query("456").await {
+            Ok(_) => panic!("The kill should fail!"),
+            Err(e) => assert!(matches!(
+                e.downcast_ref::<RuarangoErr>(),
+                Some(RuarangoErr::QueryNotFound { id }) if id == "456"
+            )),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! View trait implementation
+
+use super::EMPTY_BODY;
+use crate::{
+    common::output::Response,
+    traits::View,
+    utils::handle_response,
+    view::{
+        input::CreateConfig,
+        output::{Properties, ViewMeta},
+        BASE_VIEW_SUFFIX,
+    },
+    ArangoResult, Connection,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Url;
+
+impl Connection {
+    fn view_url(&self, suffix: Option<&str>) -> Result<Url> {
+        let suffix = suffix.map_or_else(
+            || BASE_VIEW_SUFFIX.to_string(),
+            |suffix| format!("{BASE_VIEW_SUFFIX}/{suffix}"),
+        );
+        self.db_url()
+            .join(&suffix)
+            .with_context(|| format!("Unable to build '{suffix}' url"))
+    }
+}
+
+#[async_trait]
+#[allow(unused_qualifications)]
+impl View for Connection {
+    async fn create(&self, config: CreateConfig) -> ArangoResult<ViewMeta> {
+        let url = self.view_url(None)?;
+        self.post(url, None, &config, handle_response).await
+    }
+
+    async fn list(&self) -> ArangoResult<Response<Vec<ViewMeta>>> {
+        let url = self.view_url(None)?;
+        self.get(url, None, EMPTY_BODY, handle_response).await
+    }
+
+    async fn properties(&self, name: &str) -> ArangoResult<Properties> {
+        let url = self.view_url(Some(&format!("{name}/properties")))?;
+        self.get(url, None, EMPTY_BODY, handle_response).await
+    }
+
+    async fn drop(&self, name: &str) -> ArangoResult<Response<bool>> {
+        let url = self.view_url(Some(name))?;
+        self.delete(url, None, EMPTY_BODY, handle_response).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::View;
+    use crate::{
+        utils::{
+            default_conn, mock_auth,
+            mocks::view::{mock_create_view, mock_drop_view, mock_view_properties},
+        },
+        view::{input::CreateConfigBuilder, ViewKind},
+    };
+    use anyhow::Result;
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn create_view() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_create_view(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let config = CreateConfigBuilder::default().name("test_view").build()?;
+        let res = conn.create(config).await?;
+        assert!(res.is_right());
+        let view = res.right_safe()?;
+        assert_eq!(view.name(), "test_view");
+        assert_eq!(*view.kind(), ViewKind::ArangoSearch);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn view_properties() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_view_properties(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.properties("test_view").await?;
+        assert!(res.is_right());
+        let props = res.right_safe()?;
+        assert_eq!(props.meta().name(), "test_view");
+        assert!(props.links().contains_key("test_coll"));
+        assert_eq!(*props.cleanup_interval_step(), Some(2));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drop_view() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mock_auth(&mock_server).await;
+        mock_drop_view(&mock_server).await?;
+
+        let conn = default_conn(mock_server.uri()).await?;
+        let res = conn.drop("test_view").await?;
+        assert!(res.is_right());
+        assert!(res.right_safe()?.result());
+
+        Ok(())
+    }
+}
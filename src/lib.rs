@@ -185,6 +185,15 @@
 //! # tokio_test::block_on(blah());
 //! ```
 //!
+//! # Arbitrary Precision Numbers
+//! By default, numbers in responses are parsed as `u64`, `i64` or `f64`, so
+//! integers beyond the `u64` range and decimals with more precision than an
+//! `f64` are rounded when read into a [`serde_json::Value`] document.  Enable
+//! the `arbitrary_precision` feature to keep such numbers as they were sent.
+//! The tradeoff is that numbers in a `Value` are held as their string
+//! representation, so every [`serde_json::Number`] is parsed on access, and
+//! the feature applies to every crate using `serde_json` in your build.
+//!
 
 // rustc lints
 #![cfg_attr(
@@ -399,6 +408,8 @@
 #![cfg_attr(all(docsrs, nightly), feature(doc_cfg))]
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
+#[cfg(all(test, not(feature = "gzip")))]
+use flate2 as _;
 #[cfg(test)]
 use {lazy_static as _, r2d2 as _, rand as _, tokio_test as _};
 
@@ -410,17 +421,26 @@ mod utils;
 mod builder;
 mod conn;
 mod error;
+mod hook;
 #[doc(hidden)]
 mod mocks;
 mod model;
+#[cfg(feature = "r2d2")]
+mod pool;
 mod traits;
 mod types;
+mod vpack;
 
 pub use builder::AsyncKind;
+pub use builder::AuthKind;
 pub use builder::Connection as BaseConnection;
 pub use builder::ConnectionBuilder;
+pub use builder::ContentType;
 pub use conn::Connection;
 pub use error::RuarangoErr as Error;
+pub use hook::OnRequest;
+pub use hook::OnResponse;
+pub use hook::Trace;
 #[doc(hidden)]
 pub use mocks::mock_async_database_create;
 #[doc(hidden)]
@@ -435,13 +455,21 @@ pub use mocks::mock_get_job;
 pub use mocks::mock_put_job;
 #[doc(hidden)]
 pub use mocks::start_mock_server;
+pub use model::admin;
+pub use model::analyzer;
 pub use model::coll;
 pub use model::common;
 pub use model::cursor;
 pub use model::db;
 pub use model::doc;
 pub use model::graph;
+pub use model::query;
+pub use model::view;
 pub use model::BaseErr;
+#[cfg(feature = "r2d2")]
+pub use pool::ConnectionManager;
+pub use traits::Admin;
+pub use traits::Analyzer;
 pub use traits::Collection;
 pub use traits::Cursor;
 pub use traits::Database;
@@ -449,7 +477,12 @@ pub use traits::Document;
 pub use traits::Graph;
 pub use traits::Job;
 pub use traits::JobInfo;
+pub use traits::Query;
+pub use traits::View;
 pub use types::ArangoEither;
+pub use types::ArangoEitherExt;
+pub use types::ArangoMap;
+pub use types::ArangoMapResult;
 pub use types::ArangoResult;
 pub use types::ArangoVec;
 pub use types::ArangoVecResult;
@@ -0,0 +1,33 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! [`Output`](crate::admin::output) for [`Admin`](crate::Admin) operations
+
+pub mod output;
+
+use serde::{Deserialize, Serialize};
+
+/// The log level of a server log topic
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LogLevel {
+    /// Only log fatal errors
+    Fatal,
+    /// Log errors
+    Error,
+    /// Log warnings and errors
+    Warning,
+    /// Log informational messages, warnings and errors
+    Info,
+    /// Log debug messages and above
+    Debug,
+    /// Log everything
+    Trace,
+    /// Reset the topic to its default level
+    Default,
+}
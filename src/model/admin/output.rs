@@ -0,0 +1,290 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Admin Output Structs
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Output when [`version`](crate::Admin::version) is called
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Version {
+    /// Will always contain `arango`
+    server: String,
+    /// The server version string. The string has the format "major.minor.sub".
+    version: String,
+    /// The license of the server, i.e. `community` or `enterprise`
+    license: String,
+    /// Additional information about the server, if `details` was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, String>>,
+}
+
+/// A single sample parsed from the Prometheus text exposition format output
+/// by [`metrics`](crate::Admin::metrics)
+#[derive(Clone, Debug, Deserialize, Getters, PartialEq, Serialize)]
+#[getset(get = "pub")]
+pub struct Metric {
+    /// The metric name, i.e. `arangodb_client_connection_statistics_total_time_count`
+    name: String,
+    /// The metric labels, i.e. `role="SINGLE"`
+    labels: HashMap<String, String>,
+    /// The sample value
+    value: f64,
+}
+
+impl Metric {
+    /// Parse the samples out of a Prometheus text exposition format body.
+    /// Comment (`# HELP`/`# TYPE`) lines and lines that cannot be parsed
+    /// are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ruarango::admin::output::Metric;
+    /// let body = "# TYPE arangodb_scheduler_num_worker_threads gauge\n\
+    ///             arangodb_scheduler_num_worker_threads{role=\"SINGLE\"} 4\n";
+    /// let metrics = Metric::parse(body);
+    /// assert_eq!(1, metrics.len());
+    /// assert_eq!("arangodb_scheduler_num_worker_threads", metrics[0].name());
+    /// assert_eq!(4.0, *metrics[0].value());
+    /// ```
+    #[must_use]
+    pub fn parse(text: &str) -> Vec<Metric> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(Self::parse_line)
+            .collect()
+    }
+
+    fn parse_line(line: &str) -> Option<Metric> {
+        let (name, labels, rest) = if let Some(open) = line.find('{') {
+            let close = line.rfind('}')?;
+            let labels = Self::parse_labels(line.get(open + 1..close)?)?;
+            (&line[..open], labels, &line[close + 1..])
+        } else {
+            let idx = line.find(char::is_whitespace)?;
+            (&line[..idx], HashMap::new(), &line[idx..])
+        };
+        let value = rest.split_whitespace().next()?.parse().ok()?;
+
+        Some(Metric {
+            name: name.trim().to_string(),
+            labels,
+            value,
+        })
+    }
+
+    fn parse_labels(text: &str) -> Option<HashMap<String, String>> {
+        let mut labels = HashMap::new();
+        let mut chars = text.chars().peekable();
+
+        loop {
+            while chars
+                .peek()
+                .map_or(false, |c| *c == ',' || c.is_whitespace())
+            {
+                let _ = chars.next();
+            }
+            if chars.peek().is_none() {
+                break;
+            }
+
+            let key: String = chars.by_ref().take_while(|c| *c != '=').collect();
+            if chars.next()? != '"' {
+                return None;
+            }
+
+            let mut value = String::new();
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => match chars.next()? {
+                        'n' => value.push('\n'),
+                        c => value.push(c),
+                    },
+                    c => value.push(c),
+                }
+            }
+            let _ = labels.insert(key.trim().to_string(), value);
+        }
+
+        Some(labels)
+    }
+}
+
+#[cfg(test)]
+impl Default for Version {
+    fn default() -> Self {
+        Self {
+            server: "arango".to_string(),
+            version: "3.11.0".to_string(),
+            license: "community".to_string(),
+            details: None,
+        }
+    }
+}
+
+/// Output when [`status`](crate::Admin::status) is called
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Status {
+    /// Will always contain `arango`
+    server: String,
+    /// The server version string
+    version: String,
+    /// The process id of the server
+    pid: u64,
+    /// The license of the server, i.e. `community` or `enterprise`
+    license: String,
+    /// The server mode, i.e. `server` or `console`
+    mode: String,
+    /// The server operation mode, i.e. `server` or `console`
+    #[serde(rename = "operationMode")]
+    operation_mode: String,
+    /// Is the Foxx API enabled?
+    #[serde(rename = "foxxApi")]
+    foxx_api: bool,
+    /// The host identifier of the server
+    host: String,
+    /// The hostname of the server, if available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hostname: Option<String>,
+    /// Information about the server
+    #[serde(rename = "serverInfo")]
+    server_info: ServerInfo,
+}
+
+#[cfg(test)]
+impl Default for Status {
+    fn default() -> Self {
+        Self {
+            server: "arango".to_string(),
+            version: "3.11.0".to_string(),
+            pid: 1,
+            license: "community".to_string(),
+            mode: "server".to_string(),
+            operation_mode: "server".to_string(),
+            foxx_api: true,
+            host: "abcdef".to_string(),
+            hostname: None,
+            server_info: ServerInfo::default(),
+        }
+    }
+}
+
+/// The raw output of the cluster endpoints call made by
+/// [`endpoints`](crate::Admin::endpoints)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Endpoints {
+    /// The coordinator endpoints of the cluster
+    endpoints: Vec<Endpoint>,
+}
+
+impl Endpoints {
+    pub(crate) fn urls(self) -> Vec<String> {
+        self.endpoints.into_iter().map(|e| e.endpoint).collect()
+    }
+}
+
+/// A single coordinator endpoint
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Endpoint {
+    /// The endpoint url, i.e. `tcp://127.0.0.1:8529`
+    endpoint: String,
+}
+
+/// The request as seen by the server, returned by [`echo`](crate::Admin::echo)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Echo {
+    /// The name of the database the request was routed to
+    database: String,
+    /// The request url, relative to the database, i.e. `/_admin/echo`
+    url: String,
+    /// The request path, relative to the matched route
+    path: String,
+    /// The request method, i.e. `POST`
+    #[serde(rename = "requestType")]
+    request_type: String,
+    /// The request headers as received by the server, with lowercase names
+    headers: HashMap<String, String>,
+    /// The query parameters of the request
+    #[serde(default)]
+    parameters: HashMap<String, String>,
+    /// The authenticated user, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+}
+
+/// Server information included in the [`status`](crate::Admin::status) output
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct ServerInfo {
+    /// Is the server in maintenance mode?
+    maintenance: bool,
+    /// The server role, i.e. `SINGLE`, `COORDINATOR`, `PRIMARY` or `AGENT`
+    role: String,
+    /// Are write operations enabled?
+    #[serde(rename = "writeOpsEnabled")]
+    write_ops_enabled: bool,
+    /// Is the server in read-only mode?
+    #[serde(rename = "readOnly")]
+    read_only: bool,
+}
+
+#[cfg(test)]
+impl Default for ServerInfo {
+    fn default() -> Self {
+        Self {
+            maintenance: false,
+            role: "SINGLE".to_string(),
+            write_ops_enabled: true,
+            read_only: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Metric;
+
+    const METRICS: &str = r#"# HELP arangodb_agency_cache_callback_number Current number of entries in agency cache callbacks table
+# TYPE arangodb_agency_cache_callback_number gauge
+arangodb_agency_cache_callback_number{role="SINGLE"} 0
+# TYPE arangodb_client_connection_statistics_bytes_received_bucket histogram
+arangodb_client_connection_statistics_bytes_received_bucket{role="SINGLE",le="+Inf"} 128
+arangodb_process_statistics_resident_set_size 1.2345e8 1680000000000
+arangodb_label_escapes{path="a\"b,c",role="SINGLE"} 1
+not a metric line
+"#;
+
+    #[test]
+    fn parse_metrics() {
+        let metrics = Metric::parse(METRICS);
+        assert_eq!(4, metrics.len());
+
+        assert_eq!("arangodb_agency_cache_callback_number", metrics[0].name());
+        assert_eq!(Some(&"SINGLE".to_string()), metrics[0].labels().get("role"));
+        assert_eq!(0.0, *metrics[0].value());
+
+        assert_eq!(Some(&"+Inf".to_string()), metrics[1].labels().get("le"));
+        assert_eq!(128.0, *metrics[1].value());
+
+        assert!(metrics[2].labels().is_empty());
+        assert_eq!(1.2345e8, *metrics[2].value());
+
+        assert_eq!(Some(&"a\"b,c".to_string()), metrics[3].labels().get("path"));
+        assert_eq!(2, metrics[3].labels().len());
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Analyzer Input Structs
+
+use super::{AnalyzerKind, Feature};
+use derive_builder::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Analyzer creation configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct AnalyzerConfig {
+    /// The name of the analyzer
+    #[builder(setter(into))]
+    name: String,
+    /// The kind of analyzer
+    #[builder(default)]
+    #[serde(rename = "type")]
+    kind: AnalyzerKind,
+    /// The analyzer properties.  These vary by [`AnalyzerKind`], i.e. a
+    /// `text` analyzer takes `locale`, `case` and `stemming`.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Value>,
+    /// The features to enable for the analyzer
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    features: Option<Vec<Feature>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::AnalyzerConfigBuilder;
+    use crate::analyzer::{AnalyzerKind, Feature};
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn analyzer_config_serializes() -> Result<()> {
+        let config = AnalyzerConfigBuilder::default()
+            .name("text_test")
+            .kind(AnalyzerKind::Text)
+            .properties(json!({ "locale": "en", "stemming": true }))
+            .features(vec![Feature::Frequency, Feature::Norm, Feature::Position])
+            .build()?;
+        assert_eq!(
+            json!({
+                "name": "text_test",
+                "type": "text",
+                "properties": { "locale": "en", "stemming": true },
+                "features": ["frequency", "norm", "position"]
+            }),
+            serde_json::to_value(&config)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn analyzer_kind_serializes() -> Result<()> {
+        assert_eq!(
+            json!("geojson"),
+            serde_json::to_value(AnalyzerKind::GeoJson)?
+        );
+        assert_eq!(
+            json!("identity"),
+            serde_json::to_value(AnalyzerKind::default())?
+        );
+        Ok(())
+    }
+}
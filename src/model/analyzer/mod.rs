@@ -0,0 +1,64 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! [`Input`](crate::analyzer::input)/[`Output`](crate::analyzer::output) for [`Analyzer`](crate::Analyzer) operations
+
+pub mod input;
+pub mod output;
+
+pub(crate) const BASE_ANALYZER_SUFFIX: &str = "_api/analyzer";
+
+use serde::{Deserialize, Serialize};
+
+/// The kind of analyzer
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalyzerKind {
+    /// Treats the input as a single token
+    #[default]
+    Identity,
+    /// Splits the input into tokens on a delimiter
+    Delimiter,
+    /// Applies stemming to the input as a whole
+    Stem,
+    /// Normalizes the case and accents of the input as a whole
+    Norm,
+    /// Produces n-grams from the input
+    Ngram,
+    /// Tokenizes the input into words, with optional stemming and stop words
+    Text,
+    /// Runs an AQL query to produce the tokens
+    Aql,
+    /// Chains several analyzers together
+    Pipeline,
+    /// Removes the given stop words
+    Stopwords,
+    /// Applies a language specific collation to the input
+    Collation,
+    /// Splits the input into words using Unicode segmentation rules
+    Segmentation,
+    /// Indexes `GeoJSON` data
+    GeoJson,
+    /// Indexes geo coordinate pairs
+    GeoPoint,
+}
+
+/// A feature of an analyzer, used to enable search features on the indexed
+/// data
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Feature {
+    /// Track how often a term occurs, required for `BM25()` and `TFIDF()`
+    Frequency,
+    /// Track the field normalization factor, used by `BM25()` and `TFIDF()`
+    Norm,
+    /// Track the term positions, required for `PHRASE()`
+    Position,
+    /// Track the term offsets, required for `OFFSET_INFO()`
+    Offset,
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Analyzer Output Structs
+
+use super::{AnalyzerKind, Feature};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Analyzer metadata output by [`create`](crate::Analyzer::create) and
+/// [`list`](crate::Analyzer::list)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct AnalyzerMeta {
+    /// The name of the analyzer, prefixed with the database name for
+    /// non-builtin analyzers, i.e. `_system::text_test`
+    name: String,
+    /// The kind of analyzer
+    #[serde(rename = "type")]
+    kind: AnalyzerKind,
+    /// The analyzer properties
+    #[serde(default)]
+    properties: Value,
+    /// The features enabled for the analyzer
+    #[serde(default)]
+    features: Vec<Feature>,
+}
+
+/// Output when [`delete`](crate::Analyzer::delete) is successful
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Delete {
+    /// Is this response an error?
+    error: bool,
+    /// The response code, i.e. 200
+    code: usize,
+    /// The name of the deleted analyzer
+    name: String,
+}
+
+#[cfg(test)]
+impl Default for AnalyzerMeta {
+    fn default() -> Self {
+        Self {
+            name: "keti::text_test".to_string(),
+            kind: AnalyzerKind::Text,
+            properties: serde_json::json!({ "locale": "en", "stemming": true }),
+            features: vec![Feature::Frequency, Feature::Norm, Feature::Position],
+        }
+    }
+}
+
+#[cfg(test)]
+impl Default for Delete {
+    fn default() -> Self {
+        Self {
+            error: false,
+            code: 200,
+            name: "keti::text_test".to_string(),
+        }
+    }
+}
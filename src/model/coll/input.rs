@@ -8,6 +8,11 @@
 
 //! Collection Input Structs
 
+use crate::{
+    cursor::input::{CreateConfig, OptionsBuilder},
+    query::AqlBuilder,
+};
+use anyhow::Result;
 use derive_builder::Builder;
 use getset::Getters;
 use serde::{Deserialize, Serialize};
@@ -168,11 +173,105 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
     schema: Option<String>,
+    /// Attributes whose values are computed by the server from an AQL
+    /// expression when documents are written to the collection
+    #[serde(rename = "computedValues", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    computed_values: Option<Vec<ComputedValue>>,
+    /// The cluster only options (`replication_factor`, `write_concern`,
+    /// `smart_join_attribute`, `number_of_shards`, `distribute_shards_like`
+    /// and `shard_keys`) are ignored, or rejected, by a single server.  If
+    /// this is true, [`create`](crate::Collection::create) first checks the
+    /// server role via [`status`](crate::Admin::status), and returns a
+    /// [`ClusterOnly`](crate::Error::ClusterOnly) error instead if any of
+    /// them are set on a single server.  The check is skipped on an
+    /// asynchronous connection.  This is never sent to the server.
+    #[serde(skip)]
+    #[builder(setter(strip_option), default)]
+    cluster_only_checked: Option<bool>,
+}
+
+impl Config {
+    /// The names of the cluster only options that are set
+    pub(crate) fn cluster_only_fields(&self) -> Vec<String> {
+        [
+            ("replication_factor", self.replication_factor.is_some()),
+            ("write_concern", self.write_concern.is_some()),
+            ("smart_join_attribute", self.smart_join_attribute.is_some()),
+            ("number_of_shards", self.number_of_shards.is_some()),
+            (
+                "distribute_shards_like",
+                self.distribute_shards_like.is_some(),
+            ),
+            ("shard_keys", self.shard_keys.is_some()),
+        ]
+        .iter()
+        .filter(|(_, is_set)| *is_set)
+        .map(|(name, _)| (*name).to_string())
+        .collect()
+    }
+}
+
+/// The write operations a [`ComputedValue`] is computed on
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputeOn {
+    /// Compute the value when a document is inserted
+    Insert,
+    /// Compute the value when a document is updated
+    Update,
+    /// Compute the value when a document is replaced
+    Replace,
+}
+
+/// A document attribute computed by the server
+#[derive(Builder, Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct ComputedValue {
+    /// The name of the target attribute
+    #[builder(setter(into))]
+    name: String,
+    /// An AQL `RETURN` expression computing the value.  The document is
+    /// available as `@doc`, i.e. `RETURN CONCAT(@doc.first, ' ', @doc.last)`
+    #[builder(setter(into))]
+    expression: String,
+    /// Whether the computed value overwrites an existing attribute with the
+    /// same name
+    overwrite: bool,
+    /// The write operations the value is computed on.  The server default is
+    /// all of them.
+    #[serde(rename = "computeOn", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    compute_on: Option<Vec<ComputeOn>>,
+    /// Whether the target attribute is set if the expression evaluates to
+    /// `null`.  The server default is true.
+    #[serde(rename = "keepNull", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    keep_null: Option<bool>,
+}
+
+/// The key generator used for a collection, see [`KeyOptions`]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyGeneratorKind {
+    /// Numerical keys in ascending order
+    Traditional,
+    /// Numerical keys in ascending order with a configurable offset and
+    /// increment
+    AutoIncrement,
+    /// Universally unique 128 bit keys in hexadecimal format
+    Uuid,
+    /// Fixed length keys in ascending lexicographical order
+    Padded,
 }
 
+const INCREMENT_OFFSET_ERR: &str =
+    "increment and offset are only valid for the autoincrement key generator!";
+
 /// key options for collection response
 #[derive(Builder, Clone, Debug, Deserialize, Getters, Serialize)]
 #[getset(get = "pub")]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct KeyOptions {
     /// If set to true, then it is allowed to supply own key values in the
     /// _key attribute of a document. If set to false, then the key generator
@@ -196,20 +295,33 @@ pub struct KeyOptions {
     /// in a single-server or cluster to generate "seemingly random" keys. The keys
     /// produced by this key generator are not lexicographically sorted.
     #[serde(rename = "type")]
-    #[builder(setter(into))]
-    kind: String,
-    /// increment value for autoincrement key generator. Not used for other key
-    /// generator types.
+    kind: KeyGeneratorKind,
+    /// increment value for autoincrement key generator. Only valid for the
+    /// [`AutoIncrement`](KeyGeneratorKind::AutoIncrement) key generator.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
     increment: Option<usize>,
     /// Initial offset value for autoincrement key generator.
-    /// Not used for other key generator types.
+    /// Only valid for the [`AutoIncrement`](KeyGeneratorKind::AutoIncrement)
+    /// key generator.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(strip_option), default)]
     offset: Option<usize>,
 }
 
+impl KeyOptionsBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        let has_increment_or_offset =
+            matches!(self.increment, Some(Some(_))) || matches!(self.offset, Some(Some(_)));
+
+        if has_increment_or_offset && self.kind != Some(KeyGeneratorKind::AutoIncrement) {
+            Err(INCREMENT_OFFSET_ERR.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// Should a count happen on puts
 #[derive(Builder, Clone, Copy, Debug, Serialize)]
 pub(crate) struct ShouldCount {
@@ -244,6 +356,145 @@ pub struct Props {
     schema: Option<String>,
 }
 
+/// Configuration used when truncating a collection, see
+/// [`truncate_with`](crate::Collection::truncate_with)
+#[derive(Builder, Clone, Copy, Debug, Default, Getters)]
+#[getset(get = "pub(crate)")]
+pub struct TruncateConfig {
+    /// If true then the truncate will wait until the data has been
+    /// synchronized to disk.
+    #[builder(setter(strip_option), default)]
+    wait_for_sync: Option<bool>,
+    /// If true (the server default) then the storage engine is told to
+    /// compact the collection data after the truncate, reclaiming the
+    /// disk space immediately.
+    #[builder(setter(strip_option), default)]
+    compact: Option<bool>,
+}
+
+/// Configuration used when dropping a collection, see
+/// [`drop_with`](crate::Collection::drop_with)
+#[derive(Builder, Clone, Copy, Debug, Default, Getters)]
+#[getset(get = "pub(crate)")]
+pub struct DropConfig {
+    /// Whether or not the collection to drop is a system collection.  This
+    /// must be set to true in order to drop a system collection.
+    #[builder(setter(strip_option), default)]
+    is_system: Option<bool>,
+}
+
+/// Configuration used when scanning every document of a collection, see
+/// [`all`](crate::Collection::all)
+#[derive(Builder, Clone, Debug, Default, Getters)]
+#[getset(get = "pub(crate)")]
+pub struct AllConfig {
+    /// The number of documents fetched from the server in each batch.  The
+    /// server default is 1000.
+    #[builder(setter(strip_option), default)]
+    batch_size: Option<usize>,
+    /// The top-level attributes to keep, i.e. `test`.  If none are given,
+    /// the whole document is returned.
+    #[builder(setter(each(name = "attribute", into)), default)]
+    attributes: Vec<String>,
+}
+
+impl AllConfig {
+    pub(crate) fn cursor_config(&self, name: &str) -> Result<CreateConfig> {
+        let attributes = (0..self.attributes.len())
+            .map(|i| format!("@attr{i}"))
+            .collect::<Vec<String>>();
+        let ret = if attributes.is_empty() {
+            "d".to_string()
+        } else {
+            format!("KEEP(d, {})", attributes.join(", "))
+        };
+        let mut aql = AqlBuilder::for_("d")
+            .in_("@@coll")
+            .ret(ret)
+            .bind("@coll", name);
+        for (i, attribute) in self.attributes.iter().enumerate() {
+            aql = aql.bind(format!("attr{i}"), attribute.clone());
+        }
+
+        let mut builder = aql.config_builder();
+        let _ = builder.options(OptionsBuilder::default().stream(true).build()?);
+        if let Some(batch_size) = self.batch_size {
+            let _ = builder.batch_size(batch_size);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Configuration used when exporting every document of a collection, see
+/// [`export`](crate::Collection::export)
+#[derive(Builder, Clone, Debug, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct ExportConfig {
+    /// The name of the collection to export
+    #[serde(skip)]
+    #[builder(setter(into))]
+    collection: String,
+    /// The number of documents fetched from the server in each batch.  The
+    /// server default is 5000.
+    #[serde(rename = "batchSize", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    batch_size: Option<usize>,
+    /// If true, the WAL is flushed before the export so that it includes
+    /// all recently written documents.  The server default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    flush: Option<bool>,
+    /// The time-to-live, in seconds, of the export cursor on the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    ttl: Option<usize>,
+    /// The top-level attributes to include in, or exclude from, each
+    /// exported document.  If not given, the whole document is returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    restrict: Option<Restrict>,
+}
+
+/// Restrict the attributes of the exported documents, see
+/// [`ExportConfig`](ExportConfig)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Restrict {
+    /// Whether the `fields` are included or excluded
+    #[serde(rename = "type")]
+    kind: RestrictKind,
+    /// The top-level attributes to include or exclude
+    fields: Vec<String>,
+}
+
+impl Restrict {
+    /// Keep only the given top-level attributes
+    pub fn include<S: Into<String>>(fields: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            kind: RestrictKind::Include,
+            fields: fields.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Keep every top-level attribute except the given ones
+    pub fn exclude<S: Into<String>>(fields: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            kind: RestrictKind::Exclude,
+            fields: fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The kind of an export [`Restrict`](Restrict)
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RestrictKind {
+    /// Only the given attributes are returned
+    Include,
+    /// All but the given attributes are returned
+    Exclude,
+}
+
 /// A new collection
 #[derive(Builder, Clone, Debug, Serialize)]
 pub(crate) struct NewName {
@@ -251,3 +502,150 @@ pub(crate) struct NewName {
     #[builder(setter(into))]
     name: String,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        AllConfigBuilder, ComputeOn, ComputedValueBuilder, ConfigBuilder, ExportConfigBuilder,
+        KeyGeneratorKind, KeyOptionsBuilder, Restrict, INCREMENT_OFFSET_ERR,
+    };
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn computed_values_serialize() -> Result<()> {
+        let computed = ComputedValueBuilder::default()
+            .name("fullName")
+            .expression("RETURN CONCAT(@doc.first, ' ', @doc.last)")
+            .overwrite(true)
+            .compute_on(vec![ComputeOn::Insert, ComputeOn::Update])
+            .keep_null(false)
+            .build()?;
+        let config = ConfigBuilder::default()
+            .name("test_coll")
+            .computed_values(vec![computed])
+            .build()?;
+        assert_eq!(
+            json!({
+                "name": "test_coll",
+                "computedValues": [{
+                    "name": "fullName",
+                    "expression": "RETURN CONCAT(@doc.first, ' ', @doc.last)",
+                    "overwrite": true,
+                    "computeOn": ["insert", "update"],
+                    "keepNull": false
+                }]
+            }),
+            serde_json::to_value(&config)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn computed_values_are_omitted() -> Result<()> {
+        let config = ConfigBuilder::default().name("test_coll").build()?;
+        assert_eq!(
+            json!({ "name": "test_coll" }),
+            serde_json::to_value(&config)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn key_options_serialize() -> Result<()> {
+        let key_options = KeyOptionsBuilder::default()
+            .allow_user_keys(false)
+            .kind(KeyGeneratorKind::AutoIncrement)
+            .increment(5)
+            .offset(10)
+            .build()?;
+        assert_eq!(
+            json!({
+                "allowUserKeys": false,
+                "type": "autoincrement",
+                "increment": 5,
+                "offset": 10
+            }),
+            serde_json::to_value(&key_options)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn increment_requires_autoincrement() {
+        match KeyOptionsBuilder::default()
+            .allow_user_keys(true)
+            .kind(KeyGeneratorKind::Padded)
+            .increment(5)
+            .build()
+        {
+            Ok(_) => panic!("The builder should fail!"),
+            Err(e) => assert_eq!(INCREMENT_OFFSET_ERR, format!("{e}")),
+        }
+    }
+
+    #[test]
+    fn all_query() -> Result<()> {
+        let cursor_config = AllConfigBuilder::default()
+            .build()?
+            .cursor_config("test_coll")?;
+        assert_eq!("FOR d IN @@coll RETURN d", cursor_config.query());
+        let value = serde_json::to_value(&cursor_config)?;
+        assert_eq!(
+            Some(&json!({ "@coll": "test_coll" })),
+            value.get("bindVars")
+        );
+        assert_eq!(Some(&json!(true)), value.pointer("/options/stream"));
+        assert!(value.get("batchSize").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn all_projected_query() -> Result<()> {
+        let cursor_config = AllConfigBuilder::default()
+            .batch_size(10)
+            .attribute("test")
+            .attribute("other")
+            .build()?
+            .cursor_config("test_coll")?;
+        assert_eq!(
+            "FOR d IN @@coll RETURN KEEP(d, @attr0, @attr1)",
+            cursor_config.query()
+        );
+        assert_eq!(Some(10), *cursor_config.batch_size());
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(3, bind_vars.len());
+        assert_eq!(Some(&json!("other")), bind_vars.get("attr1"));
+        Ok(())
+    }
+
+    #[test]
+    fn export_serialize() -> Result<()> {
+        let config = ExportConfigBuilder::default()
+            .collection("test_coll")
+            .batch_size(10)
+            .flush(true)
+            .ttl(30)
+            .restrict(Restrict::exclude(vec!["_rev"]))
+            .build()?;
+        assert_eq!(
+            json!({
+                "batchSize": 10,
+                "flush": true,
+                "ttl": 30,
+                "restrict": { "type": "exclude", "fields": ["_rev"] }
+            }),
+            serde_json::to_value(&config)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn export_defaults_are_omitted() -> Result<()> {
+        let config = ExportConfigBuilder::default()
+            .collection("test_coll")
+            .build()?;
+        assert_eq!(json!({}), serde_json::to_value(&config)?);
+        Ok(())
+    }
+}
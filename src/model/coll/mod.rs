@@ -73,6 +73,8 @@ impl Visitor<'_> for CollectionKindVisitor {
 pub enum Status {
     /// unknown - may be corrupted
     Unknown,
+    /// new born collection
+    NewBorn,
     /// unloaded
     Unloaded,
     /// loaded
@@ -92,6 +94,7 @@ impl Ser for Status {
     {
         match self {
             Status::Unknown => serializer.serialize_u64(0),
+            Status::NewBorn => serializer.serialize_u64(1),
             Status::Unloaded => serializer.serialize_u64(2),
             Status::Loaded => serializer.serialize_u64(3),
             Status::Unloading => serializer.serialize_u64(4),
@@ -124,6 +127,7 @@ impl Visitor<'_> for StatusVisitor {
         E: de::Error,
     {
         match value {
+            1 => Ok(Status::NewBorn),
             2 => Ok(Status::Unloaded),
             3 => Ok(Status::Loaded),
             4 => Ok(Status::Unloading),
@@ -133,3 +137,46 @@ impl Visitor<'_> for StatusVisitor {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CollectionKind, Status};
+    use anyhow::Result;
+
+    #[test]
+    fn collection_kind_round_trip() -> Result<()> {
+        for kind in &[CollectionKind::Document, CollectionKind::Edges] {
+            let json = serde_json::to_string(kind)?;
+            let actual: CollectionKind = serde_json::from_str(&json)?;
+            assert_eq!(*kind, actual);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn status_round_trip() -> Result<()> {
+        for status in &[
+            Status::Unknown,
+            Status::NewBorn,
+            Status::Unloaded,
+            Status::Loaded,
+            Status::Unloading,
+            Status::Deleted,
+            Status::Loading,
+        ] {
+            let json = serde_json::to_string(status)?;
+            let actual: Status = serde_json::from_str(&json)?;
+            assert_eq!(*status, actual);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn status_serializes_explicit_codes() -> Result<()> {
+        assert_eq!("0", serde_json::to_string(&Status::Unknown)?);
+        assert_eq!("1", serde_json::to_string(&Status::NewBorn)?);
+        let unknown: Status = serde_json::from_str("42")?;
+        assert_eq!(Status::Unknown, unknown);
+        Ok(())
+    }
+}
@@ -9,10 +9,12 @@
 //! Collection Output Structs
 
 use super::{CollectionKind, Status};
+use crate::db::ReplicationFactor;
 use getset::Getters;
 #[cfg(test)]
 use getset::Setters;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 macro_rules! coll_output {
     ($(#[$sattr:meta])+ pub struct $name:ident {
@@ -73,7 +75,6 @@ coll_output!(
 
 coll_output!(
     /// Output when [`figures`](crate::Collection::figures) is called for a collection
-    #[derive(Copy)]
     pub struct Figures {
         /// Figure details
         figures: FiguresDetails => FiguresDetails::default(),
@@ -129,6 +130,51 @@ coll_output!(
     }
 );
 
+coll_output!(
+    /// Output when [`properties`](crate::Collection::properties) is called for a collection
+    pub struct Properties {
+        /// The collection name
+        name: String => "test_coll".to_string(),
+        /// Unique identifier of the collection
+        id: String => "abc".to_string(),
+        /// Unique identifier of the collection
+        #[serde(rename = "globallyUniqueId")]
+        globally_unique_id: String => "abcdef".to_string(),
+        /// The collection kind
+        #[serde(rename = "type")]
+        kind: CollectionKind => CollectionKind::Document,
+        /// The collection status
+        status: Status => Status::Loaded,
+        /// true if this is a system collection; usually name will
+        /// start with an underscore.
+        #[serde(rename = "isSystem")]
+        is_system: bool => false,
+        /// If true then creating, changing or removing documents
+        /// will wait until the data has been synchronized to disk.
+        #[serde(rename = "waitForSync")]
+        wait_for_sync: bool => true,
+        /// Key Options
+        #[serde(rename = "keyOptions")]
+        key_options: CreateKeyOptions => CreateKeyOptions::default(),
+        /// The collection level schema for documents.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        schema: Option<Value> => None,
+        /// The number of shards of the collection. (cluster only)
+        #[serde(rename = "numberOfShards", skip_serializing_if = "Option::is_none")]
+        number_of_shards: Option<usize> => None,
+        /// Contains how many copies of each shard are kept on different
+        /// DB-Servers. It is an integer number in the range of 1-10 or the
+        /// string "satellite" for a SatelliteCollection (Enterprise Edition only).
+        /// (cluster only)
+        #[serde(rename = "replicationFactor", skip_serializing_if = "Option::is_none")]
+        replication_factor: Option<ReplicationFactor> => None,
+        /// Determines how many copies of each shard are required to be
+        /// in sync on the different DB-Servers. (cluster only)
+        #[serde(rename = "writeConcern", skip_serializing_if = "Option::is_none")]
+        write_concern: Option<usize> => None,
+    }
+);
+
 coll_output!(
     /// Output when [`recalculate_count`](crate::Collection::recalculate_count) is called for a collection
     #[derive(Copy)]
@@ -160,6 +206,15 @@ coll_output!(
     pub struct Unload {}
 );
 
+coll_output!(
+    /// Output when [`responsible_shard`](crate::Collection::responsible_shard) is called for a collection
+    pub struct ResponsibleShard {
+        /// The id of the shard responsible for the given document
+        #[serde(rename = "shardId")]
+        shard_id: String => "s100001".to_string(),
+    }
+);
+
 coll_output!(
     /// Output when [`collection`](crate::Collection::collection) is called for a collection
     pub struct Collection {
@@ -181,6 +236,21 @@ coll_output!(
     }
 );
 
+impl From<Create> for Collection {
+    fn from(create: Create) -> Self {
+        Self {
+            error: create.error,
+            code: create.code,
+            id: create.id,
+            name: create.name,
+            status: create.status,
+            kind: create.kind,
+            is_system: create.is_system,
+            globally_unique_id: create.globally_unique_id,
+        }
+    }
+}
+
 coll_output!(
     /// Output when [`create`](crate::Collection::create) is called for a collection
     pub struct Create {
@@ -294,6 +364,16 @@ pub struct Collections {
     /// The globally unique id
     #[serde(rename = "globallyUniqueId")]
     globally_unique_id: String,
+    /// The number of documents in the collection, only populated if
+    /// requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    count: Option<usize>,
+}
+
+impl Collections {
+    pub(crate) fn count_mut(&mut self) -> &mut Option<usize> {
+        &mut self.count
+    }
 }
 
 impl Default for Collections {
@@ -305,12 +385,13 @@ impl Default for Collections {
             kind: CollectionKind::Edges,
             is_system: false,
             globally_unique_id: "hD4537D142F4C/16042".to_string(),
+            count: None,
         }
     }
 }
 
 /// Figure details that are part of the [`Figures`](Figures) output
-#[derive(Clone, Copy, Debug, Deserialize, Getters, Serialize)]
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
 #[cfg_attr(test, derive(Default))]
 #[getset(get = "pub")]
 pub struct FiguresDetails {
@@ -328,6 +409,10 @@ pub struct FiguresDetails {
     /// Cache usage in bytes
     #[serde(rename = "cacheUsage")]
     cache_usage: usize,
+    /// The extended storage engine figures, only populated if `details` were
+    /// requested.  The shape depends on the `ArangoDB` version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    engine: Option<Value>,
 }
 
 /// Index details that are part of the [`Figures`](Figures) output
@@ -348,7 +433,7 @@ impl Default for FiguresIndexes {
     }
 }
 
-/// Key options that are part of the [`Create`](Create) output
+/// Key options that are part of the [`Create`](Create) and [`Properties`](Properties) output
 #[derive(Clone, Debug, Deserialize, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct CreateKeyOptions {
@@ -390,3 +475,59 @@ impl Default for CreateKeyOptions {
         }
     }
 }
+
+/// A batch of documents returned by the export API, see
+/// [`export`](crate::Collection::export)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct ExportBatch<T> {
+    /// id of the export cursor created on the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    /// The exported documents of this batch
+    #[serde(default = "Vec::new")]
+    result: Vec<T>,
+    /// The total number of documents being exported, if requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<usize>,
+    /// A boolean indicator whether there are more documents available for
+    /// the export cursor on the server
+    #[serde(rename = "hasMore")]
+    has_more: bool,
+    /// The HTTP status code
+    code: u16,
+    /// A flag to indicate that an error occurred
+    error: bool,
+}
+
+impl<T> ExportBatch<T> {
+    pub(crate) fn take_result(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Properties;
+    use crate::db::ReplicationFactor;
+    use anyhow::Result;
+
+    #[test]
+    fn properties_replication_factor() -> Result<()> {
+        for factor in &[ReplicationFactor::Factor(2), ReplicationFactor::Satellite] {
+            let mut props = Properties::default();
+            let _ = props.set_replication_factor(Some(*factor));
+            let json = serde_json::to_value(&props)?;
+            let actual: Properties = serde_json::from_value(json)?;
+            assert_eq!(Some(*factor), *actual.replication_factor());
+        }
+        let mut json = serde_json::to_value(Properties::default())?;
+        json["replicationFactor"] = serde_json::json!(2);
+        let props: Properties = serde_json::from_value(json)?;
+        assert_eq!(
+            Some(ReplicationFactor::Factor(2)),
+            *props.replication_factor()
+        );
+        Ok(())
+    }
+}
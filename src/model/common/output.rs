@@ -14,6 +14,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(test)]
 use {crate::coll::output::Collections, getset::Setters};
 
+const ERROR_CONFLICT: usize = 1200;
+const ERROR_DOCUMENT_NOT_FOUND: usize = 1202;
+const ERROR_UNIQUE_CONSTRAINT_VIOLATED: usize = 1210;
+
 /// A base response
 #[derive(Clone, Debug, Deserialize, Getters, Serialize)]
 #[cfg_attr(test, derive(Setters), getset(set = "pub(crate)"))]
@@ -27,6 +31,12 @@ pub struct Response<T> {
     result: T,
 }
 
+impl<T> Response<T> {
+    pub(crate) fn result_mut(&mut self) -> &mut T {
+        &mut self.result
+    }
+}
+
 impl Default for Response<Current> {
     fn default() -> Self {
         Response {
@@ -83,3 +93,63 @@ pub struct ArangoErr {
     #[serde(rename = "errorMessage")]
     error_message: String,
 }
+
+impl ArangoErr {
+    /// Is this a `1202` document not found error?
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.error_num == ERROR_DOCUMENT_NOT_FOUND
+    }
+
+    /// Is this a `1200` write conflict or a `1210` unique constraint
+    /// violation?
+    #[must_use]
+    pub fn is_conflict(&self) -> bool {
+        self.error_num == ERROR_CONFLICT || self.error_num == ERROR_UNIQUE_CONSTRAINT_VIOLATED
+    }
+
+    /// Is this a `1200` conflict caused by a revision mismatch?  Batch
+    /// operations report a failed revision check with the same `1200` code
+    /// as a write conflict, so this overlaps with
+    /// [`is_conflict`](Self::is_conflict).
+    #[must_use]
+    pub fn is_precondition_failed(&self) -> bool {
+        self.error_num == ERROR_CONFLICT
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArangoErr;
+    use anyhow::Result;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn batch_error_predicates() -> Result<()> {
+        let batch = json!([
+            { "_key": "a", "_id": "test_coll/a", "_rev": "_a" },
+            { "error": true, "errorNum": 1202, "errorMessage": "document not found" },
+            { "error": true, "errorNum": 1200, "errorMessage": "conflict", "_key": "c" },
+            { "error": true, "errorNum": 1210, "errorMessage": "unique constraint violated" }
+        ]);
+        let errs = serde_json::from_value::<Vec<Value>>(batch)?
+            .into_iter()
+            .filter(|val| val.get("error").and_then(Value::as_bool) == Some(true))
+            .map(serde_json::from_value::<ArangoErr>)
+            .collect::<Result<Vec<ArangoErr>, _>>()?;
+        assert_eq!(3, errs.len());
+
+        assert!(errs[0].is_not_found());
+        assert!(!errs[0].is_conflict());
+        assert!(!errs[0].is_precondition_failed());
+
+        assert!(!errs[1].is_not_found());
+        assert!(errs[1].is_conflict());
+        assert!(errs[1].is_precondition_failed());
+
+        assert!(!errs[2].is_not_found());
+        assert!(errs[2].is_conflict());
+        assert!(!errs[2].is_precondition_failed());
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ use getset::Getters;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use serde::{Serialize as Ser, Serializer};
+use serde_json::Value;
 use std::collections::HashMap;
 
 const BATCH_SIZE_ZERO_ERR: &str = "batch_size cannot be 0!";
@@ -27,15 +28,23 @@ pub struct Config {
     /// Contains the query string to be executed
     #[builder(setter(into))]
     query: String,
-    /// key/value pairs representing the bind parameters.
-    #[builder(setter(strip_option), default)]
+    /// key/value pairs representing the bind parameters.  The values keep
+    /// their JSON type, so bind a number, i.e. `json!(5)`, when comparing
+    /// against a numeric attribute.
+    #[builder(setter(custom), default)]
     #[serde(rename = "bindVars", skip_serializing_if = "Option::is_none")]
-    bind_vars: Option<HashMap<String, String>>,
+    bind_vars: Option<HashMap<String, Value>>,
     /// Indicates whether the number of documents in the result set
     /// should be returned in the "count" attribute of the result.
     /// Calculating the "count" attribute might have a performance
     /// impact for some queries in the future so this option is
     /// turned off by default, and "count" is only returned when requested.
+    ///
+    /// When unset the attribute is omitted from the request, so the server
+    /// default applies.  Unless this is set to `true`,
+    /// [`count`](crate::cursor::output::CursorMeta::count) is `None`.  The
+    /// batch following helpers, i.e. [`query_all`](crate::Cursor::query_all),
+    /// only rely on `hasMore` and work either way.
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     count: Option<bool>,
@@ -51,6 +60,11 @@ pub struct Config {
     /// will be skipped for the query. If set to true, it will lead
     /// to the query cache being checked for the query if the query
     /// cache mode is either on or demand.
+    ///
+    /// Note that this has no effect if the server's query cache mode
+    /// (`--query.cache-mode`) is `off`, the default.  A result served from
+    /// the cache is flagged by
+    /// [`cached`](crate::cursor::output::CursorMeta::cached).
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     cache: Option<bool>,
@@ -60,7 +74,7 @@ pub struct Config {
     /// much memory. A value of 0 indicates that there is no memory limit.
     #[builder(setter(strip_option), default)]
     #[serde(rename = "memoryLimit", skip_serializing_if = "Option::is_none")]
-    memory_limit: Option<usize>,
+    memory_limit: Option<u64>,
     /// The time-to-live for the cursor (in seconds). The cursor will be
     /// removed on the server automatically after the specified amount of
     /// time. This is useful to ensure garbage collection of cursors that
@@ -68,7 +82,7 @@ pub struct Config {
     /// value will be used (default: 30 seconds).
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ttl: Option<usize>,
+    ttl: Option<u32>,
     /// Additional cursor options
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -76,6 +90,16 @@ pub struct Config {
 }
 
 impl ConfigBuilder {
+    /// key/value pairs representing the bind parameters.
+    pub fn bind_vars<T>(&mut self, bind_vars: HashMap<String, T>) -> &mut Self
+    where
+        T: Into<Value>,
+    {
+        let bind_vars = bind_vars.into_iter().map(|(k, v)| (k, v.into())).collect();
+        self.bind_vars = Some(Some(bind_vars));
+        self
+    }
+
     fn validate(&self) -> std::result::Result<(), String> {
         self.batch_size.as_ref().map_or(Ok(()), |bs_opt| {
             if let Some(0) = bs_opt {
@@ -129,6 +153,9 @@ pub struct Options {
     /// configuration option `--query.fail-on-warning` for setting the
     /// default value for `fail_on_warning` so it does not need to be set
     /// on a per-query level.
+    ///
+    /// A query aborted this way fails with [`Cursor`](crate::Error::Cursor),
+    /// carrying the `ArangoDB` error number of the warning.
     #[builder(setter(strip_option), default)]
     #[serde(rename = "failOnWarning")]
     fail_on_warning: Option<bool>,
@@ -173,15 +200,16 @@ pub struct Options {
     #[builder(setter(strip_option), default)]
     stream: Option<bool>,
     /// The query has to be executed within the given runtime or it
-    /// will be killed. The value is specified in seconds. The default
-    /// value is 0 (no timeout).
+    /// will be killed. The value is specified in seconds, fractions
+    /// allowed. The default value is 0 (no timeout).
     #[builder(setter(strip_option), default)]
     #[serde(rename = "maxRuntime")]
-    max_runtime: Option<usize>,
+    max_runtime: Option<f64>,
     /// Limits the maximum number of warnings a query will return.
     /// The number of warnings a query will return is limited to 10 by
     /// default, but that number can be increased or decreased by setting
-    /// this attribute.
+    /// this attribute.  The warnings are returned in
+    /// [`Extra::warnings`](crate::cursor::output::Extra::warnings).
     #[builder(setter(strip_option), default)]
     #[serde(rename = "maxWarningCount")]
     max_warning_count: Option<usize>,
@@ -204,7 +232,8 @@ pub struct Options {
     max_plans: Option<usize>,
     /// If set to true and the query contains a LIMIT clause, then the
     /// result will have an extra attribute with the sub-attributes
-    /// `stats` and `full_count`, { ... , "extra": { "stats": { "`full_count`": 123 } } }.
+    /// `stats` and `fullCount`, { ... , "extra": { "stats": { "fullCount": 123 } } },
+    /// available via [`Stats::full_count`](crate::cursor::output::Stats::full_count).
     /// The `fullCount` attribute will contain the number of documents
     /// in the result before the last top-level LIMIT in the query
     /// was applied. It can be used to count the number of documents
@@ -219,13 +248,53 @@ pub struct Options {
     ///
     /// Note that the `fullCount` attribute may only be present in
     /// the result if the query has a top-level LIMIT clause and the LIMIT
-    /// clause is actually used in the query.
+    /// clause is actually used in the query.  Otherwise the full count
+    /// stays `None`.
     #[builder(setter(strip_option), default)]
     #[serde(rename = "fullCount")]
     full_count: Option<bool>,
+    /// If set to true, the cursor can be asked to return the most recent
+    /// batch again with [`next_batch`](crate::Cursor::next_batch), i.e. if
+    /// the response was lost to a network error.  The id of the next batch
+    /// is returned in
+    /// [`next_batch_id`](crate::cursor::output::CursorMeta::next_batch_id).
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "allowRetry")]
+    allow_retry: Option<bool>,
+    /// If set to false, the query will not add the data it reads to the
+    /// `RocksDB` block cache, so large one-off queries do not evict more
+    /// frequently used data.  The default is true.
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "fillBlockCache", skip_serializing_if = "Option::is_none")]
+    fill_block_cache: Option<bool>,
+    /// If set to true, the query may read from followers in cluster or
+    /// active-failover setups, so read-only queries can be spread across
+    /// them.  The results may be slightly stale.  This applies to the query
+    /// as a whole, unlike the per-request `x-arango-allow-dirty-read` header
+    /// set by [`dirty_reads`](crate::ConnectionBuilder::dirty_reads).
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "allowDirtyReads", skip_serializing_if = "Option::is_none")]
+    allow_dirty_reads: Option<bool>,
 }
 
 /// Cursor creation optimizer rules
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use ruarango::cursor::input::{OptionsBuilder, Rules, RulesBuilder};
+/// #
+/// # pub fn main() -> Result<()> {
+/// let rules = Rules::disable("use-indexes").and_enable("reduce-extraction-to-projection");
+/// let options = OptionsBuilder::default().optimizer(rules).build()?;
+///
+/// // or with the raw `+rule`/`-rule` tokens
+/// let rules = RulesBuilder::default()
+///     .rules(vec!["-all".to_string(), "+use-indexes".to_string()])
+///     .build()?;
+/// #   Ok(())
+/// # }
+/// ```
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub(crate)")]
 pub struct Rules {
@@ -234,12 +303,134 @@ pub struct Rules {
     /// specific rules. To disable a rule, prefix its name with a `-`,
     /// to enable a rule, prefix it with a `+`. There is also a pseudo-rule
     /// `all`, which matches all optimizer rules. `-all` disables all rules.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     rules: Option<Vec<String>>,
 }
 
+impl Rules {
+    /// Rules enabling the given optimizer rule, i.e. `use-indexes`
+    #[must_use]
+    pub fn enable(name: &str) -> Self {
+        Self::default().and_enable(name)
+    }
+
+    /// Rules disabling the given optimizer rule, i.e. `use-indexes`
+    #[must_use]
+    pub fn disable(name: &str) -> Self {
+        Self::default().and_disable(name)
+    }
+
+    /// Also enable the given optimizer rule
+    #[must_use]
+    pub fn and_enable(self, name: &str) -> Self {
+        self.push(format!("+{name}"))
+    }
+
+    /// Also disable the given optimizer rule
+    #[must_use]
+    pub fn and_disable(self, name: &str) -> Self {
+        self.push(format!("-{name}"))
+    }
+
+    fn push(mut self, rule: String) -> Self {
+        self.rules.get_or_insert_with(Vec::new).push(rule);
+        self
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{ConfigBuilder, BATCH_SIZE_ZERO_ERR};
+    use super::{ConfigBuilder, OptionsBuilder, Rules, RulesBuilder, BATCH_SIZE_ZERO_ERR};
+    use anyhow::Result;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn guardrails_serialize() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .query("FOR d IN test_coll RETURN d")
+            .memory_limit(1_048_576)
+            .ttl(60)
+            .options(OptionsBuilder::default().max_runtime(1.5).build()?)
+            .build()?;
+        let value = serde_json::to_value(&config)?;
+        assert_eq!(Some(&Value::from(1_048_576)), value.get("memoryLimit"));
+        assert_eq!(Some(&Value::from(60)), value.get("ttl"));
+        assert_eq!(
+            Some(&Value::from(1.5)),
+            value.get("options").and_then(|o| o.get("maxRuntime"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cache_serialize() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .query("FOR d IN test_coll RETURN d")
+            .cache(true)
+            .options(OptionsBuilder::default().fill_block_cache(false).build()?)
+            .build()?;
+        let value = serde_json::to_value(&config)?;
+        assert_eq!(Some(&Value::from(true)), value.get("cache"));
+        assert_eq!(
+            Some(&Value::from(false)),
+            value.get("options").and_then(|o| o.get("fillBlockCache"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn count_serialize() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .query("FOR d IN test_coll RETURN d")
+            .build()?;
+        let value = serde_json::to_value(&config)?;
+        assert!(value.get("count").is_none());
+
+        let config = ConfigBuilder::default()
+            .query("FOR d IN test_coll RETURN d")
+            .count(false)
+            .build()?;
+        let value = serde_json::to_value(&config)?;
+        assert_eq!(Some(&Value::from(false)), value.get("count"));
+        assert!(serde_json::to_string(&config)?.contains(r#""count":false"#));
+        Ok(())
+    }
+
+    #[test]
+    fn allow_dirty_reads_serialize() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .query("FOR d IN test_coll RETURN d")
+            .options(OptionsBuilder::default().allow_dirty_reads(true).build()?)
+            .build()?;
+        let value = serde_json::to_value(&config)?;
+        assert_eq!(
+            Some(&Value::from(true)),
+            value.get("options").and_then(|o| o.get("allowDirtyReads"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rules_serialize() -> Result<()> {
+        let rules = Rules::disable("use-indexes").and_enable("reduce-extraction-to-projection");
+        let config = ConfigBuilder::default()
+            .query("FOR d IN test_coll RETURN d")
+            .options(OptionsBuilder::default().optimizer(rules).build()?)
+            .build()?;
+        let value = serde_json::to_value(&config)?;
+        assert_eq!(
+            Some(&json!({ "rules": ["-use-indexes", "+reduce-extraction-to-projection"] })),
+            value.pointer("/options/optimizer")
+        );
+
+        let rules = RulesBuilder::default()
+            .rules(vec!["-all".to_string()])
+            .build()?;
+        assert_eq!(json!({ "rules": ["-all"] }), serde_json::to_value(&rules)?);
+        assert_eq!(json!({}), serde_json::to_value(Rules::default())?);
+        Ok(())
+    }
 
     #[test]
     fn batch_size_zero_errors() {
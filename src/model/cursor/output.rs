@@ -29,7 +29,9 @@ pub struct CursorMeta<T> {
     #[serde(skip_serializing_if = "Option::is_none")]
     extra: Option<Extra>,
     /// The total number of result documents available (only available
-    /// if the query was executed with the count attribute set)
+    /// if the query was executed with the count attribute set).  This is
+    /// never available for a [`stream`](crate::cursor::input::OptionsBuilder::stream)
+    /// cursor, as the results are calculated as the batches are fetched.
     #[serde(skip_serializing_if = "Option::is_none")]
     count: Option<usize>,
     /// The HTTP status code
@@ -43,23 +45,46 @@ pub struct CursorMeta<T> {
     /// the cursor on the server
     #[serde(rename = "hasMore")]
     has_more: bool,
+    /// The id of the next batch, only available if the cursor was created
+    /// with [`allow_retry`](crate::cursor::input::OptionsBuilder::allow_retry).
+    /// The id of the current batch is one less, and can be re-fetched with
+    /// [`next_batch`](crate::Cursor::next_batch).
+    #[serde(rename = "nextBatchId", skip_serializing_if = "Option::is_none")]
+    next_batch_id: Option<String>,
     /// A flag to indicate that an error occurred
     error: bool,
 }
 
+impl<T> CursorMeta<T> {
+    pub(crate) fn take_result(&mut self) -> Option<Vec<T>> {
+        self.result.take()
+    }
+}
+
 /// Cursor metadata extra output
 #[derive(Clone, Debug, Deserialize, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct Extra {
     /// Query statistics
     stats: Stats,
-    /// Any generated warnings,
-    warnings: Vec<String>,
+    /// Any generated warnings, up to
+    /// [`max_warning_count`](crate::cursor::input::OptionsBuilder::max_warning_count)
+    warnings: Vec<QueryWarning>,
     /// Optional profile information
     #[serde(skip_serializing_if = "Option::is_none")]
     profile: Option<Profile>,
 }
 
+/// A warning generated while running a query, i.e. a division by zero
+#[derive(Clone, Debug, Deserialize, Eq, Getters, PartialEq, Serialize)]
+#[getset(get = "pub")]
+pub struct QueryWarning {
+    /// The `ArangoDB` error number of the warning
+    code: usize,
+    /// The warning message
+    message: String,
+}
+
 /// Cursor metadata extra stats output
 #[derive(Clone, Copy, Debug, Deserialize, Getters, Serialize)]
 #[getset(get = "pub")]
@@ -87,6 +112,30 @@ pub struct Stats {
     /// peak memory usage
     #[serde(rename = "peakMemoryUsage")]
     peak_memory_usage: usize,
+    /// The number of documents in the result before the last top-level
+    /// LIMIT was applied.  Only present if
+    /// [`full_count`](crate::cursor::input::OptionsBuilder::full_count) was
+    /// requested, and stays `None` if the query has no top-level LIMIT.
+    #[serde(rename = "fullCount", skip_serializing_if = "Option::is_none")]
+    full_count: Option<usize>,
+    /// The number of cursors created for sub-queries
+    #[serde(rename = "cursorsCreated", skip_serializing_if = "Option::is_none")]
+    cursors_created: Option<usize>,
+    /// The number of times sub-query cursors were re-armed
+    #[serde(rename = "cursorsRearmed", skip_serializing_if = "Option::is_none")]
+    cursors_rearmed: Option<usize>,
+    /// The number of in-memory cache hits
+    #[serde(rename = "cacheHits", skip_serializing_if = "Option::is_none")]
+    cache_hits: Option<usize>,
+    /// The number of in-memory cache misses
+    #[serde(rename = "cacheMisses", skip_serializing_if = "Option::is_none")]
+    cache_misses: Option<usize>,
+    /// The number of intermediate commits performed by the query
+    #[serde(
+        rename = "intermediateCommits",
+        skip_serializing_if = "Option::is_none"
+    )]
+    intermediate_commits: Option<usize>,
 }
 
 /// Extra profile information
@@ -114,3 +163,89 @@ pub struct Profile {
     /// finalizing
     finalizing: f64,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CursorMeta, Extra, Stats};
+    use anyhow::Result;
+
+    const STATS: &str = r#"{
+        "writesExecuted": 0,
+        "writesIgnored": 0,
+        "scannedFull": 10,
+        "scannedIndex": 0,
+        "filtered": 0,
+        "httpRequests": 0,
+        "executionTime": 0.001,
+        "peakMemoryUsage": 0
+    }"#;
+
+    const STATS_FULL_COUNT: &str = r#"{
+        "writesExecuted": 0,
+        "writesIgnored": 0,
+        "scannedFull": 10,
+        "scannedIndex": 0,
+        "filtered": 0,
+        "httpRequests": 0,
+        "executionTime": 0.001,
+        "peakMemoryUsage": 0,
+        "fullCount": 10,
+        "cursorsCreated": 0,
+        "cursorsRearmed": 0,
+        "cacheHits": 0,
+        "cacheMisses": 0,
+        "intermediateCommits": 0
+    }"#;
+
+    #[test]
+    fn cursor_meta_next_batch_id() -> Result<()> {
+        let json = r#"{"id":"123","result":[1,2],"hasMore":true,"nextBatchId":"2","cached":false,"code":201,"error":false}"#;
+        let meta: CursorMeta<usize> = serde_json::from_str(json)?;
+        assert_eq!(Some("2"), meta.next_batch_id().as_deref());
+
+        let json = r#"{"result":[1],"hasMore":false,"cached":false,"code":201,"error":false}"#;
+        let meta: CursorMeta<usize> = serde_json::from_str(json)?;
+        assert!(meta.next_batch_id().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_meta_streaming() -> Result<()> {
+        let json =
+            r#"{"id":"123","result":[],"hasMore":true,"cached":false,"code":201,"error":false}"#;
+        let meta: CursorMeta<usize> = serde_json::from_str(json)?;
+        assert!(meta.count().is_none());
+        assert!(meta.extra().is_none());
+        assert!(meta.has_more());
+        assert_eq!(Some(0), meta.result().as_ref().map(Vec::len));
+        Ok(())
+    }
+
+    #[test]
+    fn extra_warnings() -> Result<()> {
+        let json = format!(
+            r#"{{"stats":{STATS},"warnings":[{{"code":1562,"message":"division by zero"}}]}}"#
+        );
+        let extra: Extra = serde_json::from_str(&json)?;
+        assert_eq!(1, extra.warnings().len());
+        assert_eq!(1562, *extra.warnings()[0].code());
+        assert_eq!("division by zero", extra.warnings()[0].message());
+        Ok(())
+    }
+
+    #[test]
+    fn stats_without_full_count() -> Result<()> {
+        let stats: Stats = serde_json::from_str(STATS)?;
+        assert!(stats.full_count().is_none());
+        assert!(stats.cache_hits().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn stats_with_full_count() -> Result<()> {
+        let stats: Stats = serde_json::from_str(STATS_FULL_COUNT)?;
+        assert_eq!(Some(10), *stats.full_count());
+        assert_eq!(Some(0), *stats.intermediate_commits());
+        Ok(())
+    }
+}
@@ -39,8 +39,10 @@
 //! #   Ok(())
 //! # }
 //! ```
+use super::ReplicationFactor;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Database creation configuration
 #[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
@@ -71,7 +73,7 @@ pub struct Options {
     /// to every DB-Server (Enterprise Edition only), and 1, which disables replication (cluster only)
     #[serde(rename = "replicationFactor", skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
-    replication_factor: Option<String>,
+    replication_factor: Option<ReplicationFactor>,
     /// Default write concern for new collections created in this database.
     /// It determines how many copies of each shard are required to be
     /// in sync on the different DB-Servers. If there are less then these many copies
@@ -79,8 +81,8 @@ pub struct Options {
     /// up-to-date copies will succeed at the same time however. The value of
     /// writeConcern can not be larger than replicationFactor. (cluster only)
     #[serde(rename = "writeConcern", skip_serializing_if = "Option::is_none")]
-    #[builder(setter(into, strip_option), default)]
-    write_concern: Option<String>,
+    #[builder(setter(strip_option), default)]
+    write_concern: Option<usize>,
 }
 
 /// Optional user information used during database creation
@@ -90,20 +92,70 @@ pub struct User {
     #[builder(setter(into))]
     username: String,
     /// The user password as a string. If not specified, it will default to an empty string.
-    #[builder(setter(into))]
-    password: String,
+    #[serde(rename = "passwd", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    password: Option<String>,
     /// A flag indicating whether the user account should be activated or not.
     /// The default value is true. If set to false, the user won't be able to
     /// log into the database.
-    active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    active: Option<bool>,
+    /// A JSON object with extra user information. It is used by the web
+    /// interface to store graph viewer settings and saved queries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    extra: Option<Value>,
 }
 
 #[cfg(test)]
 mod test {
-    use super::CreateBuilder;
+    use super::{CreateBuilder, OptionsBuilder, UserBuilder};
+    use anyhow::Result;
+    use serde_json::json;
 
     #[test]
     fn current_builder_fails_when_missing_name() {
         assert!(CreateBuilder::default().build().is_err());
     }
+
+    #[test]
+    fn name_only_serializes_name() -> Result<()> {
+        let create = CreateBuilder::default().name("test_db").build()?;
+        assert_eq!(json!({ "name": "test_db" }), serde_json::to_value(create)?);
+        Ok(())
+    }
+
+    #[test]
+    fn options_and_users_serialize_nested() -> Result<()> {
+        let options = OptionsBuilder::default()
+            .sharding("single")
+            .replication_factor(2)
+            .write_concern(2)
+            .build()?;
+        let user = UserBuilder::default()
+            .username("test")
+            .password("test")
+            .active(true)
+            .extra(json!({ "key": "value" }))
+            .build()?;
+        let no_passwd = UserBuilder::default().username("other").build()?;
+        let create = CreateBuilder::default()
+            .name("test_db")
+            .options(options)
+            .users(vec![user, no_passwd])
+            .build()?;
+        assert_eq!(
+            json!({
+                "name": "test_db",
+                "options": { "sharding": "single", "replicationFactor": 2, "writeConcern": 2 },
+                "users": [
+                    { "username": "test", "passwd": "test", "active": true, "extra": { "key": "value" } },
+                    { "username": "other" }
+                ]
+            }),
+            serde_json::to_value(create)?
+        );
+        Ok(())
+    }
 }
@@ -10,3 +10,102 @@
 
 pub mod input;
 pub mod output;
+
+use serde::{
+    de::{self, Deserialize, Deserializer, Unexpected, Visitor},
+    ser::{Serialize, Serializer},
+};
+use std::{convert::TryFrom, fmt};
+
+/// The replication factor of a database or collection
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplicationFactor {
+    /// Keep this many copies of each shard
+    Factor(usize),
+    /// Replicate to every DB-Server (Enterprise Edition only)
+    Satellite,
+}
+
+impl From<usize> for ReplicationFactor {
+    fn from(factor: usize) -> Self {
+        Self::Factor(factor)
+    }
+}
+
+impl Serialize for ReplicationFactor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ReplicationFactor::Factor(factor) => serializer.serialize_u64(*factor as u64),
+            ReplicationFactor::Satellite => serializer.serialize_str("satellite"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ReplicationFactor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ReplicationFactorVisitor)
+    }
+}
+
+struct ReplicationFactorVisitor;
+
+impl Visitor<'_> for ReplicationFactorVisitor {
+    type Value = ReplicationFactor;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a replication factor or satellite")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        usize::try_from(value)
+            .map(ReplicationFactor::Factor)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match value {
+            "satellite" => Ok(ReplicationFactor::Satellite),
+            _ => value
+                .parse()
+                .map(ReplicationFactor::Factor)
+                .map_err(|_| E::invalid_value(Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReplicationFactor;
+    use anyhow::Result;
+
+    #[test]
+    fn replication_factor_round_trip() -> Result<()> {
+        for factor in &[ReplicationFactor::Factor(2), ReplicationFactor::Satellite] {
+            let json = serde_json::to_string(factor)?;
+            let actual: ReplicationFactor = serde_json::from_str(&json)?;
+            assert_eq!(*factor, actual);
+        }
+        assert_eq!("2", serde_json::to_string(&ReplicationFactor::Factor(2))?);
+        Ok(())
+    }
+
+    #[test]
+    fn replication_factor_from_str() -> Result<()> {
+        let actual: ReplicationFactor = serde_json::from_str("\"3\"")?;
+        assert_eq!(ReplicationFactor::Factor(3), actual);
+        assert!(serde_json::from_str::<ReplicationFactor>("\"bad\"").is_err());
+        Ok(())
+    }
+}
@@ -8,8 +8,10 @@
 
 //! Database Output Structs
 
+use super::ReplicationFactor;
 use getset::Getters;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Output when [`current`](crate::Database::current) is called for a document
 #[derive(Clone, Debug, Deserialize, Getters, Serialize)]
@@ -29,10 +31,47 @@ pub struct Current {
     sharding: Option<String>,
     /// The default replication factor for collections in this database
     #[serde(rename = "replicationFactor", skip_serializing_if = "Option::is_none")]
-    replication_factor: Option<String>,
+    replication_factor: Option<ReplicationFactor>,
     /// The default write concern for collections in this database
     #[serde(rename = "writeConcern", skip_serializing_if = "Option::is_none")]
-    write_concern: Option<String>,
+    write_concern: Option<usize>,
+}
+
+/// Output when [`engine`](crate::Database::engine) is called
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Engine {
+    /// The name of the storage engine, i.e. `rocksdb`
+    name: String,
+    /// The features supported by the storage engine
+    #[serde(default)]
+    supports: Supports,
+}
+
+impl Engine {
+    /// Is this the `MMFiles` storage engine?  Collection options such as
+    /// `journal_size`, `is_volatile` and `do_compact` are only meaningful for
+    /// `MMFiles`, and are ignored by `RocksDB`.
+    #[must_use]
+    pub fn is_mmfiles(&self) -> bool {
+        self.name == "mmfiles"
+    }
+}
+
+/// The features supported by a storage engine
+#[derive(Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Supports {
+    /// Does the engine support database directories, reported by older
+    /// server versions only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dfdb: Option<bool>,
+    /// The index types supported by the engine
+    #[serde(default)]
+    indexes: Vec<String>,
+    /// Index type aliases, i.e. `hash` is an alias of `persistent`
+    #[serde(default)]
+    aliases: HashMap<String, HashMap<String, String>>,
 }
 
 impl Default for Current {
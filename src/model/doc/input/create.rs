@@ -13,8 +13,8 @@ use crate::{
     model::{
         add_qp, add_qps, BuildUrl,
         QueryParam::{
-            KeepNull, MergeObjects, Overwrite, OverwriteMode as Mode, ReturnNew, ReturnOld, Silent,
-            WaitForSync,
+            KeepNull, MergeObjects, Overwrite, OverwriteMode as Mode, RefillIndexCaches as Refill,
+            ReturnNew, ReturnOld, Silent, VersionAttribute, WaitForSync,
         },
     },
     Connection,
@@ -25,9 +25,12 @@ use getset::Getters;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
+const VERSION_ATTRIBUTE_ERR: &str = "version_attribute requires overwrite or overwrite_mode!";
+
 /// Document creation configuration
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Config<T> {
     /// The collection to create the document in
     #[builder(setter(into))]
@@ -48,6 +51,10 @@ pub struct Config<T> {
     /// save some network traffic.
     #[builder(setter(strip_option), default)]
     silent: Option<bool>,
+    /// Refill the in-memory index caches affected by this operation, so that
+    /// they stay warm after the modification.
+    #[builder(setter(strip_option), default)]
+    refill_index_caches: Option<bool>,
     /// If set to true, the insert becomes a replace-insert. If a document with the
     /// same `_key` already exists the new document is not rejected with unique
     /// constraint violated but will replace the old document. Note that operations
@@ -73,6 +80,20 @@ pub struct Config<T> {
     /// This option controls the update-insert behavior only.
     #[builder(setter(strip_option), default)]
     merge_objects: Option<bool>,
+    /// The name of a numeric attribute holding the document version.  An
+    /// overwrite is only applied if the version of the incoming document
+    /// is greater than the version of the existing document, i.e. last
+    /// writer wins by version rather than by arrival.  Only valid along with
+    /// `overwrite` or an `overwrite_mode`, and has no effect in the
+    /// [`Conflict`](OverwriteMode::Conflict) or [`Ignore`](OverwriteMode::Ignore)
+    /// modes.
+    #[builder(setter(into, strip_option), default)]
+    version_attribute: Option<String>,
+    /// If the collection doesn't exist, create it as a document collection
+    /// with the default options and retry the write once.  The default is
+    /// false.
+    #[builder(setter(strip_option), default)]
+    create_collection: Option<bool>,
     /// The document to create
     document: T,
 }
@@ -99,14 +120,43 @@ impl<T> Config<T> {
                 add_qp(*self.keep_null(), &mut url, &mut has_qp, KeepNull);
                 add_qp(*self.merge_objects(), &mut url, &mut has_qp, MergeObjects);
             }
+
+            if *mode == OverwriteMode::Update || *mode == OverwriteMode::Replace {
+                let version_attribute = self.version_attribute().as_ref();
+                add_qps(version_attribute, &mut url, &mut has_qp, VersionAttribute);
+            }
         } else if self.overwrite().is_some() {
             add_qp(*self.overwrite(), &mut url, &mut has_qp, Overwrite);
+
+            if *self.overwrite() == Some(true) {
+                let version_attribute = self.version_attribute().as_ref();
+                add_qps(version_attribute, &mut url, &mut has_qp, VersionAttribute);
+            }
         }
 
+        add_qp(*self.refill_index_caches(), &mut url, &mut has_qp, Refill);
+
         url
     }
 }
 
+impl<T> ConfigBuilder<T>
+where
+    T: Clone,
+{
+    fn validate(&self) -> std::result::Result<(), String> {
+        let has_version_attribute = matches!(self.version_attribute, Some(Some(_)));
+        let overwrites = matches!(self.overwrite, Some(Some(true)))
+            || matches!(self.overwrite_mode, Some(Some(_)));
+
+        if has_version_attribute && !overwrites {
+            Err(VERSION_ATTRIBUTE_ERR.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl<T> BuildUrl for Config<T> {
     fn build_url(&self, base: &str, conn: &Connection) -> Result<Url> {
         let suffix = self.build_suffix(base);
@@ -118,18 +168,20 @@ impl<T> BuildUrl for Config<T> {
 
 #[cfg(test)]
 mod test {
-    use super::{Config, ConfigBuilder};
+    use super::{Config, ConfigBuilder, VERSION_ATTRIBUTE_ERR};
     use crate::{
         doc::{input::OverwriteMode, BASE_DOC_SUFFIX},
         model::{
-            KEEP_NULL_QP, MERGE_OBJECTS_QP, OVERWRITE_MODE_QP, OVERWRITE_QP, RETURN_NEW_QP,
-            RETURN_OLD_QP, SILENT_QP, TEST_COLL, WAIT_FOR_SYNC_QP,
+            KEEP_NULL_FALSE_QP, KEEP_NULL_QP, MERGE_OBJECTS_FALSE_QP, MERGE_OBJECTS_QP,
+            OVERWRITE_MODE_QP, OVERWRITE_QP, REFILL_INDEX_CACHES_QP, RETURN_NEW_QP, RETURN_OLD_QP,
+            SILENT_QP, TEST_COLL, VERSION_ATTRIBUTE_QP, WAIT_FOR_SYNC_QP,
         },
     };
     use anyhow::Result;
     use const_format::concatcp;
 
     const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL);
+    const REFILL_INDEX_CACHES_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", REFILL_INDEX_CACHES_QP);
     const WAIT_FOR_SYNC_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", WAIT_FOR_SYNC_QP);
     const SILENT_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", SILENT_QP);
     const RETURN_NEW_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", RETURN_NEW_QP);
@@ -146,8 +198,28 @@ mod test {
         "&",
         MERGE_OBJECTS_QP
     );
+    const OVERWRITE_MODE_UPDATE_FALSE_ACTUAL: &str = concatcp!(
+        BASIC_ACTUAL,
+        "?",
+        OVERWRITE_MODE_QP,
+        "update",
+        "&",
+        KEEP_NULL_FALSE_QP,
+        "&",
+        MERGE_OBJECTS_FALSE_QP
+    );
     const OVERWRITE_MODE_REPLACE_ACTUAL: &str =
         concatcp!(BASIC_ACTUAL, "?", OVERWRITE_MODE_QP, "replace");
+    const VERSION_REPLACE_ACTUAL: &str = concatcp!(
+        OVERWRITE_MODE_REPLACE_ACTUAL,
+        "&",
+        VERSION_ATTRIBUTE_QP,
+        "version"
+    );
+    const VERSION_OVERWRITE_ACTUAL: &str =
+        concatcp!(OVERWRITE_ACTUAL, "&", VERSION_ATTRIBUTE_QP, "version");
+    const VERSION_CONFLICT_ACTUAL: &str =
+        concatcp!(BASIC_ACTUAL, "?", OVERWRITE_MODE_QP, "conflict");
     const ALL_ACTUAL: &str = concatcp!(
         BASIC_ACTUAL,
         "?",
@@ -283,6 +355,19 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn create_overwrite_mode_update_false_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test)")
+            .overwrite_mode(OverwriteMode::Update)
+            .keep_null(false)
+            .merge_objects(false)
+            .build()?;
+        check_url(&config, OVERWRITE_MODE_UPDATE_FALSE_ACTUAL);
+        Ok(())
+    }
+
     #[test]
     fn create_overwrite_mode_non_update_url() -> Result<()> {
         let config = ConfigBuilder::default()
@@ -311,4 +396,64 @@ mod test {
         check_url(&config, ALL_ACTUAL);
         Ok(())
     }
+
+    #[test]
+    fn create_refill_index_caches_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test")
+            .build()?;
+        check_url(&config, BASIC_ACTUAL);
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test")
+            .refill_index_caches(true)
+            .build()?;
+        check_url(&config, REFILL_INDEX_CACHES_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn create_version_attribute_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test")
+            .overwrite_mode(OverwriteMode::Replace)
+            .version_attribute("version")
+            .build()?;
+        check_url(&config, VERSION_REPLACE_ACTUAL);
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test")
+            .overwrite(true)
+            .version_attribute("version")
+            .build()?;
+        check_url(&config, VERSION_OVERWRITE_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn create_version_attribute_conflict_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test")
+            .overwrite_mode(OverwriteMode::Conflict)
+            .version_attribute("version")
+            .build()?;
+        check_url(&config, VERSION_CONFLICT_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn version_attribute_without_overwrite_errors() {
+        match ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document("test")
+            .version_attribute("version")
+            .build()
+        {
+            Ok(_) => panic!("The builder should fail!"),
+            Err(e) => assert_eq!(VERSION_ATTRIBUTE_ERR, format!("{e}")),
+        }
+    }
 }
@@ -14,7 +14,7 @@ use crate::{
         add_qp, add_qps, BuildUrl,
         QueryParam::{
             KeepNull, MergeObjects, Overwrite, OverwriteMode as Mode, ReturnNew, ReturnOld, Silent,
-            WaitForSync,
+            VersionAttribute, WaitForSync,
         },
     },
     Connection,
@@ -25,9 +25,12 @@ use getset::Getters;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
+const VERSION_ATTRIBUTE_ERR: &str = "version_attribute requires overwrite or overwrite_mode!";
+
 /// Documents creation configuration
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Config<T> {
     /// The collection to create the document in
     #[builder(setter(into))]
@@ -73,6 +76,20 @@ pub struct Config<T> {
     /// This option controls the update-insert behavior only.
     #[builder(setter(strip_option), default)]
     merge_objects: Option<bool>,
+    /// The name of a numeric attribute holding the document version.  An
+    /// overwrite is only applied if the version of the incoming document
+    /// is greater than the version of the existing document, i.e. last
+    /// writer wins by version rather than by arrival.  Only valid along with
+    /// `overwrite` or an `overwrite_mode`, and has no effect in the
+    /// [`Conflict`](OverwriteMode::Conflict) or [`Ignore`](OverwriteMode::Ignore)
+    /// modes.
+    #[builder(setter(into, strip_option), default)]
+    version_attribute: Option<String>,
+    /// If the collection doesn't exist, create it as a document collection
+    /// with the default options and retry the write once.  The default is
+    /// false.
+    #[builder(setter(strip_option), default)]
+    create_collection: Option<bool>,
     /// The document to create
     document: Vec<T>,
 }
@@ -98,14 +115,41 @@ impl<T> Config<T> {
                 add_qp(*self.keep_null(), &mut url, &mut has_qp, KeepNull);
                 add_qp(*self.merge_objects(), &mut url, &mut has_qp, MergeObjects);
             }
+
+            if *mode == OverwriteMode::Update || *mode == OverwriteMode::Replace {
+                let version_attribute = self.version_attribute().as_ref();
+                add_qps(version_attribute, &mut url, &mut has_qp, VersionAttribute);
+            }
         } else if self.overwrite().is_some() {
             add_qp(*self.overwrite(), &mut url, &mut has_qp, Overwrite);
+
+            if *self.overwrite() == Some(true) {
+                let version_attribute = self.version_attribute().as_ref();
+                add_qps(version_attribute, &mut url, &mut has_qp, VersionAttribute);
+            }
         }
 
         url
     }
 }
 
+impl<T> ConfigBuilder<T>
+where
+    T: Clone,
+{
+    fn validate(&self) -> std::result::Result<(), String> {
+        let has_version_attribute = matches!(self.version_attribute, Some(Some(_)));
+        let overwrites = matches!(self.overwrite, Some(Some(true)))
+            || matches!(self.overwrite_mode, Some(Some(_)));
+
+        if has_version_attribute && !overwrites {
+            Err(VERSION_ATTRIBUTE_ERR.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl<T> BuildUrl for Config<T> {
     fn build_url(&self, base: &str, conn: &Connection) -> Result<Url> {
         let suffix = self.build_suffix(base);
@@ -114,3 +158,56 @@ impl<T> BuildUrl for Config<T> {
             .with_context(|| format!("Unable to build '{suffix}' url"))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ConfigBuilder;
+    use crate::{
+        doc::{input::OverwriteMode, BASE_DOC_SUFFIX},
+        model::{KEEP_NULL_FALSE_QP, OVERWRITE_MODE_QP, TEST_COLL},
+    };
+    use anyhow::Result;
+    use const_format::concatcp;
+
+    const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL);
+    const OVERWRITE_MODE_UPDATE_ACTUAL: &str = concatcp!(
+        BASIC_ACTUAL,
+        "?",
+        OVERWRITE_MODE_QP,
+        "update",
+        "&",
+        KEEP_NULL_FALSE_QP
+    );
+    const OVERWRITE_MODE_REPLACE_ACTUAL: &str =
+        concatcp!(BASIC_ACTUAL, "?", OVERWRITE_MODE_QP, "replace");
+
+    #[test]
+    fn creates_overwrite_mode_update_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document(vec!["test"])
+            .overwrite_mode(OverwriteMode::Update)
+            .keep_null(false)
+            .build()?;
+        assert_eq!(
+            OVERWRITE_MODE_UPDATE_ACTUAL,
+            config.build_suffix(BASE_DOC_SUFFIX)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn creates_overwrite_mode_replace_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document(vec!["test"])
+            .overwrite_mode(OverwriteMode::Replace)
+            .keep_null(false)
+            .build()?;
+        assert_eq!(
+            OVERWRITE_MODE_REPLACE_ACTUAL,
+            config.build_suffix(BASE_DOC_SUFFIX)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Document Delete By Query Input Structs
+
+use crate::model::cursor::input::{CreateConfig, CreateConfigBuilder};
+use anyhow::Result;
+use derive_builder::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const EMPTY_FILTER_ERR: &str =
+    "filter cannot be empty unless allow_delete_all is true, as this would delete every document!";
+
+/// Document delete by query configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Config {
+    /// The collection to delete the documents from
+    #[builder(setter(into))]
+    collection: String,
+    /// An AQL condition on the current document `d`, i.e. `d.age > @age`.
+    /// Only the matching documents are deleted.
+    #[builder(setter(into, strip_option), default)]
+    filter: Option<String>,
+    /// The bind parameters used in the filter, without the leading `@`.
+    /// The values keep their JSON type, so bind a number, i.e. `json!(30)`,
+    /// when comparing against a numeric attribute.
+    #[builder(setter(strip_option), default)]
+    bind_vars: Option<HashMap<String, Value>>,
+    /// Allow an empty filter, which deletes every document in the collection
+    /// (default: false).  Prefer [`truncate`](crate::Collection::truncate)
+    /// for this.
+    #[builder(default)]
+    allow_delete_all: bool,
+}
+
+impl ConfigBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        let has_filter = self
+            .filter
+            .as_ref()
+            .and_then(Option::as_ref)
+            .map_or(false, |filter| !filter.trim().is_empty());
+
+        if has_filter || self.allow_delete_all.unwrap_or_default() {
+            Ok(())
+        } else {
+            Err(EMPTY_FILTER_ERR.into())
+        }
+    }
+}
+
+impl Config {
+    fn query(&self) -> String {
+        let mut query = "FOR d IN @@collection".to_string();
+
+        if let Some(filter) = self.filter() {
+            if !filter.trim().is_empty() {
+                query.push_str(" FILTER ");
+                query.push_str(filter);
+            }
+        }
+        query.push_str(" REMOVE d IN @@collection");
+        query
+    }
+
+    pub(crate) fn cursor_config(&self) -> Result<CreateConfig> {
+        let mut bind_vars = self.bind_vars.clone().unwrap_or_default();
+        let _ = bind_vars.insert(
+            "@collection".to_string(),
+            Value::String(self.collection.clone()),
+        );
+
+        Ok(CreateConfigBuilder::default()
+            .query(self.query())
+            .bind_vars(bind_vars)
+            .build()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConfigBuilder, EMPTY_FILTER_ERR};
+    use anyhow::Result;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn delete_by_query_query() -> Result<()> {
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert("test".to_string(), json!("delete"));
+        let config = ConfigBuilder::default()
+            .collection("test_coll")
+            .filter("d.test == @test")
+            .bind_vars(bind_vars)
+            .build()?;
+        assert_eq!(
+            "FOR d IN @@collection FILTER d.test == @test REMOVE d IN @@collection",
+            config.query()
+        );
+        let cursor_config = config.cursor_config()?;
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(2, bind_vars.len());
+        assert_eq!(Some(&json!("test_coll")), bind_vars.get("@collection"));
+        Ok(())
+    }
+
+    #[test]
+    fn delete_by_query_numeric_bind_var() -> Result<()> {
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert("age".to_string(), json!(30));
+        let config = ConfigBuilder::default()
+            .collection("test_coll")
+            .filter("d.age > @age")
+            .bind_vars(bind_vars)
+            .build()?;
+        let body: Value = serde_json::to_value(config.cursor_config()?)?;
+        assert_eq!(
+            json!({
+                "query": "FOR d IN @@collection FILTER d.age > @age REMOVE d IN @@collection",
+                "bindVars": { "@collection": "test_coll", "age": 30 }
+            }),
+            body
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn delete_all_query() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection("test_coll")
+            .allow_delete_all(true)
+            .build()?;
+        assert_eq!(
+            "FOR d IN @@collection REMOVE d IN @@collection",
+            config.query()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty_filter_errors() {
+        for builder in &[
+            ConfigBuilder::default().collection("test_coll").clone(),
+            ConfigBuilder::default()
+                .collection("test_coll")
+                .filter(" ")
+                .clone(),
+        ] {
+            match builder.build() {
+                Ok(_) => panic!("The builder should fail!"),
+                Err(e) => assert_eq!(EMPTY_FILTER_ERR, format!("{e}")),
+            }
+        }
+    }
+}
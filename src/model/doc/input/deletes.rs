@@ -10,8 +10,10 @@
 
 use crate::{
     model::{
-        add_qp, BuildUrl,
-        QueryParam::{IgnoreRevs, ReturnOld, WaitForSync},
+        add_qp,
+        doc::input::check_keys,
+        BuildUrl,
+        QueryParam::{IgnoreRevs, ReturnOld, Silent, WaitForSync},
     },
     Connection,
 };
@@ -28,7 +30,10 @@ pub struct Config<T> {
     /// The collection to replace the document in
     #[builder(setter(into))]
     collection: String,
-    /// The document keys to delete
+    /// The documents to delete.  These are either document keys, or selector
+    /// objects with a `_key` and, optionally, a `_rev` attribute.  The `_rev`
+    /// is only checked if [`ignore_revs`](ConfigBuilder::ignore_revs) is
+    /// false.
     documents: Vec<T>,
     /// Wait until the delete operation has been synced to disk.
     #[builder(setter(strip_option), default)]
@@ -37,11 +42,21 @@ pub struct Config<T> {
     /// in the result.
     #[builder(setter(strip_option), default)]
     return_old: Option<bool>,
+    /// If set to true, no meta-data will be returned for the deleted
+    /// documents, only the errors of any deletes that failed.  This option
+    /// supersedes `return_old`, and can be used to save some network traffic.
+    #[builder(setter(strip_option), default)]
+    silent: Option<bool>,
     /// If set to true, ignore any `_rev` attribute in the selectors. No
     /// revision check is performed. If set to false then revisions are checked.
     /// The default is true.
     #[builder(setter(into, strip_option), default)]
     ignore_revs: Option<bool>,
+    /// Check that every document has a `_key` before sending the request,
+    /// returning a [`MissingKey`](crate::Error::MissingKey) error naming the
+    /// first document without one (default: false).
+    #[builder(setter(strip_option), default)]
+    validate_keys: Option<bool>,
 }
 
 impl<T> Config<T> {
@@ -50,11 +65,26 @@ impl<T> Config<T> {
         let mut has_qp = false;
 
         add_qp(*self.wait_for_sync(), &mut url, &mut has_qp, WaitForSync);
-        add_qp(*self.return_old(), &mut url, &mut has_qp, ReturnOld);
+        if self.silent().is_some() {
+            add_qp(*self.silent(), &mut url, &mut has_qp, Silent);
+        } else {
+            add_qp(*self.return_old(), &mut url, &mut has_qp, ReturnOld);
+        }
         add_qp(*self.ignore_revs(), &mut url, &mut has_qp, IgnoreRevs);
 
         url
     }
+
+    pub(crate) fn check_keys(&self) -> Result<()>
+    where
+        T: Serialize,
+    {
+        if self.validate_keys.unwrap_or_default() {
+            check_keys(&self.documents)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<T> BuildUrl for Config<T> {
@@ -70,15 +100,19 @@ impl<T> BuildUrl for Config<T> {
 mod test {
     use super::{Config, ConfigBuilder};
     use crate::model::{
-        doc::BASE_DOC_SUFFIX, IGNORE_REVS_QP, RETURN_OLD_QP, TEST_COLL, TEST_KEY, WAIT_FOR_SYNC_QP,
+        doc::BASE_DOC_SUFFIX, IGNORE_REVS_FALSE_QP, IGNORE_REVS_QP, RETURN_OLD_QP, SILENT_QP,
+        TEST_COLL, TEST_KEY, WAIT_FOR_SYNC_QP,
     };
     use anyhow::Result;
     use const_format::concatcp;
+    use serde_json::json;
 
     const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL);
     const WAIT_FOR_SYNC_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", WAIT_FOR_SYNC_QP);
     const RETURN_OLD_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", RETURN_OLD_QP);
+    const SILENT_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", SILENT_QP);
     const IGNORE_REVS_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", IGNORE_REVS_QP);
+    const IGNORE_REVS_FALSE_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", IGNORE_REVS_FALSE_QP);
     const ALL_ACTUAL: &str = concatcp!(
         BASIC_ACTUAL,
         "?",
@@ -103,6 +137,18 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn deletes_silent_forces_no_return_old_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![TEST_KEY])
+            .silent(true)
+            .return_old(true)
+            .build()?;
+        check_url(&config, SILENT_ACTUAL);
+        Ok(())
+    }
+
     #[test]
     fn deletes_wait_for_sync_url() -> Result<()> {
         let config = ConfigBuilder::default()
@@ -136,6 +182,17 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn deletes_ignore_revs_false_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![json!({ "_key": TEST_KEY, "_rev": "_rev" })])
+            .ignore_revs(false)
+            .build()?;
+        check_url(&config, IGNORE_REVS_FALSE_ACTUAL);
+        Ok(())
+    }
+
     #[test]
     fn deletes_all() -> Result<()> {
         let config = ConfigBuilder::default()
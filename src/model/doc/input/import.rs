@@ -0,0 +1,206 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Document Import Input Structs
+
+use crate::{
+    model::{
+        add_qp, add_qps, BuildUrl,
+        QueryParam::{
+            Collection, Complete, Details, DocumentsType, OnDuplicate as Duplicate, WaitForSync,
+        },
+    },
+    utils::prepend_sep,
+    Connection,
+};
+use anyhow::{Context, Result};
+use derive_builder::Builder;
+use getset::Getters;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+/// Controls what happens when an imported document has a `_key` that
+/// already exists in the collection
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnDuplicate {
+    /// Do not import the document and report it as an error.  This is the
+    /// default.
+    Error,
+    /// Update (patch) the existing document with the imported attributes
+    Update,
+    /// Replace the existing document with the imported document
+    Replace,
+    /// Do not import the document, but do not report it as an error either
+    Ignore,
+}
+
+/// # Example
+///
+/// ```
+/// # use ruarango::doc::input::OnDuplicate;
+/// let on_duplicate: String = OnDuplicate::Ignore.into();
+/// ```
+impl From<OnDuplicate> for String {
+    /// # Example
+    ///
+    /// ```
+    /// # use ruarango::doc::input::OnDuplicate;
+    /// let on_duplicate: String = OnDuplicate::Ignore.into();
+    /// ```
+    fn from(on_duplicate: OnDuplicate) -> String {
+        match on_duplicate {
+            OnDuplicate::Error => "error",
+            OnDuplicate::Update => "update",
+            OnDuplicate::Replace => "replace",
+            OnDuplicate::Ignore => "ignore",
+        }
+        .to_string()
+    }
+}
+
+/// Document import configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct Config<T> {
+    /// The collection to import the documents into
+    #[builder(setter(into))]
+    collection: String,
+    /// Controls the behavior when a document with the same `_key` already
+    /// exists.  The server default is [`Error`](OnDuplicate::Error).
+    #[builder(setter(strip_option), default)]
+    on_duplicate: Option<OnDuplicate>,
+    /// If set to true, the whole import will fail if any error occurs,
+    /// otherwise the import will continue even if some documents cannot be
+    /// imported.
+    #[builder(setter(strip_option), default)]
+    complete: Option<bool>,
+    /// If set to true, the result will include a `details` attribute with
+    /// information about documents that could not be imported.
+    #[builder(setter(strip_option), default)]
+    details: Option<bool>,
+    /// Wait until the documents have been synced to disk.
+    #[builder(setter(strip_option), default)]
+    wait_for_sync: Option<bool>,
+    /// The documents to import
+    documents: Vec<T>,
+}
+
+impl<T> Config<T> {
+    fn build_suffix(&self, base: &str) -> String {
+        let mut url = base.to_string();
+        let mut has_qp = false;
+
+        add_qps(Some(self.collection()), &mut url, &mut has_qp, Collection);
+        let _ = prepend_sep(&mut url, has_qp);
+        url.push_str(&String::from(DocumentsType));
+        add_qps(*self.on_duplicate(), &mut url, &mut has_qp, Duplicate);
+        add_qp(*self.complete(), &mut url, &mut has_qp, Complete);
+        add_qp(*self.details(), &mut url, &mut has_qp, Details);
+        add_qp(*self.wait_for_sync(), &mut url, &mut has_qp, WaitForSync);
+
+        url
+    }
+}
+
+impl<T> Config<T>
+where
+    T: Serialize,
+{
+    /// Serialize the documents as JSON lines, one document per line
+    pub(crate) fn body(&self) -> Result<String> {
+        let lines = self
+            .documents()
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<std::result::Result<Vec<String>, _>>()
+            .with_context(|| "Unable to serialize the import documents")?;
+        Ok(lines.join("\n"))
+    }
+}
+
+impl<T> BuildUrl for Config<T> {
+    fn build_url(&self, base: &str, conn: &Connection) -> Result<Url> {
+        let suffix = self.build_suffix(base);
+        conn.db_url()
+            .join(&suffix)
+            .with_context(|| format!("Unable to build '{suffix}' url"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Config, ConfigBuilder, OnDuplicate};
+    use crate::model::{
+        doc::BASE_IMPORT_SUFFIX, COLLECTION_QP, COMPLETE_QP, DETAILS_QP, ON_DUPLICATE_QP,
+        TEST_COLL, TYPE_DOCUMENTS_QP,
+    };
+    use anyhow::Result;
+    use const_format::concatcp;
+    use serde::Serialize;
+
+    const BASIC_ACTUAL: &str = concatcp!(
+        BASE_IMPORT_SUFFIX,
+        "?",
+        COLLECTION_QP,
+        TEST_COLL,
+        "&",
+        TYPE_DOCUMENTS_QP
+    );
+    const ALL_ACTUAL: &str = concatcp!(
+        BASIC_ACTUAL,
+        "&",
+        ON_DUPLICATE_QP,
+        "ignore&",
+        COMPLETE_QP,
+        "&",
+        DETAILS_QP
+    );
+
+    #[derive(Clone, Debug, Serialize)]
+    struct TestDoc {
+        test: usize,
+    }
+
+    fn check_url<T>(config: &Config<T>, actual: &str) {
+        assert_eq!(actual, config.build_suffix(BASE_IMPORT_SUFFIX));
+    }
+
+    #[test]
+    fn import_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![TestDoc { test: 1 }])
+            .build()?;
+        check_url(&config, BASIC_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn import_all_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .on_duplicate(OnDuplicate::Ignore)
+            .complete(true)
+            .details(true)
+            .documents(vec![TestDoc { test: 1 }])
+            .build()?;
+        check_url(&config, ALL_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn import_body_is_json_lines() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![TestDoc { test: 1 }, TestDoc { test: 2 }])
+            .build()?;
+        assert_eq!("{\"test\":1}\n{\"test\":2}", config.body()?);
+        Ok(())
+    }
+}
@@ -11,8 +11,12 @@
 mod create;
 mod creates;
 mod delete;
+mod delete_by_query;
 mod deletes;
+mod import;
 mod read;
+mod read_by;
+mod read_projected;
 mod reads;
 mod replace;
 mod replaces;
@@ -31,14 +35,30 @@ pub use delete::{
     Config as DeleteConfig, ConfigBuilder as DeleteConfigBuilder,
     ConfigBuilderError as DeleteConfigBuilderError,
 };
+pub use delete_by_query::{
+    Config as DeleteByQueryConfig, ConfigBuilder as DeleteByQueryConfigBuilder,
+    ConfigBuilderError as DeleteByQueryConfigBuilderError,
+};
 pub use deletes::{
     Config as DeletesConfig, ConfigBuilder as DeletesConfigBuilder,
     ConfigBuilderError as DeletesConfigBuilderError,
 };
+pub use import::{
+    Config as ImportConfig, ConfigBuilder as ImportConfigBuilder,
+    ConfigBuilderError as ImportConfigBuilderError, OnDuplicate,
+};
 pub use read::{
     Config as ReadConfig, ConfigBuilder as ReadConfigBuilder,
     ConfigBuilderError as ReadConfigBuilderError,
 };
+pub use read_by::{
+    Config as ReadByConfig, ConfigBuilder as ReadByConfigBuilder,
+    ConfigBuilderError as ReadByConfigBuilderError,
+};
+pub use read_projected::{
+    Config as ReadProjectedConfig, ConfigBuilder as ReadProjectedConfigBuilder,
+    ConfigBuilderError as ReadProjectedConfigBuilderError,
+};
 pub use reads::{
     Config as ReadsConfig, ConfigBuilder as ReadsConfigBuilder,
     ConfigBuilderError as ReadsConfigBuilderError,
@@ -60,11 +80,13 @@ pub use updates::{
     ConfigBuilderError as UpdatesConfigBuilderError,
 };
 
+use crate::error::RuarangoErr::MissingKey;
 use anyhow::Result;
 use serde::{
-    de::{self, Deserialize, Deserializer, Visitor},
+    de::{self, Deserialize, Deserializer, Unexpected, Visitor},
     ser::{Serialize, Serializer},
 };
+use serde_json::Value;
 use std::fmt;
 
 /// Overwrite Modes
@@ -160,20 +182,83 @@ impl Visitor<'_> for OverwriteModeVisitor {
     type Value = OverwriteMode;
 
     fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str("u64")
+        formatter.write_str("one of ignore/update/replace/conflict")
     }
 
     fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
     where
         E: de::Error,
     {
-        let normalized = value.to_lowercase();
-        match &normalized[..] {
+        match value {
             "ignore" => Ok(OverwriteMode::Ignore),
             "replace" => Ok(OverwriteMode::Replace),
             "update" => Ok(OverwriteMode::Update),
             "conflict" => Ok(OverwriteMode::Conflict),
-            _ => Err(E::custom("Invalid overwrite mode")),
+            _ => Err(E::invalid_value(Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+/// Check that every document in a batch is either a key or an object with
+/// a `_key`, returning a [`MissingKey`] error for the first one that isn't
+pub(crate) fn check_keys<T>(documents: &[T]) -> Result<()>
+where
+    T: Serialize,
+{
+    for (index, document) in documents.iter().enumerate() {
+        let has_key = match serde_json::to_value(document)? {
+            Value::String(_) => true,
+            Value::Object(map) => map.contains_key("_key"),
+            _ => false,
+        };
+        if !has_key {
+            return Err(MissingKey { index }.into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_keys, OverwriteMode};
+    use crate::error::RuarangoErr;
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn check_keys_works() -> Result<()> {
+        check_keys(&[json!({ "_key": "a" }), json!("b")])?;
+
+        let err = check_keys(&[json!({ "_key": "a" }), json!({ "test": "b" })]).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::MissingKey { index: 1 })
+        ));
+        assert_eq!("The document at index 1 has no '_key'", format!("{err}"));
+        Ok(())
+    }
+
+    #[test]
+    fn overwrite_mode_round_trip() -> Result<()> {
+        for mode in &[
+            OverwriteMode::Ignore,
+            OverwriteMode::Update,
+            OverwriteMode::Replace,
+            OverwriteMode::Conflict,
+        ] {
+            let json = serde_json::to_string(mode)?;
+            let actual: OverwriteMode = serde_json::from_str(&json)?;
+            assert_eq!(*mode, actual);
         }
+        Ok(())
+    }
+
+    #[test]
+    fn bad_overwrite_mode_errors() {
+        let res = serde_json::from_str::<OverwriteMode>("\"upsert\"");
+        assert!(res.is_err());
+        let message = res.err().map(|e| e.to_string()).unwrap_or_default();
+        assert!(message.contains("upsert"));
+        assert!(message.contains("one of ignore/update/replace/conflict"));
     }
 }
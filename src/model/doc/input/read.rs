@@ -9,8 +9,7 @@
 //! Document Read Input Structs
 
 use crate::{
-    error::RuarangoErr::Unreachable,
-    model::{AddHeaders, BuildUrl},
+    model::{AddHeaders, BuildUrl, ALLOW_DIRTY_READ},
     Connection,
 };
 use anyhow::{Context, Result};
@@ -44,6 +43,12 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(setter(into, strip_option), default)]
     if_match: Option<String>,
+    /// Allow the read to be served by a follower in cluster or active-failover
+    /// setups.  This overrides the connection wide
+    /// [`dirty_reads`](crate::ConnectionBuilder::dirty_reads) setting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    allow_dirty_read: Option<bool>,
 }
 
 impl Config {
@@ -63,7 +68,7 @@ impl BuildUrl for Config {
 
 impl AddHeaders for Config {
     fn has_header(&self) -> bool {
-        self.if_match.is_some() || self.if_none_match.is_some()
+        self.if_match.is_some() || self.if_none_match.is_some() || self.allow_dirty_read.is_some()
     }
 
     fn add_headers(&self) -> Result<Option<HeaderMap>> {
@@ -76,19 +81,21 @@ impl AddHeaders for Config {
                     HeaderName::from_static("if-match"),
                     HeaderValue::from_str(rev)?,
                 );
-                headers = Some(headers_map);
             } else if let Some(rev) = self.if_none_match() {
                 let _ = headers_map.append(
                     HeaderName::from_static("if-none-match"),
                     HeaderValue::from_str(rev)?,
                 );
-                headers = Some(headers_map);
-            } else {
-                return Err(Unreachable {
-                    msg: "One of 'if_match' or 'if_none_match' should be true!".to_string(),
-                }
-                .into());
             }
+
+            if let Some(allow_dirty_read) = self.allow_dirty_read() {
+                let _ = headers_map.append(
+                    HeaderName::from_static(ALLOW_DIRTY_READ),
+                    HeaderValue::from_static(if *allow_dirty_read { "true" } else { "false" }),
+                );
+            }
+
+            headers = Some(headers_map);
         }
         Ok(headers)
     }
@@ -143,6 +150,25 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn has_allow_dirty_read_header() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .key(TEST_KEY)
+            .if_match("_rev")
+            .allow_dirty_read(true)
+            .build()?;
+        let headers = config.add_headers()?.unwrap_or_default();
+        assert_eq!(headers.keys_len(), 2);
+        assert_eq!(
+            headers
+                .get("x-arango-allow-dirty-read")
+                .map(|x| x.as_bytes()),
+            Some(&b"true"[..])
+        );
+        Ok(())
+    }
+
     #[test]
     fn has_no_header() -> Result<()> {
         let config = ConfigBuilder::default()
@@ -0,0 +1,137 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Document Read By Selector Input Structs
+
+use crate::{
+    model::{
+        add_qp, AddHeaders, BuildUrl,
+        QueryParam::{IgnoreRevs, OnlyGet},
+        ALLOW_DIRTY_READ,
+    },
+    Connection,
+};
+use anyhow::{Context, Result};
+use derive_builder::Builder;
+use getset::Getters;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Url,
+};
+use serde::{Deserialize, Serialize};
+
+/// Read a document by selector configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct Config<T> {
+    /// The collection to read the document from
+    #[builder(setter(into))]
+    collection: String,
+    /// Should the value be true (the default):
+    /// If the selector contains a value for the `_rev` field,
+    /// then the document is only returned if it has the same revision value.
+    /// Otherwise a precondition failed error is returned.
+    #[builder(setter(strip_option), default)]
+    ignore_revs: Option<bool>,
+    /// Allow the read to be served by a follower in cluster or active-failover
+    /// setups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    allow_dirty_read: Option<bool>,
+    /// The selector, i.e. a document containing the `_key` and
+    /// optionally the `_rev` of the document to read
+    document: T,
+}
+
+impl<T> Config<T> {
+    fn build_suffix(&self, base: &str) -> String {
+        let mut url = format!("{}/{}", base, self.collection);
+        let mut has_qp = false;
+
+        add_qp(Some(true), &mut url, &mut has_qp, |_| OnlyGet);
+        add_qp(*self.ignore_revs(), &mut url, &mut has_qp, IgnoreRevs);
+
+        url
+    }
+
+    /// The selector wrapped in an array, as expected by the `onlyget` endpoint
+    pub(crate) fn documents(&self) -> [&T; 1] {
+        [&self.document]
+    }
+}
+
+impl<T> BuildUrl for Config<T> {
+    fn build_url(&self, base: &str, conn: &Connection) -> Result<Url> {
+        let suffix = &self.build_suffix(base);
+        conn.db_url()
+            .join(suffix)
+            .with_context(|| format!("Unable to build '{suffix}' url"))
+    }
+}
+
+impl<T> AddHeaders for Config<T> {
+    fn has_header(&self) -> bool {
+        self.allow_dirty_read.is_some()
+    }
+
+    fn add_headers(&self) -> Result<Option<HeaderMap>> {
+        let mut headers = None;
+        if let Some(allow_dirty_read) = self.allow_dirty_read() {
+            let mut headers_map = HeaderMap::new();
+            let _ = headers_map.append(
+                HeaderName::from_static(ALLOW_DIRTY_READ),
+                HeaderValue::from_static(if *allow_dirty_read { "true" } else { "false" }),
+            );
+            headers = Some(headers_map);
+        }
+        Ok(headers)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Config, ConfigBuilder};
+    use crate::model::{
+        doc::BASE_DOC_SUFFIX, IGNORE_REVS_FALSE_QP, ONLYGET_QP, TEST_COLL, TEST_KEY,
+    };
+    use anyhow::Result;
+    use const_format::concatcp;
+    use serde_json::json;
+
+    const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL, "?", ONLYGET_QP);
+    const IGNORE_REVS_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "&", IGNORE_REVS_FALSE_QP);
+
+    fn check_url<T>(config: &Config<T>, actual: &str) {
+        assert_eq!(actual, config.build_suffix(BASE_DOC_SUFFIX));
+    }
+
+    #[test]
+    fn read_by_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document(json!({ "_key": TEST_KEY }))
+            .build()?;
+        check_url(&config, BASIC_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn read_by_ignore_revs_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .document(json!({ "_key": TEST_KEY, "_rev": "_cIw-YT6---" }))
+            .ignore_revs(false)
+            .build()?;
+        check_url(&config, IGNORE_REVS_ACTUAL);
+        assert_eq!(
+            "[{\"_key\":\"test_key\",\"_rev\":\"_cIw-YT6---\"}]",
+            serde_json::to_string(&config.documents())?
+        );
+        Ok(())
+    }
+}
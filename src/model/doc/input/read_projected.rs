@@ -0,0 +1,110 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Document Read Projected Input Structs
+
+use crate::model::cursor::input::{CreateConfig, CreateConfigBuilder};
+use anyhow::Result;
+use derive_builder::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const NO_ATTRIBUTES_ERR: &str = "at least one attribute must be projected!";
+
+/// Document read projected configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Config {
+    /// The collection to read the document from
+    #[builder(setter(into))]
+    collection: String,
+    /// The document `_key`
+    #[builder(setter(into))]
+    key: String,
+    /// The top-level attributes to keep, i.e. `test`.  All other attributes,
+    /// including the system attributes, are left out of the result.
+    #[builder(setter(each(name = "attribute", into)))]
+    attributes: Vec<String>,
+}
+
+impl ConfigBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        if self.attributes.as_ref().map_or(true, Vec::is_empty) {
+            Err(NO_ATTRIBUTES_ERR.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Config {
+    fn query(&self) -> String {
+        let attributes = (0..self.attributes.len())
+            .map(|i| format!("@attr{i}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!("LET d = DOCUMENT(@id) RETURN d == null ? null : KEEP(d, {attributes})")
+    }
+
+    pub(crate) fn cursor_config(&self) -> Result<CreateConfig> {
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert(
+            "id".to_string(),
+            format!("{}/{}", self.collection, self.key),
+        );
+        for (i, attribute) in self.attributes.iter().enumerate() {
+            let _ = bind_vars.insert(format!("attr{i}"), attribute.clone());
+        }
+
+        Ok(CreateConfigBuilder::default()
+            .query(self.query())
+            .bind_vars(bind_vars)
+            .build()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConfigBuilder, NO_ATTRIBUTES_ERR};
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn read_projected_query() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_key")
+            .attribute("test")
+            .attribute("other")
+            .build()?;
+        assert_eq!(
+            "LET d = DOCUMENT(@id) RETURN d == null ? null : KEEP(d, @attr0, @attr1)",
+            config.query()
+        );
+        let cursor_config = config.cursor_config()?;
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(3, bind_vars.len());
+        assert_eq!(Some(&json!("test_coll/test_key")), bind_vars.get("id"));
+        assert_eq!(Some(&json!("other")), bind_vars.get("attr1"));
+        Ok(())
+    }
+
+    #[test]
+    fn no_attributes_errors() {
+        match ConfigBuilder::default()
+            .collection("test_coll")
+            .key("test_key")
+            .build()
+        {
+            Ok(_) => panic!("The builder should fail!"),
+            Err(e) => assert_eq!(NO_ATTRIBUTES_ERR, format!("{e}")),
+        }
+    }
+}
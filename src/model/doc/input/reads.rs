@@ -10,16 +10,21 @@
 
 use crate::{
     model::{
-        add_qp, BuildUrl,
+        add_qp, AddHeaders, BuildUrl,
         QueryParam::{IgnoreRevs, OnlyGet},
+        ALLOW_DIRTY_READ,
     },
     Connection,
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use derive_builder::Builder;
 use getset::Getters;
-use reqwest::Url;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Url,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Document reads configuration
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
@@ -34,6 +39,11 @@ pub struct Config<T> {
     /// Otherwise a precondition failed error is returned.
     #[builder(setter(strip_option), default)]
     ignore_revs: Option<bool>,
+    /// Allow the reads to be served by a follower in cluster or active-failover
+    /// setups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    allow_dirty_read: Option<bool>,
     /// The search documents to read
     documents: Vec<T>,
 }
@@ -50,6 +60,35 @@ impl<T> Config<T> {
     }
 }
 
+impl<T> Config<T>
+where
+    T: Serialize,
+{
+    /// The `_key` of each search document, in request order.  A search
+    /// document is either a key or `_id` string, or an object with a
+    /// `_key` or `_id`.
+    pub(crate) fn keys(&self) -> Result<Vec<String>> {
+        self.documents.iter().map(search_key).collect()
+    }
+}
+
+fn search_key<T>(document: &T) -> Result<String>
+where
+    T: Serialize,
+{
+    let value = serde_json::to_value(document)?;
+    let key = match &value {
+        Value::String(key) => Some(key.as_str()),
+        Value::Object(map) => map
+            .get("_key")
+            .or_else(|| map.get("_id"))
+            .and_then(Value::as_str),
+        _ => None,
+    };
+    key.map(|key| key.rsplit('/').next().unwrap_or(key).to_string())
+        .ok_or_else(|| anyhow!("search document '{value}' has no '_key' or '_id'"))
+}
+
 impl<T> BuildUrl for Config<T> {
     fn build_url(&self, base: &str, conn: &Connection) -> Result<Url> {
         let suffix = &self.build_suffix(base);
@@ -59,12 +98,34 @@ impl<T> BuildUrl for Config<T> {
     }
 }
 
+impl<T> AddHeaders for Config<T> {
+    fn has_header(&self) -> bool {
+        self.allow_dirty_read.is_some()
+    }
+
+    fn add_headers(&self) -> Result<Option<HeaderMap>> {
+        let mut headers = None;
+        if let Some(allow_dirty_read) = self.allow_dirty_read() {
+            let mut headers_map = HeaderMap::new();
+            let _ = headers_map.append(
+                HeaderName::from_static(ALLOW_DIRTY_READ),
+                HeaderValue::from_static(if *allow_dirty_read { "true" } else { "false" }),
+            );
+            headers = Some(headers_map);
+        }
+        Ok(headers)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Config, ConfigBuilder};
-    use crate::model::{doc::BASE_DOC_SUFFIX, IGNORE_REVS_QP, ONLYGET_QP, TEST_COLL, TEST_KEY};
+    use crate::model::{
+        doc::BASE_DOC_SUFFIX, AddHeaders, IGNORE_REVS_QP, ONLYGET_QP, TEST_COLL, TEST_KEY,
+    };
     use anyhow::Result;
     use const_format::concatcp;
+    use serde_json::json;
 
     const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL, "?", ONLYGET_QP);
     const IGNORE_REVS_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "&", IGNORE_REVS_QP);
@@ -93,4 +154,53 @@ mod test {
         check_url(&config, IGNORE_REVS_ACTUAL);
         Ok(())
     }
+
+    #[test]
+    fn has_allow_dirty_read_header() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![TEST_KEY])
+            .allow_dirty_read(true)
+            .build()?;
+        let headers_opt = config.add_headers()?;
+        assert!(headers_opt.is_some());
+        assert_eq!(headers_opt.unwrap().keys_len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn has_no_header() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![TEST_KEY])
+            .build()?;
+        let headers_opt = config.add_headers()?;
+        assert!(headers_opt.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn reads_keys() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![
+                json!("a"),
+                json!("test_coll/b"),
+                json!({ "_key": "c" }),
+                json!({ "_id": "test_coll/d" }),
+            ])
+            .build()?;
+        assert_eq!(vec!["a", "b", "c", "d"], config.keys()?);
+        Ok(())
+    }
+
+    #[test]
+    fn reads_keys_missing_key_errors() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec![json!({ "test": "test" })])
+            .build()?;
+        assert!(config.keys().is_err());
+        Ok(())
+    }
 }
@@ -12,7 +12,9 @@ use crate::{
     error::RuarangoErr::Unreachable,
     model::{
         add_qp, AddHeaders, BuildUrl,
-        QueryParam::{IgnoreRevs, ReturnNew, ReturnOld, Silent, WaitForSync},
+        QueryParam::{
+            IgnoreRevs, RefillIndexCaches as Refill, ReturnNew, ReturnOld, Silent, WaitForSync,
+        },
     },
     Connection,
 };
@@ -60,6 +62,10 @@ pub struct Config<T> {
     /// save some network traffic.
     #[builder(setter(strip_option), default)]
     silent: Option<bool>,
+    /// Refill the in-memory index caches affected by this operation, so that
+    /// they stay warm after the modification.
+    #[builder(setter(strip_option), default)]
+    refill_index_caches: Option<bool>,
     /// You can conditionally replace a document based on a target `rev` by
     /// using the `if_match` option
     #[builder(setter(into, strip_option), default)]
@@ -82,6 +88,8 @@ impl<T> Config<T> {
 
         add_qp(*self.ignore_revs(), &mut url, &mut has_qp, IgnoreRevs);
 
+        add_qp(*self.refill_index_caches(), &mut url, &mut has_qp, Refill);
+
         url
     }
 }
@@ -125,13 +133,14 @@ impl<T> AddHeaders for Config<T> {
 mod test {
     use super::{Config, ConfigBuilder};
     use crate::model::{
-        doc::BASE_DOC_SUFFIX, AddHeaders, RETURN_NEW_QP, RETURN_OLD_QP, SILENT_QP, TEST_COLL,
-        TEST_KEY, WAIT_FOR_SYNC_QP,
+        doc::BASE_DOC_SUFFIX, AddHeaders, REFILL_INDEX_CACHES_QP, RETURN_NEW_QP, RETURN_OLD_QP,
+        SILENT_QP, TEST_COLL, TEST_KEY, WAIT_FOR_SYNC_QP,
     };
     use anyhow::Result;
     use const_format::concatcp;
 
     const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL, "/", TEST_KEY);
+    const REFILL_INDEX_CACHES_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", REFILL_INDEX_CACHES_QP);
     const WAIT_FOR_SYNC_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", WAIT_FOR_SYNC_QP);
     const SILENT_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", SILENT_QP);
     const RETURN_NEW_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", RETURN_NEW_QP);
@@ -306,4 +315,22 @@ mod test {
         assert!(headers_opt.is_none());
         Ok(())
     }
+
+    #[test]
+    fn replace_refill_index_caches_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .key(TEST_KEY)
+            .document("test")
+            .build()?;
+        check_url(&config, BASIC_ACTUAL);
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .key(TEST_KEY)
+            .document("test")
+            .refill_index_caches(true)
+            .build()?;
+        check_url(&config, REFILL_INDEX_CACHES_ACTUAL);
+        Ok(())
+    }
 }
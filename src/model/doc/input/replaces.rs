@@ -10,7 +10,9 @@
 
 use crate::{
     model::{
-        add_qp, BuildUrl,
+        add_qp,
+        doc::input::check_keys,
+        BuildUrl,
         QueryParam::{IgnoreRevs, ReturnNew, ReturnOld, WaitForSync},
     },
     Connection,
@@ -22,6 +24,11 @@ use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 /// Document replace configuration
+///
+/// The server does not support an `overwriteMode` when replacing documents,
+/// as the documents must already exist.  For an upsert, use
+/// [`creates`](crate::Document::creates) with a
+/// [`Replace`](super::OverwriteMode::Replace) overwrite mode instead.
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub(crate)")]
 pub struct Config<T> {
@@ -48,6 +55,11 @@ pub struct Config<T> {
     /// in the result.
     #[builder(setter(strip_option), default)]
     return_old: Option<bool>,
+    /// Check that every document has a `_key` before sending the request,
+    /// returning a [`MissingKey`](crate::Error::MissingKey) error naming the
+    /// first document without one (default: false).
+    #[builder(setter(strip_option), default)]
+    validate_keys: Option<bool>,
 }
 
 impl<T> Config<T> {
@@ -62,6 +74,17 @@ impl<T> Config<T> {
 
         url
     }
+
+    pub(crate) fn check_keys(&self) -> Result<()>
+    where
+        T: Serialize,
+    {
+        if self.validate_keys.unwrap_or_default() {
+            check_keys(&self.documents)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<T> BuildUrl for Config<T> {
@@ -13,7 +13,8 @@ use crate::{
     model::{
         add_qp, AddHeaders, BuildUrl,
         QueryParam::{
-            IgnoreRevs, KeepNull, MergeObjects, ReturnNew, ReturnOld, Silent, WaitForSync,
+            IgnoreRevs, KeepNull, MergeObjects, RefillIndexCaches as Refill, ReturnNew, ReturnOld,
+            Silent, WaitForSync,
         },
     },
     Connection,
@@ -55,6 +56,10 @@ pub struct Config<T> {
     /// save some network traffic.
     #[builder(setter(strip_option), default)]
     silent: Option<bool>,
+    /// Refill the in-memory index caches affected by this operation, so that
+    /// they stay warm after the modification.
+    #[builder(setter(strip_option), default)]
+    refill_index_caches: Option<bool>,
     /// If the intention is to delete existing attributes with the update-insert
     /// command, `keep_null` can be used with a value of false.
     /// This will modify the behavior of `create` to remove any attributes from
@@ -101,6 +106,8 @@ impl<T> Config<T> {
         add_qp(*self.merge_objects(), &mut url, &mut has_qp, MergeObjects);
         add_qp(*self.ignore_revs(), &mut url, &mut has_qp, IgnoreRevs);
 
+        add_qp(*self.refill_index_caches(), &mut url, &mut has_qp, Refill);
+
         url
     }
 }
@@ -146,13 +153,14 @@ mod test {
     use super::{Config, ConfigBuilder};
     use crate::model::{
         doc::BASE_DOC_SUFFIX, AddHeaders, IGNORE_REVS_QP, KEEP_NULL_FALSE_QP, KEEP_NULL_QP,
-        MERGE_OBJECTS_QP, RETURN_NEW_QP, RETURN_OLD_QP, SILENT_QP, TEST_COLL, TEST_KEY,
-        WAIT_FOR_SYNC_QP,
+        MERGE_OBJECTS_FALSE_QP, MERGE_OBJECTS_QP, REFILL_INDEX_CACHES_QP, RETURN_NEW_QP,
+        RETURN_OLD_QP, SILENT_QP, TEST_COLL, TEST_KEY, WAIT_FOR_SYNC_QP,
     };
     use anyhow::Result;
     use const_format::concatcp;
 
     const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL, "/", TEST_KEY);
+    const REFILL_INDEX_CACHES_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", REFILL_INDEX_CACHES_QP);
     const WAIT_FOR_SYNC_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", WAIT_FOR_SYNC_QP);
     const SILENT_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", SILENT_QP);
     const RETURN_NEW_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", RETURN_NEW_QP);
@@ -160,6 +168,7 @@ mod test {
     const KEEP_NULL_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", KEEP_NULL_QP);
     const KEEP_NULL_FALSE_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", KEEP_NULL_FALSE_QP);
     const MERGE_OBJECTS_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", MERGE_OBJECTS_QP);
+    const MERGE_OBJECTS_FALSE_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", MERGE_OBJECTS_FALSE_QP);
     const IGNORE_REVS_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", IGNORE_REVS_QP);
     const WAIT_SILENT_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", WAIT_FOR_SYNC_QP, "&", SILENT_QP);
     const WAIT_RETURN_OLD_ACTUAL: &str =
@@ -308,6 +317,18 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn update_merge_objects_false_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .key(TEST_KEY)
+            .document("test)")
+            .merge_objects(false)
+            .build()?;
+        check_url(&config, MERGE_OBJECTS_FALSE_ACTUAL);
+        Ok(())
+    }
+
     #[test]
     fn update_ignore_revs_url() -> Result<()> {
         let config = ConfigBuilder::default()
@@ -407,4 +428,22 @@ mod test {
         assert_eq!(headers_opt.unwrap().keys_len(), 1);
         Ok(())
     }
+
+    #[test]
+    fn update_refill_index_caches_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .key(TEST_KEY)
+            .document("test")
+            .build()?;
+        check_url(&config, BASIC_ACTUAL);
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .key(TEST_KEY)
+            .document("test")
+            .refill_index_caches(true)
+            .build()?;
+        check_url(&config, REFILL_INDEX_CACHES_ACTUAL);
+        Ok(())
+    }
 }
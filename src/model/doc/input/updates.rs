@@ -10,8 +10,12 @@
 
 use crate::{
     model::{
-        add_qp, BuildUrl,
-        QueryParam::{IgnoreRevs, KeepNull, MergeObjects, ReturnNew, ReturnOld, WaitForSync},
+        add_qp,
+        doc::input::check_keys,
+        BuildUrl,
+        QueryParam::{
+            IgnoreRevs, KeepNull, MergeObjects, ReturnNew, ReturnOld, Silent, WaitForSync,
+        },
     },
     Connection,
 };
@@ -22,6 +26,11 @@ use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 /// Document updates configuration
+///
+/// The server does not support an `overwriteMode` when updating documents,
+/// as the documents must already exist.  For an upsert, use
+/// [`creates`](crate::Document::creates) with an
+/// [`Update`](super::OverwriteMode::Update) overwrite mode instead.
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub(crate)")]
 pub struct Config<T> {
@@ -41,6 +50,12 @@ pub struct Config<T> {
     /// in the result. Only available if the `overwrite` option is used.
     #[builder(setter(strip_option), default)]
     return_old: Option<bool>,
+    /// If set to true, an empty array will be returned as response, unless
+    /// some of the documents could not be updated, in which case only the
+    /// errors are returned.  No meta-data will be returned for the updated
+    /// documents.  This option overrides `return_new` and `return_old`.
+    #[builder(setter(strip_option), default)]
+    silent: Option<bool>,
     /// If the intention is to delete existing attributes with the update-insert
     /// command, `keep_null` can be used with a value of false.
     /// This will modify the behavior of `create` to remove any attributes from
@@ -63,6 +78,11 @@ pub struct Config<T> {
     /// is the one specified.
     #[builder(setter(strip_option), default)]
     ignore_revs: Option<bool>,
+    /// Check that every document has a `_key` before sending the request,
+    /// returning a [`MissingKey`](crate::Error::MissingKey) error naming the
+    /// first document without one (default: false).
+    #[builder(setter(strip_option), default)]
+    validate_keys: Option<bool>,
 }
 
 impl<T> Config<T> {
@@ -71,14 +91,31 @@ impl<T> Config<T> {
         let mut has_qp = false;
 
         add_qp(*self.wait_for_sync(), &mut url, &mut has_qp, WaitForSync);
-        add_qp(*self.return_new(), &mut url, &mut has_qp, ReturnNew);
-        add_qp(*self.return_old(), &mut url, &mut has_qp, ReturnOld);
+
+        if self.silent().is_some() {
+            add_qp(*self.silent(), &mut url, &mut has_qp, Silent);
+        } else {
+            add_qp(*self.return_new(), &mut url, &mut has_qp, ReturnNew);
+            add_qp(*self.return_old(), &mut url, &mut has_qp, ReturnOld);
+        }
+
         add_qp(*self.keep_null(), &mut url, &mut has_qp, KeepNull);
         add_qp(*self.merge_objects(), &mut url, &mut has_qp, MergeObjects);
         add_qp(*self.ignore_revs(), &mut url, &mut has_qp, IgnoreRevs);
 
         url
     }
+
+    pub(crate) fn check_keys(&self) -> Result<()>
+    where
+        T: Serialize,
+    {
+        if self.validate_keys.unwrap_or_default() {
+            check_keys(&self.documents)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<T> BuildUrl for Config<T> {
@@ -89,3 +126,78 @@ impl<T> BuildUrl for Config<T> {
             .with_context(|| format!("Unable to build '{suffix}' url"))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Config, ConfigBuilder};
+    use crate::model::{
+        doc::BASE_DOC_SUFFIX, KEEP_NULL_FALSE_QP, KEEP_NULL_QP, MERGE_OBJECTS_FALSE_QP,
+        MERGE_OBJECTS_QP, SILENT_QP, TEST_COLL,
+    };
+    use anyhow::Result;
+    use const_format::concatcp;
+
+    const BASIC_ACTUAL: &str = concatcp!(BASE_DOC_SUFFIX, "/", TEST_COLL);
+    const KEEP_NULL_MERGE_ACTUAL: &str =
+        concatcp!(BASIC_ACTUAL, "?", KEEP_NULL_QP, "&", MERGE_OBJECTS_QP);
+    const KEEP_NULL_MERGE_FALSE_ACTUAL: &str = concatcp!(
+        BASIC_ACTUAL,
+        "?",
+        KEEP_NULL_FALSE_QP,
+        "&",
+        MERGE_OBJECTS_FALSE_QP
+    );
+
+    const SILENT_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", SILENT_QP);
+
+    fn check_url<T>(config: &Config<T>, actual: &str) {
+        assert_eq!(actual, config.build_suffix(BASE_DOC_SUFFIX));
+    }
+
+    #[test]
+    fn updates_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec!["test"])
+            .build()?;
+        check_url(&config, BASIC_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn updates_keep_null_merge_objects_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec!["test"])
+            .keep_null(true)
+            .merge_objects(true)
+            .build()?;
+        check_url(&config, KEEP_NULL_MERGE_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn updates_keep_null_merge_objects_false_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec!["test"])
+            .keep_null(false)
+            .merge_objects(false)
+            .build()?;
+        check_url(&config, KEEP_NULL_MERGE_FALSE_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn updates_silent_forces_no_return_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .collection(TEST_COLL)
+            .documents(vec!["test"])
+            .silent(true)
+            .return_new(true)
+            .return_old(true)
+            .build()?;
+        check_url(&config, SILENT_ACTUAL);
+        Ok(())
+    }
+}
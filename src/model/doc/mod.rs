@@ -12,3 +12,259 @@ pub mod input;
 pub mod output;
 
 pub(crate) const BASE_DOC_SUFFIX: &str = "_api/document";
+pub(crate) const BASE_IMPORT_SUFFIX: &str = "_api/import";
+
+use crate::error::RuarangoErr::{self, InvalidDocumentHandle, InvalidDocumentKey};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A document `_key`
+///
+/// Any key-taking builder accepts a `DocumentKey` in place of a `&str`.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use ruarango::{doc::{DocumentKey, input::ReadConfigBuilder}};
+/// #
+/// # pub fn main() -> Result<()> {
+/// let key: DocumentKey = "abc".parse()?;
+/// let config = ReadConfigBuilder::default()
+///     .collection("test_coll")
+///     .key(key)
+///     .build()?;
+/// assert!("test_coll/abc".parse::<DocumentKey>().is_err());
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct DocumentKey(String);
+
+impl DocumentKey {
+    /// Get the key as a `&str`
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for DocumentKey {
+    type Err = RuarangoErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.contains('/') {
+            Err(InvalidDocumentKey { key: s.to_string() })
+        } else {
+            Ok(Self(s.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for DocumentKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<DocumentKey> for String {
+    fn from(key: DocumentKey) -> Self {
+        key.0
+    }
+}
+
+/// Build the document handle, i.e. the `_id`, of the document with the given
+/// `key` in `collection`, without reading the document
+///
+/// # Errors
+/// An [`InvalidDocumentHandle`](crate::Error::InvalidDocumentHandle) error is
+/// returned if either part is empty, or the key contains a `/`.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use ruarango::doc::handle;
+/// #
+/// # pub fn main() -> Result<()> {
+/// assert_eq!("test_coll/abc", handle("test_coll", "abc")?);
+/// assert!(handle("", "abc").is_err());
+/// #   Ok(())
+/// # }
+/// ```
+pub fn handle(collection: &str, key: &str) -> anyhow::Result<String> {
+    let handle: DocumentHandle = format!("{collection}/{key}").parse()?;
+    Ok(handle.into())
+}
+
+/// A document handle, i.e. an `_id` of the form `collection/key`
+///
+/// Any builder taking an `_id`, such as the `_from` and `_to` of an edge,
+/// accepts a `DocumentHandle` in place of a `&str`.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use ruarango::doc::DocumentHandle;
+/// #
+/// # pub fn main() -> Result<()> {
+/// let handle: DocumentHandle = "test_coll/abc".parse()?;
+/// assert_eq!(("test_coll", "abc"), handle.split());
+/// assert!("abc".parse::<DocumentHandle>().is_err());
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct DocumentHandle(String);
+
+impl DocumentHandle {
+    /// Create a handle from the given collection and key
+    #[must_use]
+    pub fn new(collection: &str, key: &DocumentKey) -> Self {
+        Self(format!("{collection}/{key}"))
+    }
+
+    /// Get the handle as a `&str`
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Split the handle into its collection and key
+    #[must_use]
+    pub fn split(&self) -> (&str, &str) {
+        self.0.split_once('/').unwrap_or(("", &self.0))
+    }
+}
+
+impl FromStr for DocumentHandle {
+    type Err = RuarangoErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((collection, key))
+                if !collection.is_empty() && !key.is_empty() && !key.contains('/') =>
+            {
+                Ok(Self(s.to_string()))
+            }
+            _ => Err(InvalidDocumentHandle {
+                handle: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for DocumentHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<DocumentHandle> for String {
+    fn from(handle: DocumentHandle) -> Self {
+        handle.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{handle, DocumentHandle, DocumentKey};
+    use crate::{
+        error::RuarangoErr::{InvalidDocumentHandle, InvalidDocumentKey},
+        graph::input::FromToBuilder,
+    };
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn key_parse_works() -> Result<()> {
+        let key: DocumentKey = "abc".parse()?;
+        assert_eq!("abc", key.as_str());
+        assert_eq!("abc", key.to_string());
+        assert_eq!(
+            Err(InvalidDocumentKey {
+                key: "coll/abc".to_string()
+            }),
+            "coll/abc".parse::<DocumentKey>()
+        );
+        assert!("".parse::<DocumentKey>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn handle_parse_works() -> Result<()> {
+        let handle: DocumentHandle = "coll/abc".parse()?;
+        assert_eq!("coll/abc", handle.as_str());
+        assert_eq!("coll/abc", handle.to_string());
+        for invalid in &["abc", "/abc", "coll/", "coll/abc/def", ""] {
+            assert_eq!(
+                Err(InvalidDocumentHandle {
+                    handle: (*invalid).to_string()
+                }),
+                invalid.parse::<DocumentHandle>()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn handle_split_works() -> Result<()> {
+        let handle: DocumentHandle = "coll/abc".parse()?;
+        assert_eq!(("coll", "abc"), handle.split());
+        let handle = DocumentHandle::new("coll", &"def".parse()?);
+        assert_eq!(("coll", "def"), handle.split());
+        Ok(())
+    }
+
+    #[test]
+    fn handle_works() -> Result<()> {
+        assert_eq!("v/1", handle("v", "1")?);
+        for (collection, key) in &[("", "1"), ("v", ""), ("v", "1/2")] {
+            assert!(handle(collection, key).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn edge_accepts_tuples() -> Result<()> {
+        let tuple = FromToBuilder::default()
+            .from_doc(("from_coll", "abc"))
+            .to_doc(("to_coll", "def"))
+            .build()?;
+        let string = FromToBuilder::default()
+            .from(handle("from_coll", "abc")?)
+            .to("to_coll/def")
+            .build()?;
+        assert_eq!(
+            serde_json::to_value(&string)?,
+            serde_json::to_value(&tuple)?
+        );
+        assert!(FromToBuilder::default()
+            .from_doc(("from_coll", ""))
+            .to_doc(("to_coll", "def"))
+            .build()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn serde_passthrough_works() -> Result<()> {
+        let handle: DocumentHandle = "coll/abc".parse()?;
+        assert_eq!(json!("coll/abc"), serde_json::to_value(&handle)?);
+        let key: DocumentKey = serde_json::from_value(json!("abc"))?;
+        assert_eq!("abc", key.as_str());
+        Ok(())
+    }
+
+    #[test]
+    fn edge_accepts_handles() -> Result<()> {
+        let from: DocumentHandle = "from_coll/abc".parse()?;
+        let to: DocumentHandle = "to_coll/def".parse()?;
+        let mapping = FromToBuilder::default().from(from).to(to).build()?;
+        assert_eq!(
+            json!({ "_from": "from_coll/abc", "_to": "to_coll/def" }),
+            serde_json::to_value(&mapping)?
+        );
+        Ok(())
+    }
+}
@@ -8,6 +8,9 @@
 
 //! Document Output Structs
 
+use crate::model::common::output::ArangoErr;
+use anyhow::Context;
+use bytes::Bytes;
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -30,7 +33,10 @@ pub struct DocMeta<N, O> {
     /// Contains the document revision
     #[serde(rename = "_rev")]
     rev: String,
-    /// Contains the old document revision, for some `overwrite`s
+    /// Contains the revision of the document before the write.  This is
+    /// returned for replaces and updates, and for creates that
+    /// [`overwrite`](crate::doc::input::OverwriteMode) an existing document.
+    /// It is `None` for a create of a new document and for deletes.
     #[serde(rename = "_oldRev", skip_serializing_if = "Option::is_none")]
     old_rev: Option<String>,
     /// Contains the new document, if `return_new` was enabled
@@ -42,6 +48,141 @@ pub struct DocMeta<N, O> {
     old_doc: Option<O>,
 }
 
+impl<N, O> DocMeta<N, O> {
+    /// Whether the write produced a new revision of the document, i.e. the
+    /// [`old_rev`](Self::old_rev) differs from the [`rev`](Self::rev).
+    /// This is always `true` if there is no `old_rev`.
+    pub fn changed(&self) -> bool {
+        self.old_rev.as_ref() != Some(&self.rev)
+    }
+}
+
+/// Document metadata output without the new or old document, see
+/// [`create_meta`](crate::Document::create_meta)
+#[derive(Clone, Debug, Deserialize, Eq, Getters, PartialEq, Serialize)]
+#[getset(get = "pub")]
+pub struct DocMetaOnly {
+    /// Contains the document key
+    #[serde(rename = "_key")]
+    key: String,
+    /// Contains the document identifier
+    #[serde(rename = "_id")]
+    id: String,
+    /// Contains the document revision
+    #[serde(rename = "_rev")]
+    rev: String,
+    /// Contains the revision of the document before the write, if an
+    /// existing document was overwritten
+    #[serde(rename = "_oldRev", skip_serializing_if = "Option::is_none")]
+    old_rev: Option<String>,
+}
+
+/// The retained body of a document read, see
+/// [`read_borrowed`](crate::Document::read_borrowed)
+///
+/// The document is deserialized from the retained buffer with
+/// [`doc`](RawDoc::doc), so output structs may borrow `&str` fields from it
+/// rather than allocating a `String` per field.  A `&str` can only be
+/// borrowed if the JSON string contains no escapes, so use `Cow<'a, str>`
+/// for fields that may contain them.
+#[derive(Clone, Debug)]
+pub struct RawDoc {
+    body: Bytes,
+}
+
+impl RawDoc {
+    pub(crate) fn new(body: Bytes) -> Self {
+        Self { body }
+    }
+
+    /// Deserialize the document, borrowing from the retained body
+    ///
+    /// # Errors
+    /// An error is returned if the body cannot be deserialized to `T`
+    pub fn doc<'a, T>(&'a self) -> anyhow::Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        serde_json::from_slice(&self.body).with_context(|| "Unable to deserialize the document")
+    }
+
+    /// The raw document body
+    #[must_use]
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+/// Document header output, i.e. the document metadata without the body
+#[derive(Clone, Debug, Deserialize, Eq, Getters, PartialEq, Serialize)]
+#[getset(get = "pub")]
+pub struct DocHeader {
+    /// Contains the document identifier
+    #[serde(rename = "_id")]
+    id: String,
+    /// Contains the document key
+    #[serde(rename = "_key")]
+    key: String,
+    /// Contains the document revision
+    #[serde(rename = "_rev")]
+    rev: String,
+}
+
+impl DocHeader {
+    pub(crate) fn new<T, U, V>(id: T, key: U, rev: V) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            key: key.into(),
+            rev: rev.into(),
+        }
+    }
+}
+
+/// Document import output
+#[derive(Clone, Debug, Deserialize, Eq, Getters, PartialEq, Serialize)]
+#[getset(get = "pub")]
+pub struct ImportResult {
+    /// Is this an error?
+    #[serde(default)]
+    error: bool,
+    /// The number of documents imported
+    created: usize,
+    /// The number of documents that were not imported due to an error
+    errors: usize,
+    /// The number of empty lines found in the input
+    empty: usize,
+    /// The number of updated or replaced documents, if `on_duplicate` was
+    /// set to `update` or `replace`
+    updated: usize,
+    /// The number of failed but ignored insert operations, if `on_duplicate`
+    /// was set to `ignore`
+    ignored: usize,
+    /// Information about the documents that could not be imported, if `details`
+    /// was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+impl Default for ImportResult {
+    fn default() -> Self {
+        Self {
+            error: false,
+            created: 2,
+            errors: 0,
+            empty: 0,
+            updated: 0,
+            ignored: 0,
+            details: None,
+        }
+    }
+}
+
 #[cfg(test)]
 impl Default for DocMeta<(), ()> {
     fn default() -> Self {
@@ -202,6 +343,20 @@ pub struct DocErr {
     rev: Option<String>,
 }
 
+impl DocErr {
+    pub(crate) fn from_arango_err(code: u16, err: &ArangoErr) -> Self {
+        Self {
+            error: *err.error(),
+            code,
+            error_num: *err.error_num(),
+            error_message: Some(err.error_message().clone()),
+            key: None,
+            id: None,
+            rev: None,
+        }
+    }
+}
+
 impl fmt::Display for DocErr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "error: {}", self.error)?;
@@ -222,3 +377,108 @@ impl fmt::Display for DocErr {
         Ok(())
     }
 }
+
+impl std::error::Error for DocErr {}
+
+#[cfg(test)]
+mod test {
+    use super::{DocMeta, RawDoc};
+    use crate::model::cursor::output::CursorMeta;
+    use anyhow::Result;
+    use bytes::Bytes;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn changed_works() -> Result<()> {
+        let json = r#"{"_key":"abc","_id":"test_coll/abc","_rev":"_b","_oldRev":"_a"}"#;
+        let meta: DocMeta<(), ()> = serde_json::from_str(json)?;
+        assert_eq!(Some("_a"), meta.old_rev().as_deref());
+        assert!(meta.changed());
+
+        let json = r#"{"_key":"abc","_id":"test_coll/abc","_rev":"_a","_oldRev":"_a"}"#;
+        let meta: DocMeta<(), ()> = serde_json::from_str(json)?;
+        assert!(!meta.changed());
+
+        let json = r#"{"_key":"abc","_id":"test_coll/abc","_rev":"_a"}"#;
+        let meta: DocMeta<(), ()> = serde_json::from_str(json)?;
+        assert!(meta.old_rev().is_none());
+        assert!(meta.changed());
+        Ok(())
+    }
+
+    const ROWS: usize = 1000;
+    const ITERATIONS: u32 = 20;
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Owned {
+        #[serde(rename = "_key")]
+        key: String,
+        name: String,
+        city: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Borrowed<'a> {
+        #[serde(rename = "_key")]
+        key: &'a str,
+        name: &'a str,
+        city: &'a str,
+    }
+
+    fn cursor_body() -> Result<RawDoc> {
+        let result: Vec<_> = (0..ROWS)
+            .map(|i| json!({ "_key": format!("{i}"), "name": "Yoda", "city": "Dagobah" }))
+            .collect();
+        let body = json!({
+            "result": result,
+            "hasMore": false,
+            "cached": false,
+            "code": 201,
+            "error": false
+        });
+        Ok(RawDoc::new(Bytes::from(serde_json::to_vec(&body)?)))
+    }
+
+    fn time<F>(f: F) -> Result<Duration>
+    where
+        F: Fn() -> Result<usize>,
+    {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            assert_eq!(ROWS, f()?);
+        }
+        Ok(start.elapsed() / ITERATIONS)
+    }
+
+    #[test]
+    fn null_new_doc_deserializes() -> Result<()> {
+        let meta: DocMeta<Owned, ()> = serde_json::from_value(json!({
+            "_key": "abc",
+            "_id": "test_coll/abc",
+            "_rev": "_cIw-YT6---",
+            "new": null
+        }))?;
+        assert!(meta.new_doc().is_none());
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "timing only, run with `cargo test -- --ignored --nocapture`"]
+    fn owned_vs_borrowed_deserialize() -> Result<()> {
+        let raw = cursor_body()?;
+        let owned = time(|| {
+            let meta: CursorMeta<Owned> = raw.doc()?;
+            Ok(meta.result().as_ref().map_or(0, Vec::len))
+        })?;
+        let borrowed = time(|| {
+            let meta: CursorMeta<Borrowed<'_>> = raw.doc()?;
+            Ok(meta.result().as_ref().map_or(0, Vec::len))
+        })?;
+        println!("{ROWS} rows: owned {owned:?}, borrowed {borrowed:?}");
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@
 //! Graph Create Input Structs
 
 use crate::{
+    db::ReplicationFactor,
     model::{add_qp, graph::EdgeDefinition, BuildUrl, QueryParam::WaitForSync},
     Connection,
 };
@@ -66,8 +67,22 @@ pub struct GraphMeta {
     /// An array of additional vertex collections.
     /// Documents within these collections do not have edges within this graph.
     #[builder(setter(strip_option), default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "orphanCollections", skip_serializing_if = "Option::is_none")]
     orphan_collections: Option<Vec<String>>,
+    /// Define if the created graph should be smart.
+    /// This only has effect in the Enterprise Edition, and is ignored on Community.
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "isSmart", skip_serializing_if = "Option::is_none")]
+    is_smart: Option<bool>,
+    /// Whether to create a Disjoint SmartGraph instead of a regular SmartGraph.
+    /// This only has effect in the Enterprise Edition, and is ignored on Community.
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "isDisjoint", skip_serializing_if = "Option::is_none")]
+    is_disjoint: Option<bool>,
+    /// Additional options for creating the graph in a cluster
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<GraphOptions>,
 }
 
 impl GraphMetaBuilder {
@@ -82,9 +97,38 @@ impl GraphMetaBuilder {
     }
 }
 
+/// Graph creation options, only used in a cluster
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct GraphOptions {
+    /// The attribute name that is used to smartly shard the vertices of a graph.
+    /// Required when creating a SmartGraph.
+    /// This only has effect in the Enterprise Edition, and is ignored on Community.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(
+        rename = "smartGraphAttribute",
+        skip_serializing_if = "Option::is_none"
+    )]
+    smart_graph_attribute: Option<String>,
+    /// The number of shards that is used for every collection within this graph.
+    /// Cannot be modified later.
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "numberOfShards", skip_serializing_if = "Option::is_none")]
+    number_of_shards: Option<usize>,
+    /// The replication factor used when initially creating collections for this graph.
+    /// Can be set to [`Satellite`](ReplicationFactor::Satellite) to create a
+    /// SatelliteGraph, which then ignores `number_of_shards`, `write_concern`
+    /// and `replication_factor`.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(rename = "replicationFactor", skip_serializing_if = "Option::is_none")]
+    replication_factor: Option<ReplicationFactor>,
+}
+
 #[cfg(test)]
 mod test {
-    use super::{Config, ConfigBuilder, GraphMetaBuilder, EMPTY_EDGE_DEFINITIONS_ERR};
+    use super::{
+        Config, ConfigBuilder, GraphMetaBuilder, GraphOptionsBuilder, EMPTY_EDGE_DEFINITIONS_ERR,
+    };
     use crate::{
         graph::BASE_GRAPH_SUFFIX,
         model::{
@@ -94,6 +138,7 @@ mod test {
     };
     use anyhow::Result;
     use const_format::concatcp;
+    use serde_json::json;
 
     const BASIC_ACTUAL: &str = concatcp!(BASE_GRAPH_SUFFIX);
     const WAIT_FOR_SYNC_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", WAIT_FOR_SYNC_QP);
@@ -151,4 +196,41 @@ mod test {
             Err(e) => assert_eq!(EMPTY_EDGE_DEFINITIONS_ERR, format!("{e}")),
         }
     }
+
+    #[test]
+    fn create_options_serialize() -> Result<()> {
+        let options = GraphOptionsBuilder::default()
+            .smart_graph_attribute("region")
+            .number_of_shards(3)
+            .replication_factor(2)
+            .build()?;
+        let graph_meta = GraphMetaBuilder::default()
+            .name("test")
+            .edge_definitions(edge_definition()?)
+            .orphan_collections(ve(vec!["orphan"]))
+            .is_smart(true)
+            .is_disjoint(false)
+            .options(options)
+            .build()?;
+        assert_eq!(
+            json!({
+                "name": "test",
+                "edgeDefinitions": [{
+                    "collection": "test_edge",
+                    "to": ["test_coll"],
+                    "from": ["test_coll"]
+                }],
+                "orphanCollections": ["orphan"],
+                "isSmart": true,
+                "isDisjoint": false,
+                "options": {
+                    "smartGraphAttribute": "region",
+                    "numberOfShards": 3,
+                    "replicationFactor": 2
+                }
+            }),
+            serde_json::to_value(&graph_meta)?
+        );
+        Ok(())
+    }
 }
@@ -56,3 +56,45 @@ impl BuildUrl for Config {
             .with_context(|| format!("Unable to build '{suffix}' url"))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Config, ConfigBuilder};
+    use crate::{
+        graph::BASE_GRAPH_SUFFIX,
+        model::{DROP_COLLECTIONS_FALSE_QP, DROP_COLLECTIONS_QP},
+    };
+    use anyhow::Result;
+    use const_format::concatcp;
+
+    const BASIC_ACTUAL: &str = concatcp!(BASE_GRAPH_SUFFIX, "/test");
+    const DROP_COLLECTIONS_ACTUAL: &str = concatcp!(BASIC_ACTUAL, "?", DROP_COLLECTIONS_QP);
+    const DROP_COLLECTIONS_FALSE_ACTUAL: &str =
+        concatcp!(BASIC_ACTUAL, "?", DROP_COLLECTIONS_FALSE_QP);
+
+    fn check_url(config: &Config, actual: &str) {
+        assert_eq!(actual, config.build_suffix(BASE_GRAPH_SUFFIX));
+    }
+
+    #[test]
+    fn delete_url() -> Result<()> {
+        let config = ConfigBuilder::default().name("test").build()?;
+        check_url(&config, BASIC_ACTUAL);
+        Ok(())
+    }
+
+    #[test]
+    fn delete_drop_collections_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .name("test")
+            .drop_collections(true)
+            .build()?;
+        check_url(&config, DROP_COLLECTIONS_ACTUAL);
+        let config = ConfigBuilder::default()
+            .name("test")
+            .drop_collections(false)
+            .build()?;
+        check_url(&config, DROP_COLLECTIONS_FALSE_ACTUAL);
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@
 //! Edge Create Input Structs
 
 use crate::{
+    doc::DocumentHandle,
     model::{
         add_qp, BuildUrl,
         QueryParam::{ReturnNew, WaitForSync},
@@ -66,6 +67,7 @@ impl BuildUrl for Config {
 /// The from/to mapping for an edge
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct FromTo {
     /// The from document in a vertex collection
     #[builder(setter(into))]
@@ -76,3 +78,34 @@ pub struct FromTo {
     #[serde(rename = "_to")]
     to: String,
 }
+
+impl FromToBuilder {
+    /// Set the from document by its collection and key, see
+    /// [`handle`](crate::doc::handle)
+    pub fn from_doc<C, K>(&mut self, (collection, key): (C, K)) -> &mut Self
+    where
+        C: AsRef<str>,
+        K: AsRef<str>,
+    {
+        self.from = Some(format!("{}/{}", collection.as_ref(), key.as_ref()));
+        self
+    }
+
+    /// Set the to document by its collection and key, see
+    /// [`handle`](crate::doc::handle)
+    pub fn to_doc<C, K>(&mut self, (collection, key): (C, K)) -> &mut Self
+    where
+        C: AsRef<str>,
+        K: AsRef<str>,
+    {
+        self.to = Some(format!("{}/{}", collection.as_ref(), key.as_ref()));
+        self
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        for handle in self.from.iter().chain(self.to.iter()) {
+            let _handle: DocumentHandle = handle.parse().map_err(|e| format!("{e}"))?;
+        }
+        Ok(())
+    }
+}
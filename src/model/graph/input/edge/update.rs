@@ -115,3 +115,42 @@ impl<T> AddHeaders for Config<T> {
         Ok(headers)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ConfigBuilder;
+    use crate::model::{
+        graph::BASE_GRAPH_SUFFIX, KEEP_NULL_FALSE_QP, RETURN_NEW_QP, RETURN_OLD_QP,
+        WAIT_FOR_SYNC_QP,
+    };
+    use anyhow::Result;
+    use const_format::concatcp;
+
+    const ALL_ACTUAL: &str = concatcp!(
+        BASE_GRAPH_SUFFIX,
+        "/test_graph/edge/test_coll/test_key?",
+        WAIT_FOR_SYNC_QP,
+        "&",
+        KEEP_NULL_FALSE_QP,
+        "&",
+        RETURN_OLD_QP,
+        "&",
+        RETURN_NEW_QP
+    );
+
+    #[test]
+    fn update_edge_all_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .graph("test_graph")
+            .collection("test_coll")
+            .key("test_key")
+            .wait_for_sync(true)
+            .keep_null(false)
+            .return_old(true)
+            .return_new(true)
+            .edge("test")
+            .build()?;
+        assert_eq!(ALL_ACTUAL, config.build_suffix(BASE_GRAPH_SUFFIX));
+        Ok(())
+    }
+}
@@ -9,9 +9,10 @@
 //! Graph Replace Edge Def Input Structs
 
 use crate::{
+    error::RuarangoErr::Unreachable,
     graph::EdgeDefinition,
     model::{
-        add_qp, BuildUrl,
+        add_qp, AddHeaders, BuildUrl,
         QueryParam::{DropCollections, WaitForSync},
     },
     Connection,
@@ -19,7 +20,10 @@ use crate::{
 use anyhow::{Context, Result};
 use derive_builder::Builder;
 use getset::Getters;
-use reqwest::Url;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Url,
+};
 use serde::{Deserialize, Serialize};
 
 /// Graph replace edge def configuration
@@ -39,6 +43,10 @@ pub struct Config {
     /// Collection will only be dropped if it is not used in other graphs.
     #[builder(setter(strip_option), default)]
     drop_collections: Option<bool>,
+    /// The edge definition will only be replaced if the graph has a revision
+    /// matching the revision given here, see [`rev`](crate::graph::output::Graph::rev)
+    #[builder(setter(strip_option, into), default)]
+    if_match: Option<String>,
 }
 
 impl Config {
@@ -71,3 +79,74 @@ impl BuildUrl for Config {
             .with_context(|| format!("Unable to build '{suffix}' url"))
     }
 }
+
+impl AddHeaders for Config {
+    fn has_header(&self) -> bool {
+        self.if_match.is_some()
+    }
+
+    fn add_headers(&self) -> Result<Option<HeaderMap>> {
+        let mut headers = None;
+
+        if self.has_header() {
+            let mut headers_map = HeaderMap::new();
+            if let Some(rev) = self.if_match() {
+                let _ = headers_map.append(
+                    HeaderName::from_static("if-match"),
+                    HeaderValue::from_str(rev)?,
+                );
+                headers = Some(headers_map);
+            } else {
+                return Err(Unreachable {
+                    msg: "'if_match' should be true!".to_string(),
+                }
+                .into());
+            }
+        }
+        Ok(headers)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConfigBuilder;
+    use crate::{graph::EdgeDefinitionBuilder, model::AddHeaders};
+    use anyhow::{anyhow, Result};
+
+    #[test]
+    fn no_if_match_header() -> Result<()> {
+        let edge_def = EdgeDefinitionBuilder::default()
+            .collection("edges")
+            .from(vec!["from".to_string()])
+            .to(vec!["to".to_string()])
+            .build()?;
+        let config = ConfigBuilder::default()
+            .graph("test")
+            .edge_def(edge_def)
+            .build()?;
+        assert!(config.add_headers()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn if_match_header() -> Result<()> {
+        let edge_def = EdgeDefinitionBuilder::default()
+            .collection("edges")
+            .from(vec!["from".to_string()])
+            .to(vec!["to".to_string()])
+            .build()?;
+        let config = ConfigBuilder::default()
+            .graph("test")
+            .edge_def(edge_def)
+            .if_match("_abc")
+            .build()?;
+        let headers = config
+            .add_headers()?
+            .ok_or_else(|| anyhow!("headers should be set"))?;
+        assert_eq!(
+            Some("_abc"),
+            headers.get("if-match").map(|v| v.to_str()).transpose()?
+        );
+        Ok(())
+    }
+}
@@ -13,13 +13,15 @@ mod delete;
 mod edge;
 mod edge_def;
 mod read;
+mod shortest_path;
+mod traversal;
 mod vertex;
 mod vertex_coll;
 
 pub use create::{
     Config as CreateConfig, ConfigBuilder as CreateConfigBuilder,
     ConfigBuilderError as CreateConfigBuilderError, GraphMeta, GraphMetaBuilder,
-    GraphMetaBuilderError,
+    GraphMetaBuilderError, GraphOptions, GraphOptionsBuilder, GraphOptionsBuilderError,
 };
 pub use delete::{
     Config as DeleteConfig, ConfigBuilder as DeleteConfigBuilder,
@@ -65,6 +67,14 @@ pub use read::{
     Config as ReadConfig, ConfigBuilder as ReadConfigBuilder,
     ConfigBuilderError as ReadConfigBuilderError,
 };
+pub use shortest_path::{
+    Config as ShortestPathConfig, ConfigBuilder as ShortestPathConfigBuilder,
+    ConfigBuilderError as ShortestPathConfigBuilderError,
+};
+pub use traversal::{
+    Config as TraversalConfig, ConfigBuilder as TraversalConfigBuilder,
+    ConfigBuilderError as TraversalConfigBuilderError,
+};
 pub use vertex::create::{
     Config as CreateVertexConfig, ConfigBuilder as CreateVertexConfigBuilder,
     ConfigBuilderError as CreateVertexConfigBuilderError,
@@ -0,0 +1,168 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Graph Shortest Path Input Structs
+
+use crate::model::{
+    cursor::input::{CreateConfig, CreateConfigBuilder},
+    graph::Direction,
+};
+use anyhow::Result;
+use derive_builder::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const NON_FINITE_WEIGHT_ERR: &str = "default_weight must be a finite number!";
+
+/// Graph shortest path configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Config {
+    /// The name of the graph to search
+    #[builder(setter(into))]
+    graph: String,
+    /// The `_id` of the vertex the path starts at
+    #[builder(setter(into))]
+    start: String,
+    /// The `_id` of the vertex the path ends at
+    #[builder(setter(into))]
+    target: String,
+    /// The direction to follow edges in.  The default is
+    /// [`Outbound`](Direction::Outbound).
+    #[builder(default)]
+    direction: Direction,
+    /// The edge attribute holding the weight of the edge.  If not set, every
+    /// edge has a weight of 1.
+    #[builder(setter(into, strip_option), default)]
+    weight_attribute: Option<String>,
+    /// The weight used for edges without a `weight_attribute`.  The server
+    /// default is 1.  The weight must be finite.
+    #[builder(setter(strip_option), default)]
+    default_weight: Option<f64>,
+}
+
+impl ConfigBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        match self.default_weight {
+            Some(Some(default_weight)) if !default_weight.is_finite() => {
+                Err(NON_FINITE_WEIGHT_ERR.into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Config {
+    fn query(&self) -> String {
+        let mut query = format!(
+            "FOR v, e IN {} SHORTEST_PATH @start TO @target GRAPH @graph",
+            self.direction
+        );
+        let mut options = vec![];
+
+        if self.weight_attribute.is_some() {
+            options.push("weightAttribute: @weight_attribute".to_string());
+        }
+        if self.default_weight.is_some() {
+            options.push("defaultWeight: @default_weight".to_string());
+        }
+        if !options.is_empty() {
+            query.push_str(&format!(" OPTIONS {{ {} }}", options.join(", ")));
+        }
+        query.push_str(" RETURN { v, e }");
+        query
+    }
+
+    pub(crate) fn cursor_config(&self) -> Result<CreateConfig> {
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert("start".to_string(), Value::from(self.start.clone()));
+        let _ = bind_vars.insert("target".to_string(), Value::from(self.target.clone()));
+        let _ = bind_vars.insert("graph".to_string(), Value::from(self.graph.clone()));
+
+        if let Some(weight_attribute) = self.weight_attribute() {
+            let _ = bind_vars.insert(
+                "weight_attribute".to_string(),
+                Value::from(weight_attribute.clone()),
+            );
+        }
+        if let Some(default_weight) = self.default_weight {
+            let _ = bind_vars.insert("default_weight".to_string(), Value::from(default_weight));
+        }
+
+        Ok(CreateConfigBuilder::default()
+            .query(self.query())
+            .bind_vars(bind_vars)
+            .build()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConfigBuilder, NON_FINITE_WEIGHT_ERR};
+    use crate::model::graph::Direction;
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn shortest_path_query() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .graph("test_graph")
+            .start("test_coll/a")
+            .target("test_coll/c")
+            .build()?;
+        assert_eq!(
+            "FOR v, e IN OUTBOUND SHORTEST_PATH @start TO @target GRAPH @graph RETURN { v, e }",
+            config.query()
+        );
+        let cursor_config = config.cursor_config()?;
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(3, bind_vars.len());
+        assert_eq!(Some(&json!("test_coll/c")), bind_vars.get("target"));
+        Ok(())
+    }
+
+    #[test]
+    fn shortest_path_weighted_query() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .graph("test_graph")
+            .start("test_coll/a")
+            .target("test_coll/c")
+            .direction(Direction::Any)
+            .weight_attribute("distance")
+            .default_weight(2.5)
+            .build()?;
+        assert_eq!(
+            "FOR v, e IN ANY SHORTEST_PATH @start TO @target GRAPH @graph OPTIONS { weightAttribute: @weight_attribute, defaultWeight: @default_weight } RETURN { v, e }",
+            config.query()
+        );
+        let cursor_config = config.cursor_config()?;
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(Some(&json!("distance")), bind_vars.get("weight_attribute"));
+        assert_eq!(Some(&json!(2.5)), bind_vars.get("default_weight"));
+        Ok(())
+    }
+
+    #[test]
+    fn non_finite_default_weight_errors() {
+        for default_weight in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            match ConfigBuilder::default()
+                .graph("test_graph")
+                .start("test_coll/a")
+                .target("test_coll/c")
+                .default_weight(*default_weight)
+                .build()
+            {
+                Ok(_) => panic!("The builder should fail!"),
+                Err(e) => assert_eq!(NON_FINITE_WEIGHT_ERR, format!("{e}")),
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Graph Traversal Input Structs
+
+use crate::model::{
+    cursor::input::{CreateConfig, CreateConfigBuilder},
+    graph::Direction,
+};
+use anyhow::Result;
+use derive_builder::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const MIN_GT_MAX_ERR: &str = "min_depth cannot be greater than max_depth!";
+const RESERVED_BIND_VAR_ERR: &str = "bind_vars cannot contain 'start' or 'graph'!";
+const RESERVED_BIND_VARS: [&str; 2] = ["start", "graph"];
+
+/// Graph traversal configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Config {
+    /// The name of the graph to traverse
+    #[builder(setter(into))]
+    graph: String,
+    /// The `_id` of the vertex to start the traversal at
+    #[builder(setter(into))]
+    start_vertex: String,
+    /// The direction to follow edges in.  The default is
+    /// [`Outbound`](Direction::Outbound).
+    #[builder(default)]
+    direction: Direction,
+    /// The minimum depth of the returned paths.  The default is 1.
+    #[builder(default = "1")]
+    min_depth: usize,
+    /// The maximum depth of the returned paths.  The default is 1.
+    #[builder(default = "1")]
+    max_depth: usize,
+    /// An AQL condition on the current vertex `v`, i.e. `v.active == @active`.
+    /// Paths whose vertex does not match are not returned.
+    #[builder(setter(into, strip_option), default)]
+    vertex_filter: Option<String>,
+    /// An AQL condition on the current edge `e`, i.e. `e.weight < @max`.
+    /// Paths whose edge does not match are not returned.
+    #[builder(setter(into, strip_option), default)]
+    edge_filter: Option<String>,
+    /// The bind parameters used in the filters, without the leading `@`.
+    /// `start` and `graph` are used by the traversal itself.
+    #[builder(setter(strip_option), default)]
+    bind_vars: Option<HashMap<String, Value>>,
+}
+
+impl ConfigBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        let min_depth = self.min_depth.unwrap_or(1);
+        let max_depth = self.max_depth.unwrap_or(1);
+
+        let reserved =
+            self.bind_vars
+                .as_ref()
+                .and_then(Option::as_ref)
+                .map_or(false, |bind_vars| {
+                    RESERVED_BIND_VARS
+                        .iter()
+                        .any(|name| bind_vars.contains_key(*name))
+                });
+
+        if min_depth > max_depth {
+            Err(MIN_GT_MAX_ERR.into())
+        } else if reserved {
+            Err(RESERVED_BIND_VAR_ERR.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Config {
+    fn query(&self) -> String {
+        let mut query = format!(
+            "FOR v, e, p IN {}..{} {} @start GRAPH @graph",
+            self.min_depth, self.max_depth, self.direction
+        );
+
+        if let Some(vertex_filter) = self.vertex_filter() {
+            query.push_str(" FILTER ");
+            query.push_str(vertex_filter);
+        }
+        if let Some(edge_filter) = self.edge_filter() {
+            query.push_str(" FILTER ");
+            query.push_str(edge_filter);
+        }
+        query.push_str(" RETURN p");
+        query
+    }
+
+    pub(crate) fn cursor_config(&self) -> Result<CreateConfig> {
+        let mut bind_vars = self.bind_vars.clone().unwrap_or_default();
+        let _ = bind_vars.insert(
+            "start".to_string(),
+            Value::String(self.start_vertex.clone()),
+        );
+        let _ = bind_vars.insert("graph".to_string(), Value::String(self.graph.clone()));
+
+        Ok(CreateConfigBuilder::default()
+            .query(self.query())
+            .bind_vars(bind_vars)
+            .build()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConfigBuilder, MIN_GT_MAX_ERR, RESERVED_BIND_VAR_ERR};
+    use crate::model::graph::Direction;
+    use anyhow::Result;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn traversal_query() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .graph("test_graph")
+            .start_vertex("test_coll/test_key")
+            .build()?;
+        assert_eq!(
+            "FOR v, e, p IN 1..1 OUTBOUND @start GRAPH @graph RETURN p",
+            config.query()
+        );
+        let cursor_config = config.cursor_config()?;
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(Some(&json!("test_coll/test_key")), bind_vars.get("start"));
+        assert_eq!(Some(&json!("test_graph")), bind_vars.get("graph"));
+        Ok(())
+    }
+
+    #[test]
+    fn traversal_filters_query() -> Result<()> {
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert("val".to_string(), json!("test"));
+        let _ = bind_vars.insert("max".to_string(), json!(10));
+        let config = ConfigBuilder::default()
+            .graph("test_graph")
+            .start_vertex("test_coll/test_key")
+            .direction(Direction::Any)
+            .min_depth(0)
+            .max_depth(3)
+            .vertex_filter("v.test == @val")
+            .edge_filter("e.weight < @max")
+            .bind_vars(bind_vars)
+            .build()?;
+        assert_eq!(
+            "FOR v, e, p IN 0..3 ANY @start GRAPH @graph FILTER v.test == @val FILTER e.weight < @max RETURN p",
+            config.query()
+        );
+        let cursor_config = config.cursor_config()?;
+        let bind_vars = cursor_config.bind_vars().as_ref().unwrap();
+        assert_eq!(4, bind_vars.len());
+        assert_eq!(Some(&json!("test")), bind_vars.get("val"));
+        assert_eq!(Some(&json!(10)), bind_vars.get("max"));
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_bind_var_errors() {
+        for name in &["start", "graph"] {
+            let mut bind_vars = HashMap::new();
+            let _ = bind_vars.insert(name.to_string(), json!("x"));
+            match ConfigBuilder::default()
+                .graph("test_graph")
+                .start_vertex("test_coll/test_key")
+                .bind_vars(bind_vars)
+                .build()
+            {
+                Ok(_) => panic!("The builder should fail!"),
+                Err(e) => assert_eq!(RESERVED_BIND_VAR_ERR, format!("{e}")),
+            }
+        }
+    }
+
+    #[test]
+    fn min_gt_max_errors() {
+        match ConfigBuilder::default()
+            .graph("test_graph")
+            .start_vertex("test_coll/test_key")
+            .min_depth(2)
+            .build()
+        {
+            Ok(_) => panic!("The builder should fail!"),
+            Err(e) => assert_eq!(MIN_GT_MAX_ERR, format!("{e}")),
+        }
+    }
+}
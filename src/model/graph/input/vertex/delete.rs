@@ -12,7 +12,7 @@ use crate::{
     error::RuarangoErr::Unreachable,
     model::{
         add_qp, AddHeaders, BuildUrl,
-        QueryParam::{ReturnNew, WaitForSync},
+        QueryParam::{ReturnOld, WaitForSync},
     },
     Connection,
 };
@@ -61,7 +61,7 @@ impl Config {
         let mut has_qp = false;
 
         add_qp(*self.wait_for_sync(), &mut url, &mut has_qp, WaitForSync);
-        add_qp(*self.return_old(), &mut url, &mut has_qp, ReturnNew);
+        add_qp(*self.return_old(), &mut url, &mut has_qp, ReturnOld);
 
         url
     }
@@ -102,3 +102,32 @@ impl AddHeaders for Config {
         Ok(headers)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ConfigBuilder;
+    use crate::model::{graph::BASE_GRAPH_SUFFIX, RETURN_OLD_QP, WAIT_FOR_SYNC_QP};
+    use anyhow::Result;
+    use const_format::concatcp;
+
+    const ALL_ACTUAL: &str = concatcp!(
+        BASE_GRAPH_SUFFIX,
+        "/test_graph/vertex/test_coll/test_key?",
+        WAIT_FOR_SYNC_QP,
+        "&",
+        RETURN_OLD_QP
+    );
+
+    #[test]
+    fn delete_vertex_return_old_url() -> Result<()> {
+        let config = ConfigBuilder::default()
+            .name("test_graph")
+            .collection("test_coll")
+            .key("test_key")
+            .wait_for_sync(true)
+            .return_old(true)
+            .build()?;
+        assert_eq!(ALL_ACTUAL, config.build_suffix(BASE_GRAPH_SUFFIX));
+        Ok(())
+    }
+}
@@ -12,25 +12,111 @@ pub mod input;
 pub mod output;
 
 pub(crate) const BASE_GRAPH_SUFFIX: &str = "_api/gharial";
+const EMPTY_FROM_ERR: &str = "from cannot be empty!";
+const EMPTY_TO_ERR: &str = "to cannot be empty!";
 
 use derive_builder::Builder;
 use getset::Getters;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Edge Definition Data
 #[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
 #[getset(get = "pub")]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EdgeDefinition {
     /// Name of the edge collection, where the edge are stored in.
     #[builder(setter(into))]
     collection: String,
     /// List of vertex collection names.
-    /// Edges in collection can only be inserted if their `_to` is in
-    /// any of the collections here.
+    /// Edges in collection can only be inserted if their `_from` is in
+    /// any of the collections here.  This cannot be empty.
     #[builder(setter(into))]
-    to: Vec<String>,
+    from: Vec<String>,
     /// List of vertex collection names.
     /// Edges in collection can only be inserted if their `_to` is in
-    /// any of the collections here.
-    from: Vec<String>,
+    /// any of the collections here.  This cannot be empty.
+    #[builder(setter(into))]
+    to: Vec<String>,
+}
+
+impl EdgeDefinitionBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        match (&self.from, &self.to) {
+            (Some(from), _) if from.is_empty() => Err(EMPTY_FROM_ERR.into()),
+            (_, Some(to)) if to.is_empty() => Err(EMPTY_TO_ERR.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The direction to follow edges in during a graph traversal
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// Follow edges from their `_from` to their `_to` vertex
+    #[default]
+    Outbound,
+    /// Follow edges from their `_to` to their `_from` vertex
+    Inbound,
+    /// Follow edges in either direction
+    Any,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Outbound => "OUTBOUND",
+                Self::Inbound => "INBOUND",
+                Self::Any => "ANY",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EdgeDefinitionBuilder, EMPTY_FROM_ERR, EMPTY_TO_ERR};
+    use anyhow::Result;
+
+    #[test]
+    fn edge_definition_builds() -> Result<()> {
+        let ed = EdgeDefinitionBuilder::default()
+            .collection("test_edge")
+            .from(vec!["test_from".to_string()])
+            .to(vec!["test_to".to_string()])
+            .build()?;
+        assert_eq!(&["test_from".to_string()], ed.from().as_slice());
+        assert_eq!(&["test_to".to_string()], ed.to().as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_from_errors() {
+        match EdgeDefinitionBuilder::default()
+            .collection("test_edge")
+            .from(vec![])
+            .to(vec!["test_to".to_string()])
+            .build()
+        {
+            Ok(_) => panic!("The builder should fail!"),
+            Err(e) => assert_eq!(EMPTY_FROM_ERR, format!("{e}")),
+        }
+    }
+
+    #[test]
+    fn empty_to_errors() {
+        match EdgeDefinitionBuilder::default()
+            .collection("test_edge")
+            .from(vec!["test_from".to_string()])
+            .to(vec![])
+            .build()
+        {
+            Ok(_) => panic!("The builder should fail!"),
+            Err(e) => assert_eq!(EMPTY_TO_ERR, format!("{e}")),
+        }
+    }
 }
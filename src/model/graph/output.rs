@@ -25,6 +25,9 @@ pub struct List {
 }
 
 /// Output for [`create`](crate::Graph::create), [`read`](crate::Graph::read)
+///
+/// The [`rev`](Graph::rev) of the returned graph can be used as the `if_match`
+/// of a later edge definition replace or delete.
 #[derive(Clone, Debug, Deserialize, Getters, Serialize)]
 #[getset(get = "pub")]
 pub struct GraphMeta {
@@ -71,6 +74,16 @@ pub struct EdgesMeta {
     /// The list of all vertex collections within this graph.
     /// Includes collections in edge definitions as well as orphans.
     collections: Vec<String>,
+    /// The edge definitions of this graph, including the `from` and `to`
+    /// vertex collections of each edge collection.
+    #[serde(rename = "edgeDefinitions", default)]
+    edge_definitions: Vec<EdgeDefinition>,
+}
+
+impl EdgesMeta {
+    pub(crate) fn set_edge_definitions(&mut self, edge_definitions: Vec<EdgeDefinition>) {
+        self.edge_definitions = edge_definitions;
+    }
 }
 
 /// Output for [`create_edge`](crate::Graph::create_edge)
@@ -254,3 +267,91 @@ pub struct UpdateVertexMeta {
     #[serde(skip_serializing_if = "Option::is_none")]
     new: Option<Vertex>,
 }
+
+/// A path output by [`traverse`](crate::Graph::traverse) and
+/// [`shortest_path`](crate::Graph::shortest_path)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Path<V, E> {
+    /// The vertices on the path, starting with the start vertex
+    vertices: Vec<V>,
+    /// The edges on the path
+    edges: Vec<E>,
+}
+
+impl<V, E> Path<V, E> {
+    /// Build a path from the `{ v, e }` rows of a `SHORTEST_PATH` query,
+    /// or `None` if there were no rows, i.e. no path exists
+    pub(crate) fn from_steps(steps: Vec<PathStep<V, E>>) -> Option<Self> {
+        if steps.is_empty() {
+            None
+        } else {
+            let mut vertices = Vec::with_capacity(steps.len());
+            let mut edges = Vec::with_capacity(steps.len());
+
+            for step in steps {
+                vertices.push(step.v);
+                if let Some(e) = step.e {
+                    edges.push(e);
+                }
+            }
+            Some(Self { vertices, edges })
+        }
+    }
+}
+
+/// A single vertex, and the edge leading to it, on a shortest path
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct PathStep<V, E> {
+    v: V,
+    e: Option<E>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EdgesMeta, Path, PathStep};
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn edges_meta_without_edge_definitions() -> Result<()> {
+        let edges_meta: EdgesMeta = serde_json::from_value(json!({
+            "error": false,
+            "code": 200,
+            "collections": ["test_edge"]
+        }))?;
+        assert_eq!(
+            &["test_edge".to_string()],
+            edges_meta.collections().as_slice()
+        );
+        assert!(edges_meta.edge_definitions().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn no_steps_is_no_path() {
+        let path: Option<Path<String, String>> = Path::from_steps(vec![]);
+        assert!(path.is_none());
+    }
+
+    #[test]
+    fn steps_to_path() {
+        let steps = vec![
+            PathStep {
+                v: "a".to_string(),
+                e: None,
+            },
+            PathStep {
+                v: "b".to_string(),
+                e: Some("a->b".to_string()),
+            },
+            PathStep {
+                v: "c".to_string(),
+                e: Some("b->c".to_string()),
+            },
+        ];
+        let path = Path::from_steps(steps).unwrap();
+        assert_eq!(path.vertices(), &["a", "b", "c"]);
+        assert_eq!(path.edges(), &["a->b", "b->c"]);
+    }
+}
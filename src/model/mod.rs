@@ -15,6 +15,8 @@ use reqwest::{header::HeaderMap, Url};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+pub mod admin;
+pub mod analyzer;
 pub(crate) mod auth;
 pub mod coll;
 pub mod common;
@@ -22,6 +24,8 @@ pub mod cursor;
 pub mod db;
 pub mod doc;
 pub mod graph;
+pub mod query;
+pub mod view;
 
 pub(crate) trait BuildUrl {
     fn build_url(&self, base: &str, conn: &Connection) -> Result<Url>;
@@ -37,43 +41,74 @@ pub(crate) trait AddHeaders {
 pub(crate) const TEST_COLL: &str = "test_coll";
 #[cfg(test)]
 pub(crate) const TEST_KEY: &str = "test_key";
+pub(crate) const ALLOW_DIRTY_READ: &str = "x-arango-allow-dirty-read";
+pub(crate) const QUEUE_TIME_SECONDS: &str = "x-arango-queue-time-seconds";
+pub(crate) const COLLECTION_QP: &str = "collection=";
+pub(crate) const COMPLETE_QP: &str = "complete=true";
+pub(crate) const COMPLETE_FALSE_QP: &str = "complete=false";
+pub(crate) const COMPACT_QP: &str = "compact=true";
+pub(crate) const COMPACT_FALSE_QP: &str = "compact=false";
+pub(crate) const DETAILS_QP: &str = "details=true";
+pub(crate) const DETAILS_FALSE_QP: &str = "details=false";
 pub(crate) const DROP_COLLECTION_QP: &str = "dropCollection=true";
 pub(crate) const DROP_COLLECTION_FALSE_QP: &str = "dropCollection=false";
 pub(crate) const DROP_COLLECTIONS_QP: &str = "dropCollections=true";
 pub(crate) const DROP_COLLECTIONS_FALSE_QP: &str = "dropCollections=false";
 pub(crate) const IGNORE_REVS_QP: &str = "ignoreRevs=true";
 pub(crate) const IGNORE_REVS_FALSE_QP: &str = "ignoreRevs=false";
+pub(crate) const IS_SYSTEM_QP: &str = "isSystem=true";
+pub(crate) const IS_SYSTEM_FALSE_QP: &str = "isSystem=false";
 pub(crate) const KEEP_NULL_QP: &str = "keepNull=true";
 pub(crate) const KEEP_NULL_FALSE_QP: &str = "keepNull=false";
 pub(crate) const MERGE_OBJECTS_QP: &str = "mergeObjects=true";
 pub(crate) const MERGE_OBJECTS_FALSE_QP: &str = "mergeObjects=false";
+pub(crate) const ON_DUPLICATE_QP: &str = "onDuplicate=";
 pub(crate) const ONLYGET_QP: &str = "onlyget=true";
 pub(crate) const OVERWRITE_QP: &str = "overwrite=true";
 pub(crate) const OVERWRITE_FALSE_QP: &str = "overwrite=false";
 pub(crate) const OVERWRITE_MODE_QP: &str = "overwriteMode=";
+pub(crate) const REFILL_INDEX_CACHES_QP: &str = "refillIndexCaches=true";
+pub(crate) const REFILL_INDEX_CACHES_FALSE_QP: &str = "refillIndexCaches=false";
 pub(crate) const RETURN_NEW_QP: &str = "returnNew=true";
 pub(crate) const RETURN_NEW_FALSE_QP: &str = "returnNew=false";
 pub(crate) const RETURN_OLD_QP: &str = "returnOld=true";
 pub(crate) const RETURN_OLD_FALSE_QP: &str = "returnOld=false";
 pub(crate) const SILENT_QP: &str = "silent=true";
 pub(crate) const SILENT_FALSE_QP: &str = "silent=false";
+pub(crate) const TYPE_DOCUMENTS_QP: &str = "type=documents";
+pub(crate) const VERSION_ATTRIBUTE_QP: &str = "versionAttribute=";
 pub(crate) const WAIT_FOR_SYNC_QP: &str = "waitForSync=true";
 pub(crate) const WAIT_FOR_SYNC_FALSE_QP: &str = "waitForSync=false";
+pub(crate) const WITH_DATA_QP: &str = "withData=true";
+pub(crate) const WITH_DATA_FALSE_QP: &str = "withData=false";
+pub(crate) const WITH_REVISIONS_QP: &str = "withRevisions=true";
+pub(crate) const WITH_REVISIONS_FALSE_QP: &str = "withRevisions=false";
 
 #[allow(variant_size_differences)]
 pub(crate) enum QueryParam {
+    Collection(String),
+    Compact(bool),
+    Complete(bool),
+    Details(bool),
+    DocumentsType,
     DropCollection(bool),
     DropCollections(bool),
     IgnoreRevs(bool),
+    IsSystem(bool),
     KeepNull(bool),
     MergeObjects(bool),
+    OnDuplicate(String),
     OnlyGet,
     Overwrite(bool),
     OverwriteMode(String),
+    RefillIndexCaches(bool),
     ReturnNew(bool),
     ReturnOld(bool),
     Silent(bool),
+    VersionAttribute(String),
     WaitForSync(bool),
+    WithData(bool),
+    WithRevisions(bool),
 }
 
 /// # Example
@@ -87,6 +122,11 @@ impl From<QueryParam> for String {
     /// ```
     fn from(qp: QueryParam) -> String {
         match qp {
+            QueryParam::Collection(v) => format!("{COLLECTION_QP}{v}"),
+            QueryParam::Compact(v) => if v { COMPACT_QP } else { COMPACT_FALSE_QP }.to_string(),
+            QueryParam::Complete(v) => if v { COMPLETE_QP } else { COMPLETE_FALSE_QP }.to_string(),
+            QueryParam::Details(v) => if v { DETAILS_QP } else { DETAILS_FALSE_QP }.to_string(),
+            QueryParam::DocumentsType => TYPE_DOCUMENTS_QP.to_string(),
             QueryParam::DropCollection(v) => if v {
                 DROP_COLLECTION_QP
             } else {
@@ -105,6 +145,9 @@ impl From<QueryParam> for String {
                 IGNORE_REVS_FALSE_QP
             }
             .to_string(),
+            QueryParam::IsSystem(v) => {
+                if v { IS_SYSTEM_QP } else { IS_SYSTEM_FALSE_QP }.to_string()
+            }
             QueryParam::KeepNull(v) => {
                 if v { KEEP_NULL_QP } else { KEEP_NULL_FALSE_QP }.to_string()
             }
@@ -114,11 +157,18 @@ impl From<QueryParam> for String {
                 MERGE_OBJECTS_FALSE_QP
             }
             .to_string(),
+            QueryParam::OnDuplicate(v) => format!("{ON_DUPLICATE_QP}{v}"),
             QueryParam::OnlyGet => ONLYGET_QP.to_string(),
             QueryParam::Overwrite(v) => {
                 if v { OVERWRITE_QP } else { OVERWRITE_FALSE_QP }.to_string()
             }
             QueryParam::OverwriteMode(v) => format!("{OVERWRITE_MODE_QP}{v}"),
+            QueryParam::RefillIndexCaches(v) => if v {
+                REFILL_INDEX_CACHES_QP
+            } else {
+                REFILL_INDEX_CACHES_FALSE_QP
+            }
+            .to_string(),
             QueryParam::ReturnNew(v) => if v {
                 RETURN_NEW_QP
             } else {
@@ -132,12 +182,22 @@ impl From<QueryParam> for String {
             }
             .to_string(),
             QueryParam::Silent(v) => if v { SILENT_QP } else { SILENT_FALSE_QP }.to_string(),
+            QueryParam::VersionAttribute(v) => format!("{VERSION_ATTRIBUTE_QP}{v}"),
             QueryParam::WaitForSync(v) => if v {
                 WAIT_FOR_SYNC_QP
             } else {
                 WAIT_FOR_SYNC_FALSE_QP
             }
             .to_string(),
+            QueryParam::WithData(v) => {
+                if v { WITH_DATA_QP } else { WITH_DATA_FALSE_QP }.to_string()
+            }
+            QueryParam::WithRevisions(v) => if v {
+                WITH_REVISIONS_QP
+            } else {
+                WITH_REVISIONS_FALSE_QP
+            }
+            .to_string(),
         }
     }
 }
@@ -194,3 +254,5 @@ impl fmt::Display for BaseErr {
         Ok(())
     }
 }
+
+impl std::error::Error for BaseErr {}
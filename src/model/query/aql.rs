@@ -0,0 +1,222 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! AQL Query Builder
+
+use crate::model::cursor::input::{CreateConfig, CreateConfigBuilder};
+use anyhow::Result;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A fluent builder for simple AQL queries, producing a cursor
+/// [`CreateConfig`] for use with [`Cursor::create`](crate::Cursor::create).
+///
+/// Each call appends the matching AQL operation, in order, so the chain
+/// reads like the resulting query.  Values should be passed as bind
+/// variables via [`bind`](Self::bind) rather than formatted into the
+/// operations.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use ruarango::query::AqlBuilder;
+/// # fn main() -> Result<()> {
+/// let aql = AqlBuilder::for_("d")
+///     .in_("test_coll")
+///     .filter("d.test == @val")
+///     .bind("val", "x")
+///     .limit(10)
+///     .ret("d");
+/// assert_eq!(
+///     "FOR d IN test_coll FILTER d.test == @val LIMIT 10 RETURN d",
+///     aql.query()
+/// );
+/// let _config = aql.build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AqlBuilder {
+    operations: Vec<String>,
+    bind_vars: HashMap<String, Value>,
+}
+
+impl AqlBuilder {
+    /// Start a query iterating over a collection or array with the given
+    /// variable name, i.e. `FOR d`
+    pub fn for_<T>(var: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::default().push("FOR", var)
+    }
+
+    /// The collection, view or array expression to iterate over, i.e. `IN test_coll`
+    #[must_use]
+    pub fn in_<T>(self, source: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.push("IN", source)
+    }
+
+    /// Add a `FILTER` condition
+    #[must_use]
+    pub fn filter<T>(self, condition: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.push("FILTER", condition)
+    }
+
+    /// Add a `SORT` expression, i.e. `d.name DESC`
+    #[must_use]
+    pub fn sort<T>(self, expression: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.push("SORT", expression)
+    }
+
+    /// Limit the number of results
+    #[must_use]
+    pub fn limit(self, count: usize) -> Self {
+        self.push("LIMIT", count.to_string())
+    }
+
+    /// Skip `offset` results, then limit the number of results
+    #[must_use]
+    pub fn limit_offset(self, offset: usize, count: usize) -> Self {
+        self.push("LIMIT", format!("{offset}, {count}"))
+    }
+
+    /// The expression to return, i.e. `d`
+    #[must_use]
+    pub fn ret<T>(self, expression: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.push("RETURN", expression)
+    }
+
+    /// Add a bind variable, referenced in the query as `@name`.  The value
+    /// may be any JSON value, i.e. a number, string or array
+    #[must_use]
+    pub fn bind<T, U>(mut self, name: T, value: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<Value>,
+    {
+        let _ = self.bind_vars.insert(name.into(), value.into());
+        self
+    }
+
+    /// The AQL query string
+    #[must_use]
+    pub fn query(&self) -> String {
+        self.operations.join(" ")
+    }
+
+    /// The bind variables
+    #[must_use]
+    pub fn bind_vars(&self) -> &HashMap<String, Value> {
+        &self.bind_vars
+    }
+
+    /// A cursor config builder with the query and bind variables set, so
+    /// further cursor options, i.e. `batch_size`, can be added
+    #[must_use]
+    pub fn config_builder(self) -> CreateConfigBuilder {
+        let mut builder = CreateConfigBuilder::default();
+        let _ = builder.query(self.query());
+        if !self.bind_vars.is_empty() {
+            let _ = builder.bind_vars(self.bind_vars);
+        }
+        builder
+    }
+
+    /// Build the cursor config
+    ///
+    /// # Errors
+    /// An error will be returned if the cursor config fails to build.
+    pub fn build(self) -> Result<CreateConfig> {
+        Ok(self.config_builder().build()?)
+    }
+
+    fn push<T>(mut self, keyword: &str, operand: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.operations
+            .push(format!("{keyword} {}", operand.into()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AqlBuilder;
+    use crate::model::TEST_COLL;
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn filter_limit_query() -> Result<()> {
+        let config = AqlBuilder::for_("d")
+            .in_(TEST_COLL)
+            .filter("d.test == @val")
+            .bind("val", "x")
+            .limit(10)
+            .ret("d")
+            .build()?;
+        assert_eq!(
+            "FOR d IN test_coll FILTER d.test == @val LIMIT 10 RETURN d",
+            config.query()
+        );
+        let bind_vars = config.bind_vars().as_ref().unwrap();
+        assert_eq!(1, bind_vars.len());
+        assert_eq!(Some(&json!("x")), bind_vars.get("val"));
+        Ok(())
+    }
+
+    #[test]
+    fn sort_offset_query() -> Result<()> {
+        let aql = AqlBuilder::for_("d")
+            .in_(TEST_COLL)
+            .filter("d.a >= @min")
+            .filter("d.b != null")
+            .sort("d.a DESC")
+            .limit_offset(20, 10)
+            .bind("min", 5)
+            .ret("{ a: d.a, b: d.b }");
+        assert_eq!(
+            "FOR d IN test_coll FILTER d.a >= @min FILTER d.b != null SORT d.a DESC LIMIT 20, 10 RETURN { a: d.a, b: d.b }",
+            aql.query()
+        );
+        assert_eq!(Some(&json!(5)), aql.bind_vars().get("min"));
+        let config = aql.build()?;
+        let bind_vars = config.bind_vars().as_ref().unwrap();
+        assert_eq!(Some(&json!(5)), bind_vars.get("min"));
+        Ok(())
+    }
+
+    #[test]
+    fn no_bind_vars() -> Result<()> {
+        let config = AqlBuilder::for_("d")
+            .in_(TEST_COLL)
+            .ret("d._key")
+            .config_builder()
+            .batch_size(5)
+            .build()?;
+        assert_eq!("FOR d IN test_coll RETURN d._key", config.query());
+        assert!(config.bind_vars().is_none());
+        assert_eq!(Some(5), *config.batch_size());
+        Ok(())
+    }
+}
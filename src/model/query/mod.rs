@@ -0,0 +1,17 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! [`Output`](crate::query::output) for [`Query`](crate::Query) operations,
+//! and an [`AqlBuilder`] for building cursor queries
+
+mod aql;
+pub mod output;
+
+pub use aql::AqlBuilder;
+
+pub(crate) const BASE_QUERY_SUFFIX: &str = "_api/query";
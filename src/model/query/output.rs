@@ -0,0 +1,86 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Query Output Structs
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An AQL query output by [`current_queries`](crate::Query::current_queries) and
+/// [`slow_queries`](crate::Query::slow_queries)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct RunningQuery {
+    /// The query id, used to [`kill_query`](crate::Query::kill_query) the query
+    id: String,
+    /// The name of the database the query runs in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    database: Option<String>,
+    /// The name of the user that started the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+    /// The query string, possibly truncated
+    query: String,
+    /// The bind parameter values used by the query
+    #[serde(rename = "bindVars", default)]
+    bind_vars: HashMap<String, Value>,
+    /// The date and time the query was started
+    started: String,
+    /// The query's run time up to the point the list of queries was queried,
+    /// in seconds
+    #[serde(rename = "runTime")]
+    run_time: f64,
+    /// The query's current execution state, i.e. `executing`, or `finished`
+    /// for slow queries
+    state: String,
+    /// Whether or not the query uses a streaming cursor
+    #[serde(default)]
+    stream: bool,
+}
+
+/// Output for [`clear_slow_queries`](crate::Query::clear_slow_queries) and
+/// [`kill_query`](crate::Query::kill_query)
+#[derive(Clone, Copy, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Deleted {
+    /// Is this an error?
+    error: bool,
+    /// The response code
+    code: u16,
+}
+
+#[cfg(test)]
+impl Default for RunningQuery {
+    fn default() -> Self {
+        let mut bind_vars = HashMap::new();
+        let _ = bind_vars.insert("@coll".to_string(), Value::from("test_coll"));
+        Self {
+            id: "123".to_string(),
+            database: Some("keti".to_string()),
+            user: Some("root".to_string()),
+            query: "FOR d IN @@coll RETURN d".to_string(),
+            bind_vars,
+            started: "2021-05-01T15:37:51Z".to_string(),
+            run_time: 12.5,
+            state: "executing".to_string(),
+            stream: false,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Default for Deleted {
+    fn default() -> Self {
+        Self {
+            error: false,
+            code: 200,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! View Input Structs
+
+use super::ViewKind;
+use derive_builder::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// `arangosearch` view creation configuration
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct CreateConfig {
+    /// The name of the view
+    #[builder(setter(into))]
+    name: String,
+    /// The kind of view, always [`ArangoSearch`](ViewKind::ArangoSearch)
+    #[builder(setter(skip), default)]
+    #[serde(rename = "type")]
+    kind: ViewKind,
+    /// The collections to link to the view, keyed by collection name
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<HashMap<String, Link>>,
+    /// Wait at least this many commits between removing unused files in the
+    /// data directory.  The server default is 2.
+    #[builder(setter(strip_option), default)]
+    #[serde(
+        rename = "cleanupIntervalStep",
+        skip_serializing_if = "Option::is_none"
+    )]
+    cleanup_interval_step: Option<usize>,
+    /// Wait at least this many milliseconds between applying the
+    /// consolidation policy.  The server default is 1000.
+    #[builder(setter(strip_option), default)]
+    #[serde(
+        rename = "consolidationIntervalMsec",
+        skip_serializing_if = "Option::is_none"
+    )]
+    consolidation_interval_msec: Option<usize>,
+}
+
+/// The properties of a collection link
+#[derive(Builder, Clone, Debug, Default, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+pub struct Link {
+    /// The names of the analyzers to apply to the values of the processed
+    /// fields.  The server default is `["identity"]`.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzers: Option<Vec<String>>,
+    /// Process all document attributes, not only the ones listed in `fields`
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "includeAllFields", skip_serializing_if = "Option::is_none")]
+    include_all_fields: Option<bool>,
+    /// The link properties of individual document attributes, keyed by
+    /// attribute name
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<HashMap<String, Value>>,
+    /// Index array values by their position, i.e. `attr[0]`, rather than
+    /// treating them as equivalent to the `attr` value
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "trackListPositions", skip_serializing_if = "Option::is_none")]
+    track_list_positions: Option<bool>,
+    /// Store the values of the processed attributes in the view, `none`
+    /// (the default) or `id`
+    #[builder(setter(into, strip_option), default)]
+    #[serde(rename = "storeValues", skip_serializing_if = "Option::is_none")]
+    store_values: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateConfigBuilder, LinkBuilder};
+    use crate::model::TEST_COLL;
+    use anyhow::Result;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn create_config_serializes() -> Result<()> {
+        let link = LinkBuilder::default()
+            .analyzers(vec!["text_en".to_string()])
+            .include_all_fields(true)
+            .build()?;
+        let mut links = HashMap::new();
+        let _ = links.insert(TEST_COLL.to_string(), link);
+        let config = CreateConfigBuilder::default()
+            .name("test_view")
+            .links(links)
+            .cleanup_interval_step(2)
+            .consolidation_interval_msec(1000)
+            .build()?;
+        assert_eq!(
+            json!({
+                "name": "test_view",
+                "type": "arangosearch",
+                "links": {
+                    "test_coll": {
+                        "analyzers": ["text_en"],
+                        "includeAllFields": true
+                    }
+                },
+                "cleanupIntervalStep": 2,
+                "consolidationIntervalMsec": 1000
+            }),
+            serde_json::to_value(&config)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! [`Input`](crate::view::input)/[`Output`](crate::view::output) for [`View`](crate::View) operations
+
+pub mod input;
+pub mod output;
+
+pub(crate) const BASE_VIEW_SUFFIX: &str = "_api/view";
+
+use serde::{Deserialize, Serialize};
+
+/// The kind of view
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ViewKind {
+    /// An `arangosearch` view, with links to collections
+    #[default]
+    #[serde(rename = "arangosearch")]
+    ArangoSearch,
+    /// A `search-alias` view, backed by inverted indexes
+    #[serde(rename = "search-alias")]
+    SearchAlias,
+}
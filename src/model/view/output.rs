@@ -0,0 +1,92 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! View Output Structs
+
+use super::ViewKind;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// View metadata output by [`create`](crate::View::create) and
+/// [`list`](crate::View::list)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct ViewMeta {
+    /// The id of the view
+    id: String,
+    /// The name of the view
+    name: String,
+    /// The kind of view
+    #[serde(rename = "type")]
+    kind: ViewKind,
+    /// The globally unique id of the view
+    #[serde(rename = "globallyUniqueId")]
+    globally_unique_id: String,
+}
+
+/// View properties output by [`properties`](crate::View::properties)
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub")]
+pub struct Properties {
+    /// The view metadata
+    #[serde(flatten)]
+    meta: ViewMeta,
+    /// The collection links of the view, keyed by collection name
+    #[serde(default)]
+    links: HashMap<String, Value>,
+    /// Wait at least this many commits between removing unused files in the
+    /// data directory
+    #[serde(
+        rename = "cleanupIntervalStep",
+        skip_serializing_if = "Option::is_none"
+    )]
+    cleanup_interval_step: Option<usize>,
+    /// Wait at least this many milliseconds between committing view data
+    /// store changes
+    #[serde(rename = "commitIntervalMsec", skip_serializing_if = "Option::is_none")]
+    commit_interval_msec: Option<usize>,
+    /// Wait at least this many milliseconds between applying the
+    /// consolidation policy
+    #[serde(
+        rename = "consolidationIntervalMsec",
+        skip_serializing_if = "Option::is_none"
+    )]
+    consolidation_interval_msec: Option<usize>,
+}
+
+#[cfg(test)]
+impl Default for ViewMeta {
+    fn default() -> Self {
+        Self {
+            id: "12345".to_string(),
+            name: "test_view".to_string(),
+            kind: ViewKind::ArangoSearch,
+            globally_unique_id: "h8B2B671BCFD0/12345".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Default for Properties {
+    fn default() -> Self {
+        let mut links = HashMap::new();
+        let _ = links.insert(
+            "test_coll".to_string(),
+            serde_json::json!({ "includeAllFields": true }),
+        );
+        Self {
+            meta: ViewMeta::default(),
+            links,
+            cleanup_interval_step: Some(2),
+            commit_interval_msec: Some(1000),
+            consolidation_interval_msec: Some(1000),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `r2d2` connection pool support
+
+use crate::{builder::ConnectionBuilder, conn::Connection, error::RuarangoErr};
+use anyhow::{Context, Result};
+use futures::Future;
+use r2d2::ManageConnection;
+use std::{fmt, thread};
+use tokio::runtime::{Handle, Runtime};
+
+/// An [`r2d2`] connection manager for `ArangoDB` connections.
+///
+/// Every pooled connection is built from the same [`ConnectionBuilder`], so
+/// they all share the url, credentials and settings.  Connections are checked
+/// by pinging the `_api/version` endpoint, and are considered broken if their
+/// last request failed to reach the server.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use ruarango::{mock_auth, start_mock_server};
+/// use r2d2::Pool;
+/// use ruarango::{ConnectionBuilder, ConnectionManager};
+///
+/// # fn main() -> Result<()> {
+/// # let runtime = tokio::runtime::Runtime::new()?;
+/// # let mock_server = runtime.block_on(start_mock_server());
+/// # runtime.block_on(mock_auth(&mock_server));
+/// # let url = mock_server.uri();
+/// let builder = ConnectionBuilder::default()
+///     .url(url)
+///     .username("root")
+///     .password("")
+///     .database("test_db");
+/// let manager = ConnectionManager::new(builder)?;
+/// let pool = Pool::builder().max_size(2).build(manager)?;
+/// #   Ok(())
+/// # }
+/// ```
+pub struct ConnectionManager {
+    builder: ConnectionBuilder,
+    runtime: Option<Runtime>,
+}
+
+impl ConnectionManager {
+    /// Create a new connection manager that builds connections from the
+    /// given [`ConnectionBuilder`]
+    ///
+    /// # Errors
+    /// An error is returned if the runtime used to build connections cannot be
+    /// created.
+    pub fn new(builder: ConnectionBuilder) -> Result<Self> {
+        let runtime = Runtime::new().with_context(|| "Unable to build the pool runtime")?;
+        Ok(Self {
+            builder,
+            runtime: Some(runtime),
+        })
+    }
+
+    /// Block on the given future, on a separate thread if the caller is
+    /// already running within a runtime.
+    fn block_on<F>(&self, future: F) -> Result<F::Output>
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        let runtime = self
+            .runtime
+            .as_ref()
+            .with_context(|| "The pool runtime has been shutdown")?;
+
+        if Handle::try_current().is_ok() {
+            Ok(thread::scope(|s| {
+                s.spawn(|| runtime.block_on(future))
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            }))
+        } else {
+            Ok(runtime.block_on(future))
+        }
+    }
+}
+
+impl Drop for ConnectionManager {
+    fn drop(&mut self) {
+        // Dropping a runtime blocks, which panics if the pool is dropped
+        // within another runtime.
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl fmt::Debug for ConnectionManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The builder holds the credentials, so it is not included
+        f.debug_struct("ConnectionManager").finish_non_exhaustive()
+    }
+}
+
+fn pool_err(e: anyhow::Error) -> RuarangoErr {
+    e.downcast::<RuarangoErr>()
+        .unwrap_or_else(|e| RuarangoErr::Pool { err: e.to_string() })
+}
+
+impl ManageConnection for ConnectionManager {
+    type Connection = Connection;
+    type Error = RuarangoErr;
+
+    fn connect(&self) -> std::result::Result<Self::Connection, Self::Error> {
+        self.block_on(self.builder.clone().build())
+            .and_then(|conn| conn)
+            .map_err(pool_err)
+    }
+
+    fn is_valid(&self, conn: &mut Self::Connection) -> std::result::Result<(), Self::Error> {
+        self.block_on(conn.ping())
+            .and_then(|ping| ping)
+            .map_err(pool_err)
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        conn.is_broken()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConnectionManager;
+    use crate::{
+        builder::ConnectionBuilder,
+        traits::Database,
+        utils::{
+            mock_auth,
+            mocks::{admin::mock_version, db::mock_current},
+        },
+    };
+    use anyhow::Result;
+    use r2d2::Pool;
+    use tokio::runtime::Runtime;
+    use wiremock::MockServer;
+
+    #[test]
+    fn pool_round_trip() -> Result<()> {
+        let runtime = Runtime::new()?;
+        let mock_server = runtime.block_on(MockServer::start());
+        runtime.block_on(mock_auth(&mock_server));
+        runtime.block_on(mock_version(&mock_server))?;
+        runtime.block_on(mock_current(&mock_server));
+
+        let builder = ConnectionBuilder::default()
+            .url(mock_server.uri())
+            .username("root")
+            .password("")
+            .database("keti");
+        let pool = Pool::builder()
+            .max_size(2)
+            .build(ConnectionManager::new(builder)?)?;
+        assert_eq!(pool.state().connections, 2);
+
+        let conn = pool.get()?;
+        let res = runtime.block_on(conn.current())?;
+        assert!(res.is_right());
+        assert!(!conn.is_broken());
+
+        Ok(())
+    }
+}
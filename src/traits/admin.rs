@@ -0,0 +1,62 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` admin trait
+
+use crate::{
+    admin::{
+        output::{Echo, Status, Version},
+        LogLevel,
+    },
+    types::ArangoResult,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Server Administration Operations
+#[async_trait]
+#[allow(unused_qualifications)]
+pub trait Admin {
+    /// Retrieves the server name, version and license.  If `details` is true,
+    /// additional information about the server is included.
+    async fn version(&self, details: bool) -> ArangoResult<Version>;
+    /// Retrieves the status of the server
+    async fn status(&self) -> ArangoResult<Status>;
+    /// Retrieves the current log level of each server log topic, keyed by
+    /// topic name.
+    ///
+    /// This requires superuser rights.  Unprivileged users will receive a
+    /// [`Forbidden`](crate::error::RuarangoErr::Forbidden) error.
+    async fn log_level(&self) -> ArangoResult<HashMap<String, LogLevel>>;
+    /// Retrieves the server metrics in the Prometheus text exposition
+    /// format.  Use [`Metric::parse`](crate::admin::output::Metric::parse)
+    /// to extract the individual samples.
+    async fn metrics(&self) -> ArangoResult<String>;
+    /// Retrieves the endpoints of the coordinators in the cluster, i.e.
+    /// `tcp://127.0.0.1:8529`.  These can be used to spread load across, or
+    /// fail over between, coordinators.
+    ///
+    /// This is only available on a cluster coordinator.  A single server
+    /// will respond with a
+    /// [`Forbidden`](crate::error::RuarangoErr::Forbidden) error.
+    async fn endpoints(&self) -> ArangoResult<Vec<String>>;
+    /// Sets the log level of the given server log topics, i.e. `queries`.
+    /// Topics not included are left unchanged.  The resulting log levels of
+    /// all topics are returned.
+    ///
+    /// This requires superuser rights.  Unprivileged users will receive a
+    /// [`Forbidden`](crate::error::RuarangoErr::Forbidden) error.
+    async fn set_log_level(
+        &self,
+        levels: HashMap<String, LogLevel>,
+    ) -> ArangoResult<HashMap<String, LogLevel>>;
+    /// Sends a trivial request and returns it as the server received it,
+    /// i.e. the headers, path and database.  This is useful to diagnose
+    /// proxies or headers that alter requests on their way to the server.
+    async fn echo(&self) -> ArangoResult<Echo>;
+}
@@ -0,0 +1,34 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` analyzer trait
+
+use crate::{
+    analyzer::{
+        input::AnalyzerConfig,
+        output::{AnalyzerMeta, Delete},
+    },
+    common::output::Response,
+    types::ArangoResult,
+};
+use async_trait::async_trait;
+
+/// Analyzer Operations
+#[async_trait]
+#[allow(unused_qualifications)]
+pub trait Analyzer {
+    /// Creates an analyzer
+    async fn create(&self, config: AnalyzerConfig) -> ArangoResult<AnalyzerMeta>;
+    /// Lists all analyzers available in the database
+    async fn list(&self) -> ArangoResult<Response<Vec<AnalyzerMeta>>>;
+    /// Deletes the analyzer with the given name.  An analyzer that is still
+    /// in use by a view or index is only deleted if `force` is true,
+    /// otherwise a [`Conflict`](crate::error::RuarangoErr::Conflict) is
+    /// returned.
+    async fn delete(&self, name: &str, force: bool) -> ArangoResult<Delete>;
+}
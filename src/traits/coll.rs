@@ -10,16 +10,20 @@
 
 use crate::{
     coll::{
-        input::{Config, Props},
+        input::{AllConfig, Config, DropConfig, ExportConfig, Props, TruncateConfig},
         output::{
             Checksum, Collection as Coll, Collections, Count, Create, Drop, Figures, Load,
-            LoadIndexes, ModifyProps, RecalculateCount, Rename, Revision, Truncate, Unload,
+            LoadIndexes, ModifyProps, Properties, RecalculateCount, Rename, ResponsibleShard,
+            Revision, Truncate, Unload,
         },
     },
     common::output::Response,
     types::ArangoResult,
 };
+use anyhow::Result;
 use async_trait::async_trait;
+use futures::stream::BoxStream;
+use serde::{de::DeserializeOwned, Serialize};
 
 /// Collection Operations
 #[async_trait]
@@ -29,7 +33,17 @@ pub trait Collection {
     ///
     /// Setting `exclude_system` to true will exclude all system collections
     /// from the output.
-    async fn collections(&self, exclude_system: bool) -> ArangoResult<Response<Vec<Collections>>>;
+    ///
+    /// Setting `with_count` to true will populate the document
+    /// [`count`](crate::coll::output::Collections::count) of each collection.
+    /// `ArangoDB` does not include the count in the listing, so this issues a
+    /// single additional AQL query for the counts of all collections.  This
+    /// has no effect on an asynchronous connection.
+    async fn collections(
+        &self,
+        exclude_system: bool,
+        with_count: bool,
+    ) -> ArangoResult<Response<Vec<Collections>>>;
 
     /// Return information about a single collection
     async fn collection(&self, name: &str) -> ArangoResult<Coll>;
@@ -37,9 +51,22 @@ pub trait Collection {
     /// Create a collection
     async fn create(&self, config: &Config) -> ArangoResult<Create>;
 
+    /// Create a collection if it doesn't already exist
+    ///
+    /// If a collection with the same name already exists, the information for
+    /// the existing collection is returned instead of an error, so repeated
+    /// setup is idempotent.  Any other error is returned as is.  On an
+    /// asynchronous connection this behaves like [`create`](Self::create).
+    async fn create_if_absent(&self, config: &Config) -> ArangoResult<Coll>;
+
     /// Drop a collection
+    #[deprecated(note = "use `drop_with` with a `DropConfig` instead")]
     async fn drop(&self, name: &str, is_system: bool) -> ArangoResult<Drop>;
 
+    /// Drop a collection.
+    /// The `config` controls whether the collection is a system collection.
+    async fn drop_with(&self, name: &str, config: DropConfig) -> ArangoResult<Drop>;
+
     /// Will calculate a checksum of the meta-data (keys and optionally revision ids and
     /// optionally the document data) in the collection.
     ///
@@ -72,7 +99,11 @@ pub trait Collection {
     async fn count(&self, name: &str) -> ArangoResult<Count>;
 
     /// Some figures and additional statistical information about the collection.
-    async fn figures(&self, name: &str) -> ArangoResult<Figures>;
+    ///
+    /// If `details` is true, the extended storage engine figures are returned
+    /// in [`engine`](crate::coll::output::FiguresDetails::engine) as well.
+    /// These can be expensive to compute for large collections.
+    async fn figures(&self, name: &str, details: bool) -> ArangoResult<Figures>;
 
     /// Get the revision id for a collection
     /// The revision id is a server-generated string that clients can use to
@@ -103,6 +134,9 @@ pub trait Collection {
     /// of the collection should have priority over others.
     async fn load_indexes(&self, name: &str) -> ArangoResult<LoadIndexes>;
 
+    /// Read the properties of a collection
+    async fn properties(&self, name: &str) -> ArangoResult<Properties>;
+
     /// Change the properties of a collection
     ///
     /// **Note**: except for `wait_for_sync`, `journal_size` and `schema`, collection
@@ -121,6 +155,62 @@ pub trait Collection {
     /// Removes all documents from the collection, but leaves the indexes intact.
     async fn truncate(&self, name: &str) -> ArangoResult<Truncate>;
 
+    /// Removes all documents from the collection, but leaves the indexes intact.
+    /// The `config` controls whether the truncate waits for the data to be
+    /// synchronized to disk and whether the data is compacted afterwards.
+    async fn truncate_with(&self, name: &str, config: TruncateConfig) -> ArangoResult<Truncate>;
+
+    /// Compacts the data of a collection in order to reclaim disk space.
+    ///
+    /// **Note**: this method is specific for the RocksDB storage engine
+    async fn compact(&self, name: &str) -> ArangoResult<Coll>;
+
+    /// Returns the id of the shard that is responsible for the given document
+    /// (if the document exists) or that would be responsible if such a document
+    /// existed.
+    ///
+    /// The `document` must contain all of the shard key attributes of the
+    /// collection.
+    ///
+    /// **Note**: this method is only available in a cluster.  A single server
+    /// responds with a [`NotImplemented`](crate::Error::NotImplemented) error.
+    async fn responsible_shard<T>(
+        &self,
+        name: &str,
+        document: &T,
+    ) -> ArangoResult<ResponsibleShard>
+    where
+        T: Serialize + Send + Sync;
+
+    /// Stream every document in the collection, fetching them from a
+    /// streaming cursor one batch at a time, so the whole collection is never
+    /// held in memory.  The `config` controls the batch size and can project
+    /// each document down to a set of attributes.
+    ///
+    /// If fetching a batch fails, the cursor is deleted and the error is the
+    /// last item of the stream.  The documents cannot be streamed over an
+    /// asynchronous connection, so it yields a single
+    /// [`AsyncJob`](crate::Error::AsyncJob) error instead.
+    fn all<'a, T>(&'a self, name: &str, config: AllConfig) -> BoxStream<'a, Result<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'a;
+
+    /// Stream every document in the collection using the export API, fetching
+    /// them one batch at a time.  Unlike [`all`](Collection::all), no AQL
+    /// query is run, and the `config` can include or exclude top-level
+    /// attributes of each document.
+    ///
+    /// **Note**: the export API is not available in a cluster, and is
+    /// deprecated by `ArangoDB` in favor of streaming cursors.
+    ///
+    /// If fetching a batch fails, the export cursor is deleted and the error
+    /// is the last item of the stream.  The documents cannot be streamed over
+    /// an asynchronous connection, so it yields a single
+    /// [`AsyncJob`](crate::Error::AsyncJob) error instead.
+    fn export<'a, T>(&'a self, config: ExportConfig) -> BoxStream<'a, Result<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'a;
+
     /// Removes a collection from memory. This call does not delete any documents.
     /// You can use the collection afterwards, in which case it will be loaded into
     /// memory.
@@ -29,7 +29,31 @@ pub trait Cursor {
     async fn delete(&self, config: DeleteConfig) -> ArangoResult<()>;
 
     /// Grab the next batch from an open cursor
+    ///
+    /// This moves the cursor forward on the server, so it is never retried.
+    /// Create the cursor with
+    /// [`allow_retry`](crate::cursor::input::OptionsBuilder::allow_retry) to
+    /// re-fetch a lost batch with [`next_batch`](Cursor::next_batch).
     async fn next<T>(&self, config: NextConfig) -> ArangoResult<CursorMeta<T>>
     where
         T: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Grab the batch with the given id from an open cursor.  The cursor
+    /// must have been created with
+    /// [`allow_retry`](crate::cursor::input::OptionsBuilder::allow_retry).
+    /// Only the most recently returned batch or the batch after it can be
+    /// requested.
+    async fn next_batch<T>(&self, id: &str, batch_id: &str) -> ArangoResult<CursorMeta<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Create a cursor and fetch every batch, returning all of the results.
+    /// This is meant for small result sets, as every result is held in memory.
+    ///
+    /// If fetching a batch fails, the cursor is deleted before the error
+    /// is returned.  For an asynchronous connection the job info of the
+    /// cursor creation is returned.
+    async fn query_all<T>(&self, config: CreateConfig) -> ArangoResult<Vec<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync;
 }
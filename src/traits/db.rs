@@ -10,7 +10,10 @@
 
 use crate::{
     common::output::Response,
-    db::{input::Create, output::Current},
+    db::{
+        input::Create,
+        output::{Current, Engine},
+    },
     types::ArangoResult,
 };
 use async_trait::async_trait;
@@ -20,12 +23,32 @@ use async_trait::async_trait;
 #[allow(unused_qualifications)]
 pub trait Database {
     /// Retrieves the properties of the current database
+    ///
+    /// If the connection was built with
+    /// [`cache_current`](crate::ConnectionBuilder::cache_current), the first
+    /// successful response is memoized and served for every later call.
     async fn current(&self) -> ArangoResult<Response<Current>>;
+    /// Retrieves the properties of the given database, like
+    /// [`current`](Self::current) but without switching connections.  The
+    /// user must have access to the given database.
+    ///
+    /// # Errors
+    /// A [`DatabaseNotFound`](crate::Error::DatabaseNotFound) error is
+    /// returned if the database does not exist.
+    async fn info(&self, name: &str) -> ArangoResult<Response<Current>>;
     /// Retrieves the list of all databases the current user can access without specifying a different username or password.
     async fn user(&self) -> ArangoResult<Response<Vec<String>>>;
+    /// Retrieves the list of all databases the current user can access.
+    /// Unlike [`list`](Self::list), this works for any user from within any database.
+    /// This is an alias for [`user`](Self::user).
+    async fn list_accessible(&self) -> ArangoResult<Response<Vec<String>>>;
     /// Retrieves the list of all existing databases
     /// *Note*: retrieving the list of databases is only possible from within the _system database.
-    /// *Note*: You should use the `GET user API` to fetch the list of the available databases now.
+    /// *Note*: You should use [`list_accessible`](Self::list_accessible) to fetch the list of the available databases now.
+    ///
+    /// # Errors
+    /// A [`Forbidden`](crate::Error::Forbidden) error is returned if the current user
+    /// is not allowed to list all of the databases.
     async fn list(&self) -> ArangoResult<Response<Vec<String>>>;
     /// Creates a new database
     /// *Note*: creating a new database is only possible from within the _system database.
@@ -34,4 +57,7 @@ pub trait Database {
     /// *Note*: dropping a database is only possible from within the _system database.
     /// The _system database itself cannot be dropped.
     async fn drop(&self, name: &str) -> ArangoResult<Response<bool>>;
+    /// Retrieves the storage engine used by the server and the features it
+    /// supports
+    async fn engine(&self) -> ArangoResult<Engine>;
 }
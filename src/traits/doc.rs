@@ -10,26 +10,54 @@
 
 use crate::{
     doc::input::{
-        CreateConfig, CreatesConfig, DeleteConfig, DeletesConfig, ReadConfig, ReadsConfig,
-        ReplaceConfig, ReplacesConfig, UpdateConfig, UpdatesConfig,
+        CreateConfig, CreatesConfig, DeleteByQueryConfig, DeleteConfig, DeletesConfig,
+        ImportConfig, ReadByConfig, ReadConfig, ReadProjectedConfig, ReadsConfig, ReplaceConfig,
+        ReplacesConfig, UpdateConfig, UpdatesConfig,
     },
-    types::{ArangoResult, ArangoVecResult, DocMetaResult, DocMetaVecResult},
+    doc::output::{DocHeader, DocMetaOnly, ImportResult, RawDoc},
+    types::{ArangoMapResult, ArangoResult, ArangoVecResult, DocMetaResult, DocMetaVecResult},
 };
 use async_trait::async_trait;
+use bytes::Bytes;
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 
 /// Document Operations
 #[async_trait]
 #[allow(unused_qualifications)]
 pub trait Document {
     /// Create a document
+    ///
+    /// If `create_collection` is set on the `config` and the collection
+    /// doesn't exist, i.e. the write fails with a `1203`
+    /// [`NotFound`](crate::Error::NotFound) error, the collection is created
+    /// with the default options and the write is retried once.
     async fn create<T, U, V>(&self, config: CreateConfig<T>) -> DocMetaResult<U, V>
     where
         T: Serialize + Send + Sync,
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync;
 
+    /// Create a document, returning only the document metadata.  Unlike
+    /// [`create`](Self::create), no new or old document output types need to
+    /// be named.  Any `return_new` or `return_old` output is ignored.
+    async fn create_meta<T>(&self, config: CreateConfig<T>) -> ArangoResult<DocMetaOnly>
+    where
+        T: Serialize + Send + Sync;
+
+    /// Create a document from a pre-serialized JSON body, i.e. a `String`,
+    /// `Vec<u8>` or [`Bytes`].  The body is sent as is, skipping the
+    /// serialization step of [`create`](Self::create), so it must be a
+    /// valid JSON object.  The document metadata is parsed as usual.
+    async fn create_raw<T, U, V>(&self, config: CreateConfig<T>) -> DocMetaResult<U, V>
+    where
+        T: Clone + Into<Bytes> + Send + Sync,
+        U: Serialize + DeserializeOwned + Send + Sync,
+        V: Serialize + DeserializeOwned + Send + Sync;
+
     /// Create multiple documents
+    ///
+    /// See [`create`](Self::create) for the `create_collection` option.
     async fn creates<T, U, V>(&self, config: CreatesConfig<T>) -> DocMetaVecResult<U, V>
     where
         T: Serialize + Send + Sync,
@@ -41,12 +69,59 @@ pub trait Document {
     where
         T: DeserializeOwned + Send + Sync;
 
+    /// Read a document, retaining the response body so the document can be
+    /// deserialized into structs that borrow from it.
+    ///
+    /// This avoids allocating a `String` per field on read heavy workloads.
+    /// See [`RawDoc`](crate::doc::output::RawDoc).
+    async fn read_borrowed(&self, config: ReadConfig) -> ArangoResult<RawDoc>;
+
+    /// Read the header of a document, i.e. the `_id`, `_key` and `_rev`, without
+    /// transferring the document body.
+    async fn read_header(&self, config: ReadConfig) -> ArangoResult<DocHeader>;
+
+    /// Read a document by a selector, i.e. a document containing the `_key`
+    /// and optionally the `_rev` of the document to read, rather than by
+    /// url and headers.
+    ///
+    /// A [`PreconditionFailed`](crate::Error::PreconditionFailed) error is
+    /// returned if revisions are not ignored and the `_rev` does not match.
+    async fn read_by<T, U>(&self, config: ReadByConfig<T>) -> ArangoResult<U>
+    where
+        T: Serialize + Send + Sync,
+        U: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Read only the given attributes of a document, deserialized into a
+    /// partial output struct.  This reduces the payload size for wide
+    /// documents.
+    ///
+    /// The read runs as an AQL query, so the document is read via a cursor
+    /// rather than the document API.  A [`NotFound`](crate::Error::NotFound)
+    /// error is returned if the document does not exist.
+    async fn read_projected<T>(&self, config: ReadProjectedConfig) -> ArangoResult<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync;
+
     /// Read multiple documents
+    ///
+    /// The results are in the same order as the search documents in the
+    /// config, with an error entry in place of any document that could not
+    /// be read, i.e. one that does not exist.
     async fn reads<T, U>(&self, config: ReadsConfig<T>) -> ArangoVecResult<U>
     where
         T: Serialize + Send + Sync,
         U: Serialize + DeserializeOwned + Send + Sync;
 
+    /// Read multiple documents, keyed by the `_key` of each search document
+    ///
+    /// Unlike [`reads`](Self::reads), callers do not need to rely on the
+    /// position of each result.  A document that could not be read, i.e. one
+    /// that does not exist, maps to its error.
+    async fn reads_map<T, U>(&self, config: ReadsConfig<T>) -> ArangoMapResult<U>
+    where
+        T: Serialize + Send + Sync,
+        U: Serialize + DeserializeOwned + Send + Sync;
+
     /// Replace a docment with the given document
     async fn replace<T, U, V>(&self, config: ReplaceConfig<T>) -> DocMetaResult<U, V>
     where
@@ -68,6 +143,42 @@ pub trait Document {
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync;
 
+    /// Update a document with a patch built at runtime, i.e. from user input.
+    ///
+    /// The patch must be a JSON object.  Any other value, i.e. an array, is
+    /// rejected with an [`InvalidPatch`](crate::Error::InvalidPatch) error
+    /// before the request is sent, rather than failing on the server.
+    async fn update_value<U, V>(&self, config: UpdateConfig<Value>) -> DocMetaResult<U, V>
+    where
+        U: Serialize + DeserializeOwned + Send + Sync,
+        V: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Optimistically update a document, retrying on a revision conflict.
+    ///
+    /// The document is read, `f` is called with it to produce the patch,
+    /// and the patch is applied only if the document still has the revision
+    /// that was read.  If the document was changed in the meantime, it is
+    /// re-read and `f` is called again, up to `max_attempts` times in total.
+    /// The `_rev` is consumed to make the update conditional, so it is not
+    /// available to `T`.
+    ///
+    /// The last [`PreconditionFailed`](crate::Error::PreconditionFailed)
+    /// error is returned if every attempt conflicts, and a
+    /// [`ZeroMaxAttempts`](crate::Error::ZeroMaxAttempts) error if
+    /// `max_attempts` is zero.  With an asynchronous connection, only the
+    /// read is made, and its job is returned.
+    async fn update_with_retry<T, U, F>(
+        &self,
+        collection: &str,
+        key: &str,
+        max_attempts: usize,
+        f: F,
+    ) -> DocMetaResult<(), ()>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        U: Clone + Serialize + Send + Sync,
+        F: FnMut(&T) -> U + Send + Sync;
+
     /// Update the given data in the given documents
     async fn updates<T, U, V>(&self, config: UpdatesConfig<T>) -> DocMetaVecResult<U, V>
     where
@@ -87,4 +198,15 @@ pub trait Document {
         T: Serialize + Send + Sync,
         U: Serialize + DeserializeOwned + Send + Sync,
         V: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Deletes the documents in a collection that match the given filter,
+    /// returning the number of documents deleted
+    async fn delete_by_query(&self, config: DeleteByQueryConfig) -> ArangoResult<usize>;
+
+    /// Bulk import documents into a collection via the import API.  This is
+    /// considerably faster than [`creates`](Document::creates) for large
+    /// numbers of documents.
+    async fn import<T>(&self, config: ImportConfig<T>) -> ArangoResult<ImportResult>
+    where
+        T: Serialize + Send + Sync;
 }
@@ -15,17 +15,18 @@ use crate::{
             DeleteConfig, DeleteEdgeDefConfig, DeleteVertexCollConfig, DeleteVertexConfig,
             EdgeCreateConfig, EdgeDeleteConfig, EdgeReadConfig, EdgeReplaceConfig,
             EdgeUpdateConfig, ReadConfig, ReadEdgeDefsConfig, ReadVertexCollsConfig,
-            ReadVertexConfig, ReplaceEdgeDefConfig, UpdateVertexConfig,
+            ReadVertexConfig, ReplaceEdgeDefConfig, ShortestPathConfig, TraversalConfig,
+            UpdateVertexConfig,
         },
         output::{
-            CreateEdge, DeleteEdge, DeleteVertexMeta, EdgesMeta, GraphMeta, List, ReadEdge,
+            CreateEdge, DeleteEdge, DeleteVertexMeta, EdgesMeta, GraphMeta, List, Path, ReadEdge,
             ReadVertexMeta, ReplaceEdge, UpdateEdge, UpdateVertexMeta, VertexColls, VertexMeta,
         },
     },
     ArangoResult,
 };
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 /// Database Operations
 #[async_trait]
@@ -42,6 +43,10 @@ pub trait Graph {
     /// Create an edge definition
     async fn create_edge_def(&self, config: CreateEdgeDefConfig) -> ArangoResult<GraphMeta>;
     /// Read the edge definitions for the given graph
+    ///
+    /// The server only reports the edge collection names, so the graph is
+    /// also read to fill in the `from` and `to` vertex collections of each
+    /// [`edge_definitions`](crate::graph::output::EdgesMeta::edge_definitions) entry.
     async fn read_edge_defs(&self, config: ReadEdgeDefsConfig) -> ArangoResult<EdgesMeta>;
     /// Delete an edge definition
     async fn delete_edge_def(&self, config: DeleteEdgeDefConfig) -> ArangoResult<GraphMeta>;
@@ -90,4 +95,21 @@ pub trait Graph {
     ) -> ArangoResult<UpdateVertexMeta>
     where
         T: Serialize + Send + Sync;
+
+    /// Traverse a graph from the given start vertex, returning every path
+    /// between `min_depth` and `max_depth` long
+    async fn traverse<V, E>(&self, config: TraversalConfig) -> ArangoResult<Vec<Path<V, E>>>
+    where
+        V: Serialize + DeserializeOwned + Send + Sync,
+        E: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Find the shortest path between two vertices of a graph, returning
+    /// `None` if no such path exists
+    async fn shortest_path<V, E>(
+        &self,
+        config: ShortestPathConfig,
+    ) -> ArangoResult<Option<Path<V, E>>>
+    where
+        V: Serialize + DeserializeOwned + Send + Sync,
+        E: Serialize + DeserializeOwned + Send + Sync;
 }
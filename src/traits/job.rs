@@ -39,4 +39,24 @@ pub trait Job {
 
     /// Docs
     async fn jobs(&self, kind: &str) -> Result<Vec<String>>;
+
+    /// Returns the ids of the jobs that are still queued or executing
+    async fn list_pending(&self) -> Result<Vec<String>>;
+
+    /// Returns the ids of the jobs whose results are ready to fetch
+    async fn list_done(&self) -> Result<Vec<String>>;
+
+    /// Cancels the pending job with the given `id`.
+    ///
+    /// A [`JobNotCancellable`](crate::Error::JobNotCancellable) error is
+    /// returned if the job is no longer pending.
+    async fn cancel(&self, id: &str) -> Result<bool>;
+
+    /// Deletes the results of the jobs of the given `kind`
+    ///
+    /// * `all` deletes the results of all jobs
+    /// * `expired` deletes the results of all jobs created before the unix
+    /// timestamp given as the `stamp` query parameter, i.e. `expired?stamp=1234`
+    /// * any other value is treated as a job id, deleting that job's result
+    async fn delete_all_jobs(&self, kind: &str) -> Result<bool>;
 }
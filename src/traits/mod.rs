@@ -11,19 +11,27 @@
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
+mod admin;
+mod analyzer;
 mod coll;
 mod cursor;
 mod db;
 mod doc;
 mod graph;
 mod job;
+mod query;
+mod view;
 
+pub use admin::Admin;
+pub use analyzer::Analyzer;
 pub use coll::Collection;
 pub use cursor::Cursor;
 pub use db::Database;
 pub use doc::Document;
 pub use graph::Graph;
 pub use job::Job;
+pub use query::Query;
+pub use view::View;
 
 /// Job Information from an asynchronous invocation
 #[derive(Clone, Debug, Deserialize, Getters, Serialize)]
@@ -0,0 +1,31 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` query trait
+
+use crate::{
+    query::output::{Deleted, RunningQuery},
+    types::ArangoResult,
+};
+use async_trait::async_trait;
+
+/// AQL Query Operations
+#[async_trait]
+#[allow(unused_qualifications)]
+pub trait Query {
+    /// Retrieves the AQL queries currently running in the database
+    async fn current_queries(&self) -> ArangoResult<Vec<RunningQuery>>;
+    /// Retrieves the slow AQL queries of the database
+    async fn slow_queries(&self) -> ArangoResult<Vec<RunningQuery>>;
+    /// Clears the list of slow AQL queries of the database
+    async fn clear_slow_queries(&self) -> ArangoResult<Deleted>;
+    /// Kills the running AQL query with the given id.  If the query is not
+    /// found, i.e. it has already finished, a
+    /// [`QueryNotFound`](crate::Error::QueryNotFound) error is returned.
+    async fn kill_query(&self, id: &str) -> ArangoResult<Deleted>;
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` view trait
+
+use crate::{
+    common::output::Response,
+    types::ArangoResult,
+    view::{
+        input::CreateConfig,
+        output::{Properties, ViewMeta},
+    },
+};
+use async_trait::async_trait;
+
+/// `ArangoSearch` View Operations
+#[async_trait]
+#[allow(unused_qualifications)]
+pub trait View {
+    /// Creates an `arangosearch` view
+    async fn create(&self, config: CreateConfig) -> ArangoResult<ViewMeta>;
+    /// Lists all views in the database
+    async fn list(&self) -> ArangoResult<Response<Vec<ViewMeta>>>;
+    /// Reads the properties of the view with the given name
+    async fn properties(&self, name: &str) -> ArangoResult<Properties>;
+    /// Drops the view with the given name
+    async fn drop(&self, name: &str) -> ArangoResult<Response<bool>>;
+}
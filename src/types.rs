@@ -9,11 +9,13 @@
 //! Types
 
 use crate::{
+    error::RuarangoErr::AsyncJob,
     model::{common::output::ArangoErr, doc::output::DocMeta},
     traits::JobInfo,
 };
 use anyhow::Result;
 use libeither::Either;
+use std::collections::HashMap;
 
 /// Either [`JobInfo`](crate::traits::JobInfo) from an asynchronous invocation on the left
 /// or the result `T` from a synchronous invocation on the right
@@ -39,3 +41,119 @@ pub type ArangoVecResult<T> = ArangoResult<ArangoVec<T>>;
 
 /// Doc meta vector result
 pub type DocMetaVecResult<N, O> = ArangoResult<ArangoVec<DocMeta<N, O>>>;
+
+/// arango map, keyed by document `_key`
+pub type ArangoMap<T> = HashMap<String, std::result::Result<T, ArangoErr>>;
+
+/// arango map result
+pub type ArangoMapResult<T> = ArangoResult<ArangoMap<T>>;
+
+/// Convenience methods for [`ArangoEither`]
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use libeither::Either;
+/// # use ruarango::{ArangoEither, ArangoEitherExt, JobInfo};
+/// #
+/// # pub fn main() -> Result<()> {
+/// let sync: ArangoEither<usize> = Either::new_right(1);
+/// assert!(sync.job_id().is_none());
+/// assert_eq!(2, sync.map_sync(|x| x + 1).into_result()?);
+///
+/// let not_sync: ArangoEither<usize> =
+///     Either::new_left(JobInfo::new(202, Some("123456".to_string())));
+/// assert_eq!(Some("123456"), not_sync.job_id());
+/// assert!(not_sync.into_result().is_err());
+/// #   Ok(())
+/// # }
+/// ```
+pub trait ArangoEitherExt<T> {
+    /// Convert into the synchronous result, or an
+    /// [`AsyncJob`](crate::Error::AsyncJob) error if this is the
+    /// [`JobInfo`] of an asynchronous invocation
+    ///
+    /// # Errors
+    /// * If this is the [`JobInfo`] of an asynchronous invocation
+    fn into_result(self) -> Result<T>;
+
+    /// Map the synchronous result, leaving the [`JobInfo`] of an
+    /// asynchronous invocation untouched.  Unlike the fallible `map_right`
+    /// provided by [`Either`], this cannot fail.
+    fn map_sync<U, F>(self, f: F) -> ArangoEither<U>
+    where
+        F: FnOnce(T) -> U;
+
+    /// The job id of an asynchronous invocation, if any
+    fn job_id(&self) -> Option<&str>;
+}
+
+impl<T> ArangoEitherExt<T> for ArangoEither<T> {
+    fn into_result(self) -> Result<T> {
+        if self.is_left() {
+            let id = self.left_safe()?.id().clone();
+            Err(AsyncJob { id }.into())
+        } else {
+            Ok(self.right_safe()?)
+        }
+    }
+
+    fn map_sync<U, F>(self, f: F) -> ArangoEither<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        // An `ArangoEither` is always constructed as either left or right, so
+        // the invalid variant error from `map_right` cannot occur here.
+        self.map_right(f)
+            .unwrap_or_else(|_| unreachable!("invalid ArangoEither"))
+    }
+
+    fn job_id(&self) -> Option<&str> {
+        self.left_ref()
+            .ok()
+            .and_then(|job_info| job_info.id().as_deref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ArangoEither, ArangoEitherExt};
+    use crate::{error::RuarangoErr, traits::JobInfo};
+    use libeither::Either;
+
+    fn async_either() -> ArangoEither<usize> {
+        Either::new_left(JobInfo::new(202, Some("123456".to_string())))
+    }
+
+    #[test]
+    fn right_into_result() {
+        let either: ArangoEither<usize> = Either::new_right(1);
+        assert!(either.job_id().is_none());
+        assert_eq!(1, either.into_result().unwrap());
+    }
+
+    #[test]
+    fn left_into_result() {
+        let either = async_either();
+        assert_eq!(Some("123456"), either.job_id());
+        let err = either.into_result().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RuarangoErr>(),
+            Some(RuarangoErr::AsyncJob { id }) if id.as_deref() == Some("123456")
+        ));
+    }
+
+    #[test]
+    fn right_map_sync() {
+        let either: ArangoEither<usize> = Either::new_right(1);
+        let mapped = either.map_sync(|x| x.to_string());
+        assert_eq!("1", mapped.into_result().unwrap());
+    }
+
+    #[test]
+    fn left_map_sync() {
+        let mapped = async_either().map_sync(|x| x.to_string());
+        assert!(mapped.is_left());
+        assert_eq!(Some("123456"), mapped.job_id());
+    }
+}
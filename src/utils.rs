@@ -9,18 +9,28 @@
 //! `ruarango` utils
 
 use crate::{
+    builder::VELOCYPACK_MIME,
     error::RuarangoErr::{
-        BadRequest, Conflict, Cursor, Forbidden, InvalidBody, InvalidCursorResponse,
-        InvalidDocResponse, NotFound, NotModified, PreconditionFailed,
+        self, BadRequest, Cursor, Forbidden, InvalidBody, InvalidCursorResponse,
+        InvalidDocResponse, JobNotCancellable, NotFound, NotImplemented, NotModified,
+        PayloadTooLarge, PreconditionFailed, QueryNotFound,
     },
-    model::{common::output::ArangoErr, doc::output::DocErr, BaseErr},
-    JobInfo,
+    model::{
+        common::output::ArangoErr,
+        doc::output::{DocErr, DocHeader, RawDoc},
+        BaseErr,
+    },
+    vpack, JobInfo,
 };
 use anyhow::{anyhow, Result};
 use libeither::Either;
-use reqwest::{Error, StatusCode};
-use serde::de::DeserializeOwned;
+use reqwest::{
+    header::{CONTENT_TYPE, ETAG},
+    Error, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
+use std::io::{self, Write};
 
 #[cfg(test)]
 use {
@@ -77,56 +87,175 @@ macro_rules! add_qp {
     };
 }
 
-fn invalid_body(e: &serde_json::Error, text: &str) -> anyhow::Error {
+pub(crate) const DEFAULT_MAX_ERROR_BODY_LEN: usize = 1024;
+
+/// Counts the bytes written to it, without keeping them
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The length in bytes of `value` serialized as JSON
+pub(crate) fn json_len<T>(value: &T) -> Result<usize>
+where
+    T: Serialize + ?Sized,
+{
+    let mut counter = ByteCounter(0);
+    serde_json::to_writer(&mut counter, value)?;
+    Ok(counter.0)
+}
+
+/// The maximum length of a raw response body kept in an error, attached to
+/// every response by the connection
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MaxErrorBodyLen(pub(crate) usize);
+
+fn max_error_body_len(res: &reqwest::Response) -> usize {
+    res.extensions()
+        .get::<MaxErrorBodyLen>()
+        .map_or(DEFAULT_MAX_ERROR_BODY_LEN, |max_len| max_len.0)
+}
+
+fn truncate_body(text: &str, max_len: usize) -> String {
+    if text.len() <= max_len {
+        text.to_string()
+    } else {
+        let mut end = max_len;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &text[..end])
+    }
+}
+
+fn invalid_body<E>(e: E, text: &str, max_len: usize) -> anyhow::Error
+where
+    E: std::fmt::Display,
+{
     InvalidBody {
         err: format!("{e}"),
-        body: text.to_string(),
+        body: truncate_body(text, max_len),
     }
     .into()
 }
 
+/// Did the server send a `VelocyPack` body
+fn is_velocypack(res: &reqwest::Response) -> bool {
+    res.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .map_or(false, |content_type| {
+            content_type.starts_with(VELOCYPACK_MIME)
+        })
+}
+
+/// Decode a `VelocyPack` response body
+async fn velocypack_value(res: reqwest::Response, max_len: usize) -> Result<Value> {
+    let body = res.bytes().await?;
+    vpack::decode(&body).map_err(|e| invalid_body(e, &String::from_utf8_lossy(&body), max_len))
+}
+
+/// Read a response body as JSON text, decoding it first if the server sent
+/// `VelocyPack`
+async fn body_text(res: reqwest::Response) -> Result<String> {
+    if is_velocypack(&res) {
+        let max_len = max_error_body_len(&res);
+        Ok(velocypack_value(res, max_len).await?.to_string())
+    } else {
+        Ok(res.text().await?)
+    }
+}
+
 async fn handle_text<T>(res: reqwest::Response) -> Result<T>
 where
     T: DeserializeOwned,
 {
+    let max_len = max_error_body_len(&res);
+
+    if is_velocypack(&res) {
+        let value = velocypack_value(res, max_len).await?;
+        return T::deserialize(&value).map_err(|e| invalid_body(e, &value.to_string(), max_len));
+    }
+
     match res.text().await {
         Ok(text) => {
-            let invalid_body = |e: serde_json::Error| -> anyhow::Error { invalid_body(&e, &text) };
+            let invalid_body =
+                |e: serde_json::Error| -> anyhow::Error { invalid_body(e, &text, max_len) };
             serde_json::from_str::<T>(&text).map_err(invalid_body)
         }
         Err(e) => Err(e.into()),
     }
 }
 
+/// Read the body of an error response, keeping the raw body when it isn't
+/// the expected error
+async fn handle_err_text<E>(res: reqwest::Response) -> (Option<E>, Option<String>)
+where
+    E: DeserializeOwned,
+{
+    let max_len = max_error_body_len(&res);
+
+    match body_text(res).await {
+        Ok(text) => match serde_json::from_str::<E>(&text) {
+            Ok(err) => (Some(err), None),
+            Err(_) if text.is_empty() => (None, None),
+            Err(_) => (None, Some(truncate_body(&text, max_len))),
+        },
+        Err(_) => (None, None),
+    }
+}
+
 async fn handle_text_vec<T>(res: reqwest::Response) -> Result<Vec<Either<ArangoErr, T>>>
 where
     T: DeserializeOwned,
 {
-    match res.text().await {
-        Ok(text) => {
-            let invalid_body = |e: serde_json::Error| -> anyhow::Error { invalid_body(&e, &text) };
-            let body: Value = serde_json::from_str(&text).map_err(invalid_body)?;
-            let mut result: Vec<Either<ArangoErr, T>> = vec![];
-            match body {
-                Value::Array(v) => {
-                    for val in v {
-                        let doc_val = val.clone();
-                        let err_val = val.clone();
-                        match serde_json::from_value::<T>(doc_val) {
-                            Ok(doc) => result.push(Either::new_right(doc)),
-                            Err(_e) => match serde_json::from_value::<ArangoErr>(err_val) {
-                                Ok(doc_err) => result.push(Either::new_left(doc_err)),
-                                Err(_e) => {}
-                            },
-                        }
+    let max_len = max_error_body_len(&res);
+    let body: Value = if is_velocypack(&res) {
+        velocypack_value(res, max_len).await?
+    } else {
+        let text = res.text().await?;
+        serde_json::from_str(&text).map_err(|e| invalid_body(e, &text, max_len))?
+    };
+    let mut result: Vec<Either<ArangoErr, T>> = vec![];
+    match body {
+        Value::Array(v) => {
+            for val in v {
+                // Error entries may carry `_key`, `_id` and `_rev`,
+                // i.e. on a revision conflict, so check for them first
+                if val.get("error").and_then(Value::as_bool) == Some(true) {
+                    if let Ok(doc_err) = serde_json::from_value::<ArangoErr>(val) {
+                        result.push(Either::new_left(doc_err));
                     }
+                    continue;
+                }
+                let doc_val = val.clone();
+                let err_val = val.clone();
+                match serde_json::from_value::<T>(doc_val) {
+                    Ok(doc) => result.push(Either::new_right(doc)),
+                    Err(e) => match serde_json::from_value::<ArangoErr>(err_val) {
+                        Ok(doc_err) => result.push(Either::new_left(doc_err)),
+                        Err(_) => return Err(invalid_body(e, &val.to_string(), max_len)),
+                    },
                 }
-                _ => return Err(anyhow!("result was not an array!")),
             }
-            Ok(result)
         }
-        Err(e) => Err(e.into()),
+        body => {
+            return Err(invalid_body(
+                "result was not an array!",
+                &body.to_string(),
+                max_len,
+            ))
+        }
     }
+    Ok(result)
 }
 
 async fn to_json<T>(res: reqwest::Response) -> Result<T>
@@ -147,18 +276,21 @@ where
 
 async fn into_err(res: reqwest::Response) -> anyhow::Error {
     let status = res.status();
-    let err: Option<DocErr> = handle_text(res).await.ok();
+    let (err, body) = handle_err_text::<DocErr>(res).await;
 
     match status {
         StatusCode::BAD_REQUEST => BadRequest { err }.into(),
         StatusCode::FORBIDDEN => Forbidden { err }.into(),
         StatusCode::NOT_FOUND => NotFound { err }.into(),
         StatusCode::NOT_MODIFIED => NotModified.into(),
-        StatusCode::CONFLICT => Conflict { err }.into(),
-        StatusCode::PRECONDITION_FAILED => PreconditionFailed { err }.into(),
+        StatusCode::CONFLICT => RuarangoErr::conflict(err).into(),
+        StatusCode::PRECONDITION_FAILED => RuarangoErr::precondition_failed(err).into(),
+        StatusCode::PAYLOAD_TOO_LARGE => PayloadTooLarge { err }.into(),
+        StatusCode::NOT_IMPLEMENTED => NotImplemented { err }.into(),
         _ => InvalidDocResponse {
             status: status.as_u16(),
             err,
+            body,
         }
         .into(),
     }
@@ -181,6 +313,17 @@ where
     res.map(into_result)?.await
 }
 
+async fn into_text(res: reqwest::Response) -> Result<String> {
+    match res.status() {
+        StatusCode::OK => body_text(res).await,
+        _ => Err(into_err(res).await),
+    }
+}
+
+pub(crate) async fn text_resp(res: Result<reqwest::Response, Error>) -> Result<String> {
+    res.map(into_text)?.await
+}
+
 fn to_empty(res: reqwest::Response) -> Result<()> {
     res.error_for_status().map(|_| ()).map_err(Error::into)
 }
@@ -216,16 +359,20 @@ where
         StatusCode::NOT_MODIFIED => Err(NotModified.into()),
         StatusCode::CONFLICT => {
             let err: Option<DocErr> = handle_text(res).await.ok();
-            Err(Conflict { err }.into())
+            Err(RuarangoErr::conflict(err).into())
         }
         StatusCode::PRECONDITION_FAILED => {
             let err: Option<DocErr> = handle_text(res).await.ok();
-            Err(PreconditionFailed { err }.into())
+            Err(RuarangoErr::precondition_failed(err).into())
+        }
+        StatusCode::PAYLOAD_TOO_LARGE => {
+            let err: Option<DocErr> = handle_text(res).await.ok();
+            Err(PayloadTooLarge { err }.into())
         }
         _ => {
             let status = res.status().as_u16();
-            let err: Option<DocErr> = handle_text(res).await.ok();
-            Err(InvalidDocResponse { status, err }.into())
+            let (err, body) = handle_err_text::<DocErr>(res).await;
+            Err(InvalidDocResponse { status, err, body }.into())
         }
     }
 }
@@ -245,15 +392,20 @@ where
         StatusCode::NOT_MODIFIED => Err(NotModified.into()),
         StatusCode::CONFLICT => {
             let err: Option<DocErr> = handle_text(res).await.ok();
-            Err(Conflict { err }.into())
+            Err(RuarangoErr::conflict(err).into())
         }
         StatusCode::PRECONDITION_FAILED => {
             let err: Option<DocErr> = handle_text(res).await.ok();
-            Err(PreconditionFailed { err }.into())
+            Err(RuarangoErr::precondition_failed(err).into())
+        }
+        StatusCode::PAYLOAD_TOO_LARGE => {
+            let err: Option<DocErr> = handle_text(res).await.ok();
+            Err(PayloadTooLarge { err }.into())
         }
         _ => {
             let status = res.status().as_u16();
-            Err(InvalidDocResponse { status, err: None }.into())
+            let (err, body) = handle_err_text::<DocErr>(res).await;
+            Err(InvalidDocResponse { status, err, body }.into())
         }
     }
 }
@@ -265,6 +417,25 @@ where
     res.map(to_docmeta_json)?.await
 }
 
+pub(crate) async fn doc_raw_resp(
+    res: std::result::Result<reqwest::Response, Error>,
+) -> Result<RawDoc> {
+    let res = res?;
+
+    match res.status() {
+        StatusCode::OK if is_velocypack(&res) => {
+            let max_len = max_error_body_len(&res);
+            let value = velocypack_value(res, max_len).await?;
+            Ok(RawDoc::new(serde_json::to_vec(&value)?.into()))
+        }
+        StatusCode::OK => Ok(RawDoc::new(res.bytes().await?)),
+        _ => {
+            let _err: Value = to_docmeta_json(res).await?;
+            Err(anyhow!("Unexpected document response"))
+        }
+    }
+}
+
 pub(crate) async fn doc_vec_resp<T>(
     res: std::result::Result<reqwest::Response, Error>,
 ) -> Result<Vec<Either<ArangoErr, T>>>
@@ -274,6 +445,143 @@ where
     res.map(to_docmeta_vec_json)?.await
 }
 
+/// Map the single result of an `onlyget` read, which reports failures in
+/// the body rather than through the status code
+fn into_single_doc<T>(docs: Vec<Either<ArangoErr, T>>) -> Result<T> {
+    match docs.into_iter().next() {
+        Some(doc) if doc.is_right() => Ok(doc.right_safe()?),
+        Some(doc) => {
+            let arango_err = doc.left_safe()?;
+            match arango_err.error_num() {
+                1202 => Err(NotFound {
+                    err: Some(DocErr::from_arango_err(404, &arango_err)),
+                }
+                .into()),
+                1200 => Err(
+                    RuarangoErr::precondition_failed(Some(DocErr::from_arango_err(
+                        412,
+                        &arango_err,
+                    )))
+                    .into(),
+                ),
+                _ => Err(InvalidDocResponse {
+                    status: 200,
+                    err: Some(DocErr::from_arango_err(200, &arango_err)),
+                    body: None,
+                }
+                .into()),
+            }
+        }
+        None => Err(NotFound { err: None }.into()),
+    }
+}
+
+pub(crate) async fn doc_single_resp<T>(
+    res: std::result::Result<reqwest::Response, Error>,
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    res.map(to_docmeta_vec_json)?
+        .await
+        .and_then(into_single_doc)
+}
+
+fn strip_etag(etag: &str) -> &str {
+    etag.trim_start_matches("W/").trim_matches('"')
+}
+
+fn to_doc_header(res: &reqwest::Response, collection: &str, key: &str) -> Result<DocHeader> {
+    match res.status() {
+        StatusCode::OK => {
+            let rev = res
+                .headers()
+                .get(ETAG)
+                .ok_or_else(|| anyhow!("response is missing the 'etag' header!"))?
+                .to_str()?;
+            Ok(DocHeader::new(
+                format!("{collection}/{key}"),
+                key,
+                strip_etag(rev),
+            ))
+        }
+        StatusCode::NOT_FOUND => Err(NotFound { err: None }.into()),
+        StatusCode::NOT_MODIFIED => Err(NotModified.into()),
+        StatusCode::PRECONDITION_FAILED => {
+            let current_rev = res
+                .headers()
+                .get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(|etag| strip_etag(etag).to_string());
+            Err(PreconditionFailed {
+                err: None,
+                current_rev,
+            }
+            .into())
+        }
+        _ => {
+            let status = res.status().as_u16();
+            Err(InvalidDocResponse {
+                status,
+                err: None,
+                body: None,
+            }
+            .into())
+        }
+    }
+}
+
+#[allow(clippy::unused_async)]
+pub(crate) async fn doc_header_resp(
+    res: std::result::Result<reqwest::Response, Error>,
+    collection: String,
+    key: String,
+) -> Result<DocHeader> {
+    res.map(|res| to_doc_header(&res, &collection, &key))?
+}
+
+async fn to_kill_json<T>(res: reqwest::Response, id: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    match res.status() {
+        StatusCode::OK => Ok(handle_text(res).await?),
+        StatusCode::NOT_FOUND => Err(QueryNotFound { id: id.to_string() }.into()),
+        _ => Err(into_err(res).await),
+    }
+}
+
+pub(crate) async fn kill_resp<T>(
+    res: std::result::Result<reqwest::Response, Error>,
+    id: String,
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    res.map(|res| to_kill_json(res, &id))?.await
+}
+
+async fn to_cancel_json<T>(res: reqwest::Response, id: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    match res.status() {
+        StatusCode::OK => Ok(handle_text(res).await?),
+        StatusCode::BAD_REQUEST => Err(JobNotCancellable { id: id.to_string() }.into()),
+        _ => Err(into_err(res).await),
+    }
+}
+
+pub(crate) async fn cancel_resp<T>(
+    res: std::result::Result<reqwest::Response, Error>,
+    id: String,
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    res.map(|res| to_cancel_json(res, &id))?.await
+}
+
 async fn to_cursor_json<T>(res: reqwest::Response) -> Result<T>
 where
     T: DeserializeOwned,
@@ -285,8 +593,13 @@ where
             Err(Cursor { err }.into())
         }
         _ => {
+            // i.e. a query exceeding its memory limit fails with a 500, but
+            // still carries the `ArangoDB` error in the body
             let status = res.status().as_u16();
-            Err(InvalidCursorResponse { status }.into())
+            match handle_err_text::<BaseErr>(res).await {
+                (Some(err), _) => Err(Cursor { err: Some(err) }.into()),
+                (None, body) => Err(InvalidCursorResponse { status, body }.into()),
+            }
         }
     }
 }
@@ -549,14 +862,316 @@ pub(crate) mod mocks {
             Self: Sized;
     }
 
+    pub(crate) mod admin {
+        use crate::admin::output::{Status, Version};
+        use anyhow::Result;
+        use serde_json::json;
+        use wiremock::{
+            matchers::{body_json, method, path, query_param},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        mock_res!(
+            mock_version,
+            Version::default(),
+            "GET",
+            path("_db/keti/_api/version")
+        );
+
+        mock_res!(
+            mock_version_details,
+            json!({
+                "server": "arango",
+                "version": "3.11.0",
+                "license": "community",
+                "details": { "mode": "server", "architecture": "64bit" }
+            }),
+            "GET",
+            path("_db/keti/_api/version"),
+            query_param("details", "true")
+        );
+
+        mock_async!(mock_version_async, "GET", path("_db/keti/_api/version"));
+
+        mock_res!(
+            mock_status,
+            Status::default(),
+            "GET",
+            path("_db/keti/_admin/status")
+        );
+
+        mock_res!(
+            mock_log_level,
+            json!({ "general": "INFO", "queries": "INFO", "requests": "WARNING" }),
+            "GET",
+            path("_db/keti/_admin/log/level")
+        );
+
+        mock_res!(
+            mock_set_log_level,
+            json!({ "general": "INFO", "queries": "DEBUG", "requests": "WARNING" }),
+            "PUT",
+            path("_db/keti/_admin/log/level"),
+            body_json(json!({ "queries": "DEBUG" }))
+        );
+
+        pub(crate) async fn mock_metrics(mock_server: &MockServer) {
+            let body = "# HELP arangodb_scheduler_num_worker_threads Number of worker threads\n\
+                        # TYPE arangodb_scheduler_num_worker_threads gauge\n\
+                        arangodb_scheduler_num_worker_threads{role=\"SINGLE\"} 4\n";
+            Mock::given(method("GET"))
+                .and(path("_db/keti/_admin/metrics/v2"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_raw(body, "text/plain; version=0.0.4; charset=utf-8"),
+                )
+                .mount(mock_server)
+                .await;
+        }
+
+        mock_res!(
+            mock_echo,
+            json!({
+                "authorized": true,
+                "user": "root",
+                "isAdminUser": true,
+                "database": "keti",
+                "url": "/_admin/echo",
+                "protocol": "http",
+                "portType": "tcp/ip",
+                "prefix": "/_admin/echo",
+                "headers": {
+                    "host": "127.0.0.1:8529",
+                    "content-type": "application/json",
+                    "x-forwarded-for": "10.0.0.1"
+                },
+                "requestType": "POST",
+                "requestBody": "null",
+                "parameters": {},
+                "cookies": {},
+                "suffix": [],
+                "rawSuffix": [],
+                "path": "/"
+            }),
+            "POST",
+            path("_db/keti/_admin/echo")
+        );
+
+        mock_res!(
+            mock_log_level_forbidden,
+            json!({
+                "error": true,
+                "code": 403,
+                "errorNum": 11,
+                "errorMessage": "forbidden"
+            }),
+            403,
+            "GET",
+            path("_db/keti/_admin/log/level")
+        );
+
+        mock_res!(
+            mock_endpoints,
+            json!({
+                "error": false,
+                "code": 200,
+                "endpoints": [
+                    { "endpoint": "tcp://10.0.0.1:8529" },
+                    { "endpoint": "tcp://10.0.0.2:8529" }
+                ]
+            }),
+            "GET",
+            path("_db/keti/_api/cluster/endpoints")
+        );
+
+        mock_res!(
+            mock_endpoints_forbidden,
+            json!({
+                "error": true,
+                "code": 403,
+                "errorNum": 11,
+                "errorMessage": "this API is only available in a cluster"
+            }),
+            403,
+            "GET",
+            path("_db/keti/_api/cluster/endpoints")
+        );
+    }
+
+    pub(crate) mod query {
+        use crate::query::output::{Deleted, RunningQuery};
+        use anyhow::Result;
+        use serde_json::json;
+        use wiremock::{
+            matchers::{method, path},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        mock_res!(
+            mock_current_queries,
+            vec![RunningQuery::default()],
+            "GET",
+            path("_db/keti/_api/query/current")
+        );
+
+        mock_res!(
+            mock_kill_query,
+            Deleted::default(),
+            "DELETE",
+            path("_db/keti/_api/query/123")
+        );
+
+        mock_res!(
+            mock_kill_query_not_found,
+            json!({
+                "error": true,
+                "code": 404,
+                "errorNum": 1591,
+                "errorMessage": "cannot kill query '456': query not found"
+            }),
+            404,
+            "DELETE",
+            path("_db/keti/_api/query/456")
+        );
+    }
+
+    pub(crate) mod job {
+        use anyhow::Result;
+        use serde_json::json;
+        use wiremock::{
+            matchers::{method, path},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        mock_res!(
+            mock_list_pending,
+            json!(["123456", "123457"]),
+            "GET",
+            path("_db/keti/_api/job/pending")
+        );
+
+        mock_res!(
+            mock_cancel,
+            json!({ "result": true }),
+            "PUT",
+            path("_db/keti/_api/job/123456/cancel")
+        );
+
+        mock_res!(
+            mock_cancel_not_cancellable,
+            json!({
+                "error": true,
+                "code": 400,
+                "errorNum": 400,
+                "errorMessage": "bad parameter"
+            }),
+            400,
+            "PUT",
+            path("_db/keti/_api/job/123457/cancel")
+        );
+    }
+
+    pub(crate) mod analyzer {
+        use crate::analyzer::output::{AnalyzerMeta, Delete};
+        use anyhow::Result;
+        use serde_json::json;
+        use wiremock::{
+            matchers::{body_string_contains, method, path, query_param},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        mock_res!(
+            mock_create_analyzer,
+            AnalyzerMeta::default(),
+            201,
+            "POST",
+            path("_db/keti/_api/analyzer"),
+            body_string_contains("\"type\":\"text\"")
+        );
+
+        pub(crate) async fn mock_list_analyzers(mock_server: &MockServer) -> Result<()> {
+            let body = json!({
+                "error": false,
+                "code": 200,
+                "result": [AnalyzerMeta::default()]
+            });
+            Mock::given(method("GET"))
+                .and(path("_db/keti/_api/analyzer"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .up_to_n_times(1)
+                .mount(mock_server)
+                .await;
+            Ok(())
+        }
+
+        mock_res!(
+            mock_delete_analyzer,
+            Delete::default(),
+            "DELETE",
+            path("_db/keti/_api/analyzer/text_test"),
+            query_param("force", "true")
+        );
+
+        pub(crate) async fn mock_delete_analyzer_in_use(mock_server: &MockServer) {
+            let body = json!({
+                "error": true,
+                "code": 409,
+                "errorNum": 1212,
+                "errorMessage": "analyzer in use"
+            });
+            Mock::given(method("DELETE"))
+                .and(path("_db/keti/_api/analyzer/text_test"))
+                .respond_with(ResponseTemplate::new(409).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
+    }
+
+    pub(crate) mod view {
+        use crate::{
+            common::output::Response,
+            view::output::{Properties, ViewMeta},
+        };
+        use anyhow::Result;
+        use wiremock::{
+            matchers::{body_string_contains, method, path},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        mock_res!(
+            mock_create_view,
+            ViewMeta::default(),
+            201,
+            "POST",
+            path("_db/keti/_api/view"),
+            body_string_contains("arangosearch")
+        );
+
+        mock_res!(
+            mock_view_properties,
+            Properties::default(),
+            "GET",
+            path("_db/keti/_api/view/test_view/properties")
+        );
+
+        mock_res!(
+            mock_drop_view,
+            Response::<bool>::default(),
+            "DELETE",
+            path("_db/keti/_api/view/test_view")
+        );
+    }
+
     pub(crate) mod collection {
         use crate::{
             coll::output::{
                 Checksum, Collection, Collections, Count, Create, Drop, Figures, Load, LoadIndexes,
-                ModifyProps, RecalculateCount, Rename, Revision, Truncate, Unload,
+                ModifyProps, Properties, RecalculateCount, Rename, ResponsibleShard, Revision,
+                Truncate, Unload,
             },
             common::output::Response,
         };
+        use serde_json::json;
         use wiremock::{
             matchers::{body_string_contains, method, path, query_param},
             Mock, MockServer, ResponseTemplate,
@@ -569,6 +1184,82 @@ pub(crate) mod mocks {
             path("_db/keti/_api/collection/test_coll/unload")
         );
 
+        mock_x!(
+            mock_compact,
+            Collection,
+            "PUT",
+            path("_db/keti/_api/collection/keti/compact")
+        );
+
+        mock_x!(
+            mock_responsible_shard,
+            ResponsibleShard,
+            "PUT",
+            path("_db/keti/_api/collection/test_coll/responsibleShard"),
+            body_string_contains("test_key")
+        );
+
+        pub(crate) async fn mock_collection_counts(mock_server: &MockServer) {
+            let body = json!({
+                "result": [{ "name": "edges", "count": 3 }],
+                "hasMore": false,
+                "cached": false,
+                "code": 201,
+                "error": false
+            });
+            Mock::given(method("POST"))
+                .and(path("_db/keti/_api/cursor"))
+                .and(body_string_contains("COLLECTION_COUNT"))
+                .respond_with(ResponseTemplate::new(201).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
+
+        pub(crate) async fn mock_create_duplicate(mock_server: &MockServer) {
+            let body = json!({
+                "error": true,
+                "code": 409,
+                "errorNum": 1207,
+                "errorMessage": "duplicate name"
+            });
+            Mock::given(method("POST"))
+                .and(path("_db/keti/_api/collection"))
+                .and(body_string_contains("keti"))
+                .respond_with(ResponseTemplate::new(409).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
+
+        pub(crate) async fn mock_create_bad_request(mock_server: &MockServer) {
+            let body = json!({
+                "error": true,
+                "code": 400,
+                "errorNum": 1208,
+                "errorMessage": "illegal name"
+            });
+            Mock::given(method("POST"))
+                .and(path("_db/keti/_api/collection"))
+                .respond_with(ResponseTemplate::new(400).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
+
+        pub(crate) async fn mock_responsible_shard_single_server(mock_server: &MockServer) {
+            let body = json!({
+                "error": true,
+                "code": 501,
+                "errorNum": 9,
+                "errorMessage": "not implemented"
+            });
+            Mock::given(method("PUT"))
+                .and(path(
+                    "_db/keti/_api/collection/single_coll/responsibleShard",
+                ))
+                .respond_with(ResponseTemplate::new(501).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
+
         mock_async!(
             mock_collection_async,
             "GET",
@@ -640,6 +1331,19 @@ pub(crate) mod mocks {
             path("_db/keti/_api/collection/test_coll/loadIndexesIntoMemory")
         );
 
+        mock_async!(
+            mock_properties_async,
+            "GET",
+            path("_db/keti/_api/collection/test_coll/properties")
+        );
+
+        mock_x!(
+            mock_properties,
+            Properties,
+            "GET",
+            path("_db/keti/_api/collection/test_coll/properties")
+        );
+
         mock_x!(
             mock_modify_props,
             ModifyProps,
@@ -670,6 +1374,15 @@ pub(crate) mod mocks {
             path("_db/keti/_api/collection/test_coll/truncate")
         );
 
+        mock_x!(
+            mock_truncate_with,
+            Truncate,
+            "PUT",
+            path("_db/keti/_api/collection/test_coll/truncate"),
+            query_param("waitForSync", "true"),
+            query_param("compact", "true")
+        );
+
         mock_async!(
             mock_collections_async,
             "GET",
@@ -701,6 +1414,8 @@ pub(crate) mod mocks {
 
     pub(crate) mod db {
         use crate::{common::output::Response, db::output::Current};
+        use anyhow::Result;
+        use serde_json::json;
         use wiremock::{
             matchers::{body_string_contains, method, path},
             Mock, MockServer, ResponseTemplate,
@@ -719,6 +1434,26 @@ pub(crate) mod mocks {
             path("_db/keti/_api/database/current")
         );
 
+        mock_res!(
+            mock_engine,
+            json!({ "name": "rocksdb" }),
+            "GET",
+            path("_db/keti/_api/engine")
+        );
+
+        mock_res!(
+            mock_engine_indexes,
+            json!({
+                "name": "rocksdb",
+                "supports": {
+                    "aliases": { "indexes": { "hash": "persistent", "skiplist": "persistent" } },
+                    "indexes": ["primary", "edge", "persistent", "geo", "fulltext", "ttl"]
+                }
+            }),
+            "GET",
+            path("_db/keti/_api/engine")
+        );
+
         mock_async!(mock_user_async, "GET", path("_db/keti/_api/database/user"));
 
         mock_x!(
@@ -737,6 +1472,19 @@ pub(crate) mod mocks {
             path("_api/database")
         );
 
+        mock_res!(
+            mock_list_forbidden,
+            json!({
+                "error": true,
+                "code": 403,
+                "errorNum": 11,
+                "errorMessage": "forbidden"
+            }),
+            403,
+            "GET",
+            path("_api/database")
+        );
+
         mock_x!(
             mock_create,
             Response::<bool>,
@@ -752,14 +1500,41 @@ pub(crate) mod mocks {
             "DELETE",
             path("_api/database/test_db")
         );
+
+        mock_async!(
+            mock_create_async,
+            "POST",
+            path("_api/database"),
+            body_string_contains("test_db")
+        );
+
+        mock_async!(mock_drop_async, "DELETE", path("_api/database/test_db"));
+
+        mock_x!(
+            mock_create_job,
+            Response::<bool>,
+            201 => with_set,
+            "PUT",
+            path("_api/job/123456")
+        );
+
+        mock_x!(
+            mock_drop_job,
+            Response::<bool>,
+            "PUT",
+            path("_api/job/123456")
+        );
     }
 
     pub(crate) mod doc {
         use super::Mock as RuarangoMock;
-        use crate::doc::output::{CreateMockKind, DocMeta, OutputDoc, ReadMockKind};
+        use crate::doc::output::{CreateMockKind, DocMeta, ImportResult, OutputDoc, ReadMockKind};
         use anyhow::Result;
+        use serde_json::json;
         use wiremock::{
-            matchers::{body_string_contains, header_exists, method, path, query_param},
+            matchers::{
+                body_string, body_string_contains, header, header_exists, method, path, query_param,
+            },
             Mock, MockServer, ResponseTemplate,
         };
 
@@ -772,6 +1547,16 @@ pub(crate) mod mocks {
             body_string_contains("test")
         );
 
+        mock_res!(
+            mock_create_raw,
+            DocMeta::<(), ()>::default(),
+            201,
+            "POST",
+            path("_db/keti/_api/document/test_coll"),
+            header("content-type", "application/json"),
+            body_string(r#"{"_key":"abc","test":"raw"}"#)
+        );
+
         mock_res!(
             mock_create_1,
             DocMeta::<(), ()>::try_mock(CreateMockKind::FirstCreate)?,
@@ -813,6 +1598,92 @@ pub(crate) mod mocks {
             "GET",
             path("_db/keti/_api/document/test_coll/test_doc")
         );
+        pub(crate) async fn mock_read_header(mock_server: &MockServer) -> Result<()> {
+            let mock_response = ResponseTemplate::new(200).insert_header("etag", "\"_cIw-YT6---\"");
+
+            Mock::given(method("HEAD"))
+                .and(path("_db/keti/_api/document/test_coll/test_doc"))
+                .respond_with(mock_response)
+                .up_to_n_times(1)
+                .mount(mock_server)
+                .await;
+            Ok(())
+        }
+        mock_res!(
+            mock_read_by,
+            vec![OutputDoc::try_mock(ReadMockKind::Found)?],
+            "PUT",
+            path("_db/keti/_api/document/test_coll"),
+            query_param("onlyget", "true"),
+            body_string_contains("test_doc")
+        );
+        mock_res!(
+            mock_read_by_rev_mismatch,
+            json!([{
+                "error": true,
+                "errorNum": 1200,
+                "errorMessage": "conflict, _rev values do not match"
+            }]),
+            "PUT",
+            path("_db/keti/_api/document/test_coll"),
+            query_param("onlyget", "true"),
+            body_string_contains("bad_rev")
+        );
+        mock_res!(
+            mock_deletes_rev_mismatch,
+            json!([
+                { "_key": "abc", "_id": "test_coll/abc", "_rev": "_fresh" },
+                {
+                    "error": true,
+                    "errorNum": 1200,
+                    "errorMessage": "conflict, _rev values do not match",
+                    "_key": "def",
+                    "_id": "test_coll/def",
+                    "_rev": "_current"
+                }
+            ]),
+            202,
+            "DELETE",
+            path("_db/keti/_api/document/test_coll"),
+            query_param("ignoreRevs", "false"),
+            body_string_contains("_stale")
+        );
+        mock_res!(
+            mock_reads,
+            json!([
+                { "_key": "a", "_id": "test_coll/a", "_rev": "_a", "test": "a" },
+                { "error": true, "errorNum": 1202, "errorMessage": "document not found" },
+                { "_key": "c", "_id": "test_coll/c", "_rev": "_c", "test": "c" }
+            ]),
+            200,
+            "PUT",
+            path("_db/keti/_api/document/test_coll"),
+            query_param("onlyget", "true")
+        );
+        mock_res!(
+            mock_updates_silent,
+            json!([]),
+            202,
+            "PATCH",
+            path("_db/keti/_api/document/test_coll"),
+            query_param("silent", "true")
+        );
+        mock_res!(
+            mock_deletes_silent,
+            json!([]),
+            202,
+            "DELETE",
+            path("_db/keti/_api/document/test_coll"),
+            query_param("silent", "true")
+        );
+        mock_res!(
+            mock_read_merged_headers,
+            OutputDoc::try_mock(ReadMockKind::Found)?,
+            "GET",
+            path("_db/keti/_api/document/test_coll/test_doc"),
+            header("x-trace-id", "connection"),
+            header("x-arango-allow-dirty-read", "true")
+        );
         mock_res!(
             mock_read_if_match,
             OutputDoc::try_mock(ReadMockKind::Found)?,
@@ -820,12 +1691,25 @@ pub(crate) mod mocks {
             path("_db/keti/_api/document/test_coll/test_doc"),
             header_exists("if-match")
         );
+        mock_res!(
+            mock_import,
+            ImportResult::default(),
+            201,
+            "POST",
+            path("_db/keti/_api/import"),
+            query_param("collection", "test_coll"),
+            query_param("type", "documents"),
+            header("content-type", "text/plain"),
+            body_string_contains("\n")
+        );
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::prepend_sep;
+    use super::{json_len, prepend_sep, strip_etag};
+    use anyhow::Result;
+    use serde_json::json;
 
     #[test]
     fn has_no_qp() {
@@ -838,4 +1722,18 @@ mod test {
         let mut result = String::new();
         assert_eq!("&", prepend_sep(&mut result, true));
     }
+
+    #[test]
+    fn etag_quotes_are_stripped() {
+        assert_eq!("_cIw-YT6---", strip_etag("\"_cIw-YT6---\""));
+        assert_eq!("_cIw-YT6---", strip_etag("W/\"_cIw-YT6---\""));
+        assert_eq!("_cIw-YT6---", strip_etag("_cIw-YT6---"));
+    }
+
+    #[test]
+    fn json_len_matches_serialized_len() -> Result<()> {
+        let value = json!([{ "_key": "abc", "test": "ünïcode" }, { "test": 1 }]);
+        assert_eq!(serde_json::to_vec(&value)?.len(), json_len(&value)?);
+        Ok(())
+    }
 }
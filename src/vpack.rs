@@ -0,0 +1,450 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! A `VelocyPack` codec for JSON values
+//!
+//! Values are encoded with the compact array and object types, which the
+//! server accepts anywhere.  Decoding covers the types the server sends for
+//! JSON compatible data, including indexed arrays and objects and the
+//! translated attribute names, i.e. `_key`.  Binary blobs, BCD numbers and
+//! custom types have no JSON equivalent and are rejected.
+
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Number, Value};
+use std::convert::TryFrom;
+
+/// The attribute names the server may send as small integers in place of an
+/// object key
+const TRANSLATED_KEYS: [&str; 5] = ["_key", "_rev", "_id", "_from", "_to"];
+
+/// Encode a JSON value as `VelocyPack`
+pub(crate) fn encode(value: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_into(value, &mut out);
+    out
+}
+
+/// Decode a single `VelocyPack` value into a JSON value
+pub(crate) fn decode(buf: &[u8]) -> Result<Value> {
+    let (value, end) = decode_at(buf, 0)?;
+    if end == buf.len() {
+        Ok(value)
+    } else {
+        Err(anyhow!(
+            "Invalid VelocyPack: {} trailing bytes",
+            buf.len() - end
+        ))
+    }
+}
+
+fn encode_into(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.push(0x18),
+        Value::Bool(false) => out.push(0x19),
+        Value::Bool(true) => out.push(0x1a),
+        Value::Number(number) => encode_number(number, out),
+        Value::String(s) => encode_string(s, out),
+        Value::Array(values) if values.is_empty() => out.push(0x01),
+        Value::Array(values) => {
+            let mut items = Vec::new();
+            for value in values {
+                encode_into(value, &mut items);
+            }
+            encode_compact(0x13, &items, values.len(), out);
+        }
+        Value::Object(map) if map.is_empty() => out.push(0x0a),
+        Value::Object(map) => {
+            let mut items = Vec::new();
+            for (key, value) in map {
+                encode_string(key, &mut items);
+                encode_into(value, &mut items);
+            }
+            encode_compact(0x14, &items, map.len(), out);
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn encode_number(number: &Number, out: &mut Vec<u8>) {
+    if let Some(u) = number.as_u64() {
+        if u <= 9 {
+            out.push(0x30 + u as u8);
+        } else {
+            let len = byte_len(u);
+            out.push(0x27 + len as u8);
+            out.extend_from_slice(&u.to_le_bytes()[..len]);
+        }
+    } else if let Some(i) = number.as_i64() {
+        if i >= -6 {
+            // -6 to -1 are 0x3a to 0x3f
+            out.push((0x40 + i) as u8);
+        } else {
+            let len = (1..8).find(|len| i >= -(1 << (len * 8 - 1))).unwrap_or(8);
+            out.push(0x1f + len as u8);
+            out.extend_from_slice(&i.to_le_bytes()[..len]);
+        }
+    } else {
+        out.push(0x1b);
+        out.extend_from_slice(&number.as_f64().unwrap_or_default().to_le_bytes());
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn encode_string(s: &str, out: &mut Vec<u8>) {
+    let bytes = s.as_bytes();
+    if bytes.len() <= 126 {
+        out.push(0x40 + bytes.len() as u8);
+    } else {
+        out.push(0xbf);
+        out.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    }
+    out.extend_from_slice(bytes);
+}
+
+/// Write a compact array or object, i.e. the head, the total byte length as
+/// a `VarUInt`, the items, and then the item count as a reversed `VarUInt`
+fn encode_compact(head: u8, items: &[u8], count: usize, out: &mut Vec<u8>) {
+    let mut count_bytes = var_uint(count as u64);
+    count_bytes.reverse();
+
+    // The byte length includes its own encoding
+    let base = 1 + items.len() + count_bytes.len();
+    let mut len = base + 1;
+    while base + var_uint(len as u64).len() != len {
+        len = base + var_uint(len as u64).len();
+    }
+
+    out.push(head);
+    out.extend_from_slice(&var_uint(len as u64));
+    out.extend_from_slice(items);
+    out.extend_from_slice(&count_bytes);
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn var_uint(mut value: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// The minimum number of bytes needed to hold `value`
+fn byte_len(value: u64) -> usize {
+    (1..8).find(|len| value >> (len * 8) == 0).unwrap_or(8)
+}
+
+fn take(buf: &[u8], pos: usize, len: usize) -> Result<&[u8]> {
+    pos.checked_add(len)
+        .and_then(|end| buf.get(pos..end))
+        .ok_or_else(|| anyhow!("Invalid VelocyPack: unexpected end of input"))
+}
+
+fn read_uint(buf: &[u8], pos: usize, len: usize) -> Result<u64> {
+    Ok(take(buf, pos, len)?
+        .iter()
+        .rev()
+        .fold(0, |acc, byte| (acc << 8) | u64::from(*byte)))
+}
+
+fn read_usize(buf: &[u8], pos: usize, len: usize) -> Result<usize> {
+    Ok(usize::try_from(read_uint(buf, pos, len)?)?)
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn read_int(buf: &[u8], pos: usize, len: usize) -> Result<i64> {
+    // Sign extend from the top bit of the last byte
+    let shift = 64 - len * 8;
+    Ok(((read_uint(buf, pos, len)? << shift) as i64) >> shift)
+}
+
+/// Read a `VarUInt`, returning the value and the position after it
+fn read_var_uint(buf: &[u8], mut pos: usize) -> Result<(usize, usize)> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *take(buf, pos, 1)?.first().unwrap_or(&0);
+        if shift > 56 {
+            return Err(anyhow!("Invalid VelocyPack: VarUInt overflow"));
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        pos += 1;
+        if byte & 0x80 == 0 {
+            return Ok((usize::try_from(value)?, pos));
+        }
+        shift += 7;
+    }
+}
+
+/// Read a reversed `VarUInt` ending just before `end`
+fn read_reversed_var_uint(buf: &[u8], end: usize) -> Result<usize> {
+    let mut value = 0;
+    let mut shift = 0;
+    let mut pos = end;
+    loop {
+        pos = pos
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("Invalid VelocyPack: unexpected end of input"))?;
+        let byte = *take(buf, pos, 1)?.first().unwrap_or(&0);
+        if shift > 56 {
+            return Err(anyhow!("Invalid VelocyPack: VarUInt overflow"));
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(usize::try_from(value)?);
+        }
+        shift += 7;
+    }
+}
+
+/// Skip the zero padding the server may add between the header of an array
+/// or object and its first item
+fn skip_padding(buf: &[u8], mut pos: usize, end: usize) -> usize {
+    while pos < end && buf.get(pos) == Some(&0) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Decode the value starting at `pos`, returning it and the position after it
+fn decode_at(buf: &[u8], pos: usize) -> Result<(Value, usize)> {
+    let head = *take(buf, pos, 1)?.first().unwrap_or(&0);
+
+    match head {
+        0x01 => Ok((Value::Array(Vec::new()), pos + 1)),
+        0x02..=0x05 => {
+            let width = 1 << (head - 0x02);
+            let end = pos + read_usize(buf, pos + 1, width)?;
+            let mut item = skip_padding(buf, pos + 1 + width, end);
+            let mut values = Vec::new();
+            while item < end {
+                let (value, next) = decode_at(buf, item)?;
+                values.push(value);
+                item = next;
+            }
+            Ok((Value::Array(values), end))
+        }
+        0x06..=0x09 => {
+            let (count, first, end) = indexed(buf, pos, head - 0x06, head == 0x09)?;
+            decode_items(buf, first, count).map(|values| (Value::Array(values), end))
+        }
+        0x0a => Ok((Value::Object(Map::new()), pos + 1)),
+        0x0b..=0x12 => {
+            let width_bits = (head - 0x0b) % 4;
+            let (count, first, end) = indexed(buf, pos, width_bits, width_bits == 3)?;
+            decode_pairs(buf, first, count).map(|map| (Value::Object(map), end))
+        }
+        0x13 | 0x14 => {
+            let (len, first) = read_var_uint(buf, pos + 1)?;
+            let end = pos + len;
+            if end <= first {
+                return Err(anyhow!("Invalid VelocyPack: bad compact byte length"));
+            }
+            let _ = take(buf, pos, len)?;
+            let count = read_reversed_var_uint(buf, end)?;
+            if head == 0x13 {
+                decode_items(buf, first, count).map(|values| (Value::Array(values), end))
+            } else {
+                decode_pairs(buf, first, count).map(|map| (Value::Object(map), end))
+            }
+        }
+        0x18 => Ok((Value::Null, pos + 1)),
+        0x19 => Ok((Value::Bool(false), pos + 1)),
+        0x1a => Ok((Value::Bool(true), pos + 1)),
+        0x1b => {
+            let double = f64::from_bits(read_uint(buf, pos + 1, 8)?);
+            Ok((Value::from(double), pos + 9))
+        }
+        // UTC date, as milliseconds since the epoch
+        0x1c => Ok((Value::from(read_int(buf, pos + 1, 8)?), pos + 9)),
+        0x20..=0x27 => {
+            let len = usize::from(head - 0x1f);
+            Ok((Value::from(read_int(buf, pos + 1, len)?), pos + 1 + len))
+        }
+        0x28..=0x2f => {
+            let len = usize::from(head - 0x27);
+            Ok((Value::from(read_uint(buf, pos + 1, len)?), pos + 1 + len))
+        }
+        0x30..=0x39 => Ok((Value::from(head - 0x30), pos + 1)),
+        0x3a..=0x3f => Ok((Value::from(i64::from(head) - 0x40), pos + 1)),
+        0x40..=0xbe => {
+            let len = usize::from(head - 0x40);
+            decode_str(buf, pos + 1, len).map(|s| (Value::String(s), pos + 1 + len))
+        }
+        0xbf => {
+            let len = read_usize(buf, pos + 1, 8)?;
+            decode_str(buf, pos + 9, len).map(|s| (Value::String(s), pos + 9 + len))
+        }
+        _ => Err(anyhow!("Unsupported VelocyPack type 0x{head:02x}")),
+    }
+}
+
+/// Read the header of an indexed array or object, returning the item count,
+/// the position of the first item and the position after the value.  The
+/// 8 byte wide variants keep the item count at the end.
+fn indexed(
+    buf: &[u8],
+    pos: usize,
+    width_bits: u8,
+    count_at_end: bool,
+) -> Result<(usize, usize, usize)> {
+    let width = 1 << width_bits;
+    let len = read_usize(buf, pos + 1, width)?;
+    let end = pos + len;
+    let _ = take(buf, pos, len)?;
+    let (count, header_end) = if count_at_end {
+        let count_pos = end
+            .checked_sub(8)
+            .ok_or_else(|| anyhow!("Invalid VelocyPack: bad byte length"))?;
+        (read_usize(buf, count_pos, 8)?, pos + 1 + width)
+    } else {
+        (
+            read_usize(buf, pos + 1 + width, width)?,
+            pos + 1 + 2 * width,
+        )
+    };
+    Ok((count, skip_padding(buf, header_end, end), end))
+}
+
+fn decode_items(buf: &[u8], mut pos: usize, count: usize) -> Result<Vec<Value>> {
+    let mut values = Vec::with_capacity(count.min(buf.len()));
+    for _ in 0..count {
+        let (value, next) = decode_at(buf, pos)?;
+        values.push(value);
+        pos = next;
+    }
+    Ok(values)
+}
+
+fn decode_pairs(buf: &[u8], mut pos: usize, count: usize) -> Result<Map<String, Value>> {
+    let mut map = Map::new();
+    for _ in 0..count {
+        let (key, next) = decode_at(buf, pos)?;
+        let key = match key {
+            Value::String(key) => key,
+            Value::Number(id) => id
+                .as_u64()
+                .and_then(|id| usize::try_from(id).ok())
+                .and_then(|id| id.checked_sub(1))
+                .and_then(|idx| TRANSLATED_KEYS.get(idx))
+                .ok_or_else(|| anyhow!("Invalid VelocyPack: unknown attribute id {id}"))?
+                .to_string(),
+            _ => return Err(anyhow!("Invalid VelocyPack: object key is not a string")),
+        };
+        let (value, next) = decode_at(buf, next)?;
+        let _old = map.insert(key, value);
+        pos = next;
+    }
+    Ok(map)
+}
+
+fn decode_str(buf: &[u8], pos: usize, len: usize) -> Result<String> {
+    Ok(std::str::from_utf8(take(buf, pos, len)?)?.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, encode};
+    use anyhow::Result;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn scalars_round_trip() -> Result<()> {
+        for value in [
+            json!(null),
+            json!(true),
+            json!(false),
+            json!(0),
+            json!(9),
+            json!(10),
+            json!(255),
+            json!(256),
+            json!(u64::MAX),
+            json!(-1),
+            json!(-6),
+            json!(-7),
+            json!(-128),
+            json!(-129),
+            json!(i64::MIN),
+            json!(1.5),
+            json!(""),
+            json!("test"),
+            json!("x".repeat(127)),
+        ] {
+            assert_eq!(value, decode(&encode(&value))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn compound_round_trip() -> Result<()> {
+        let value = json!({
+            "_key": "test_key",
+            "empty_array": [],
+            "empty_object": {},
+            "nested": { "a": [1, -2, 3.5, "four", null], "b": { "c": true } },
+            "long": "y".repeat(300),
+            "many": (0..200).collect::<Vec<usize>>(),
+        });
+        assert_eq!(value, decode(&encode(&value))?);
+        Ok(())
+    }
+
+    #[test]
+    fn known_encodings() {
+        assert_eq!(vec![0x30], encode(&json!(0)));
+        assert_eq!(vec![0x3f], encode(&json!(-1)));
+        assert_eq!(vec![0x28, 0x0a], encode(&json!(10)));
+        assert_eq!(vec![0x20, 0x80], encode(&json!(-128)));
+        assert_eq!(vec![0x43, b'a', b'b', b'c'], encode(&json!("abc")));
+        assert_eq!(
+            vec![0x13, 0x06, 0x31, 0x32, 0x33, 0x03],
+            encode(&json!([1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn decodes_indexed_types() -> Result<()> {
+        // [1, 2, 3] without an index table
+        assert_eq!(json!([1, 2, 3]), decode(&[0x02, 0x05, 0x31, 0x32, 0x33])?);
+        // [1, 2, 3] without an index table, padded to 8 bytes
+        let padded = [0x05, 0x0c, 0, 0, 0, 0, 0, 0, 0, 0x31, 0x32, 0x33];
+        assert_eq!(json!([1, 2, 3]), decode(&padded)?);
+        // [1, "a"] with an index table
+        let indexed = [0x06, 0x08, 0x02, 0x31, 0x41, b'a', 0x03, 0x04];
+        assert_eq!(json!([1, "a"]), decode(&indexed)?);
+        // { "b": 1, "a": 2 } with a sorted index table
+        let object = [
+            0x0b, 0x0b, 0x02, 0x41, b'b', 0x31, 0x41, b'a', 0x32, 0x06, 0x03,
+        ];
+        let value: Value = decode(&object)?;
+        assert_eq!(json!({ "a": 2, "b": 1 }), value);
+        Ok(())
+    }
+
+    #[test]
+    fn decodes_translated_keys() -> Result<()> {
+        // { _key: "k", _rev: "r" } with the keys sent as attribute ids
+        let object = [0x14, 0x09, 0x31, 0x41, b'k', 0x32, 0x41, b'r', 0x02];
+        assert_eq!(json!({ "_key": "k", "_rev": "r" }), decode(&object)?);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_input_errors() {
+        assert!(decode(&[]).is_err());
+        assert!(decode(&[0x43, b'a']).is_err());
+        assert!(decode(&[0xc0, 0x01, 0x00]).is_err());
+        assert!(decode(&[0x30, 0x30]).is_err());
+    }
+}
@@ -1,6 +1,15 @@
 macro_rules! int_test_sync_new {
     () => {};
     ($res:ident; $conn:ident; $code:literal; $pool:expr; $name:ident, $api:ident($($args:expr),*) => $asserts: block) => {
+        int_test_sync_new!($res; $conn; $pool; $name, $api($($args),*) => {
+            assert_eq!(*$res.code(), $code);
+            $asserts
+        });
+    };
+    ($res:ident; $conn:ident; $code:literal; $($tail:tt)*) => {
+        int_test_sync_new!($res; $conn; $code; $crate::pool::RUARANGO_POOL; $($tail)*);
+    };
+    ($res:ident; $conn:ident; $pool:expr; $name:ident, $api:ident($($args:expr),*) => $asserts: block) => {
         #[tokio::test]
         async fn $name() -> Result<()> {
             let $conn = &*$pool.get()?;
@@ -11,14 +20,8 @@ macro_rules! int_test_sync_new {
             Ok(())
         }
     };
-    ($res:ident; $conn:ident; $code:literal; $pool:expr; $($tail:tt)*) => {
-        int_test_sync_new!($res; $conn; $code; $pool; $($tail)*);
-    };
-    ($res:ident; $conn:ident; $code:literal; $($tail:tt)*) => {
-        int_test_sync_new!($res; $conn; $code; $crate::pool::RUARANGO_POOL; $($tail)*);
-    };
     ($res:ident; $conn:ident; $($tail:tt)*) => {
-        int_test_sync_new!($res; $conn; 200; $($tail)*);
+        int_test_sync_new!($res; $conn; $crate::pool::RUARANGO_POOL; $($tail)*);
     };
     ($res:ident; $($tail:tt)*) => {
         int_test_sync_new!($res; conn; $($tail)*);
@@ -47,8 +47,10 @@ impl ManageConnection for RuarangoPool {
             .map_err(|_e| Error::NotModified)
     }
 
-    fn is_valid(&self, _conn: &mut Self::Connection) -> Result<(), Self::Error> {
-        Ok(())
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        (*RUNTIME)
+            .block_on(conn.ping())
+            .map_err(|_e| Error::NotModified)
     }
 
     fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
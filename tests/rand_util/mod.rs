@@ -2,7 +2,10 @@ use anyhow::Result;
 use getset::Getters;
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
 use ruarango::{
-    coll::{input::ConfigBuilder, output::Create},
+    coll::{
+        input::{ConfigBuilder, DropConfig},
+        output::Create,
+    },
     doc::{
         input::{
             CreateConfigBuilder as DocCreateConfigBuilder,
@@ -87,7 +90,7 @@ pub async fn delete_random_collection<T>(conn: &Connection, name: T) -> Result<(
 where
     T: Into<String>,
 {
-    let res = conn.drop(&name.into(), false).await?;
+    let res = conn.drop_with(&name.into(), DropConfig::default()).await?;
     assert!(res.is_right());
     let dropped = res.right_safe()?;
     assert!(!dropped.error());
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use ruarango::{
+    admin::output::{Status, Version},
+    Admin,
+};
+
+int_test_async_new!(res; Version; admin_version_async, version(false) => {
+    assert_eq!(res.server(), "arango");
+    assert!(!res.version().is_empty());
+    assert!(res.details().is_none());
+});
+
+int_test_sync_new!(res; admin_version, version(false) => {
+    assert_eq!(res.server(), "arango");
+    assert!(!res.version().is_empty());
+    assert!(res.details().is_none());
+});
+
+int_test_sync_new!(res; admin_version_details, version(true) => {
+    assert_eq!(res.server(), "arango");
+    assert!(res.details().is_some());
+});
+
+int_test_async_new!(res; Status; admin_status_async, status() => {
+    assert_eq!(res.server(), "arango");
+    assert!(!res.version().is_empty());
+});
+
+int_test_sync_new!(res; admin_status, status() => {
+    assert_eq!(res.server(), "arango");
+    assert!(!res.version().is_empty());
+});
+
+int_test_sync_new!(res; admin_echo, echo() => {
+    assert_eq!(res.request_type(), "POST");
+    assert!(res.url().ends_with("_admin/echo"));
+    assert!(!res.database().is_empty());
+    assert!(res.headers().contains_key("host"));
+});
@@ -0,0 +1,44 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` analyzer integration tests
+use crate::{common::process_sync_result, rand_util::rand_name};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use ruarango::{
+    analyzer::{
+        input::{AnalyzerConfig, AnalyzerConfigBuilder},
+        AnalyzerKind, Feature,
+    },
+    Analyzer,
+};
+use serde_json::json;
+
+lazy_static! {
+    static ref ANALYZER_NAME: String = rand_name();
+}
+
+fn create_config() -> Result<AnalyzerConfig> {
+    Ok(AnalyzerConfigBuilder::default()
+        .name(&*ANALYZER_NAME)
+        .kind(AnalyzerKind::Text)
+        .properties(json!({ "locale": "en", "stemming": true }))
+        .features(vec![Feature::Frequency, Feature::Norm, Feature::Position])
+        .build()?)
+}
+
+int_test_sync_new!(res; conn; analyzer_create_list_delete, create(create_config()?) => {
+    assert!(res.name().ends_with(&*ANALYZER_NAME));
+    assert_eq!(*res.kind(), AnalyzerKind::Text);
+
+    let analyzers = process_sync_result(conn.list().await?)?;
+    assert!(analyzers.result().iter().any(|a| a.name() == res.name()));
+
+    let res = process_sync_result(conn.delete(&ANALYZER_NAME, true).await?)?;
+    assert!(!res.error());
+});
@@ -9,13 +9,18 @@
 //! `ruarango` integration tests
 use crate::{
     common::{process_async_result, process_sync_result},
-    rand_util::rand_name,
+    pool::RUARANGO_POOL,
+    rand_util::{create_random_document, delete_random_collection, rand_name},
 };
 use anyhow::Result;
+use futures::TryStreamExt;
 use lazy_static::lazy_static;
 use ruarango::{
     coll::{
-        input::{Config, ConfigBuilder, Props, PropsBuilder},
+        input::{
+            AllConfigBuilder, ComputeOn, ComputedValueBuilder, Config, ConfigBuilder, DropConfig,
+            ExportConfigBuilder, Props, PropsBuilder, Restrict,
+        },
         output::{
             Checksum, Collection as Coll, Collections, Count, Create, Figures, Load, LoadIndexes,
             ModifyProps, RecalculateCount, Revision,
@@ -23,48 +28,55 @@ use ruarango::{
         CollectionKind, Status,
     },
     common::output::Response,
-    Collection,
+    doc::input::ReadConfigBuilder,
+    ArangoEither, Collection,
 };
+use serde_json::{json, Value};
 
 const TEST_COLL: &str = "test_coll";
 
-int_test_async_new!(res; Response<Vec<Collections>>; collection_collections_ruarango_no_system_async, collections(true) => {
+int_test_async_new!(res; Response<Vec<Collections>>; collection_collections_ruarango_no_system_async, collections(true, false) => {
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
     assert!(!res.result().is_empty());
 });
 
-int_test_sync_new!(res; collection_collections_ruarango_no_system, collections(true) => {
+int_test_sync_new!(res; collection_collections_ruarango_no_system, collections(true, false) => {
     assert!(!res.result().is_empty());
 });
 
-int_test_async_new!(res; Response<Vec<Collections>>; crate::pool::ROOT_ASYNC_POOL; collection_collections_root_no_system_async, collections(true) => {
+int_test_async_new!(res; Response<Vec<Collections>>; crate::pool::ROOT_ASYNC_POOL; collection_collections_root_no_system_async, collections(true, false) => {
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
     assert!(res.result().is_empty());
 });
 
-int_test_sync_new!(res; crate::pool::ROOT_POOL; collection_collections_root_no_system, collections(true) => {
+int_test_sync_new!(res; crate::pool::ROOT_POOL; collection_collections_root_no_system, collections(true, false) => {
     assert!(res.result().is_empty());
 });
 
-int_test_async_new!(res; Response<Vec<Collections>>; collection_collections_ruarango_async, collections(false) => {
+int_test_async_new!(res; Response<Vec<Collections>>; collection_collections_ruarango_async, collections(false, false) => {
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
     assert!(!res.result().is_empty());
 });
 
-int_test_sync_new!(res; collection_collections_ruarango, collections(false) => {
+int_test_sync_new!(res; collection_collections_ruarango, collections(false, false) => {
+    assert!(!res.result().is_empty());
+});
+
+int_test_sync_new!(res; collection_collections_with_count, collections(true, true) => {
     assert!(!res.result().is_empty());
+    assert!(res.result().iter().all(|coll| coll.count().is_some()));
 });
 
-int_test_async_new!(res; Response<Vec<Collections>>; crate::pool::ROOT_ASYNC_POOL; collection_collections_root_async, collections(false) => {
+int_test_async_new!(res; Response<Vec<Collections>>; crate::pool::ROOT_ASYNC_POOL; collection_collections_root_async, collections(false, false) => {
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
     assert!(!res.result().is_empty());
 });
 
-int_test_sync_new!(res; crate::pool::ROOT_POOL; collection_collections, collections(false) => {
+int_test_sync_new!(res; crate::pool::ROOT_POOL; collection_collections, collections(false, false) => {
     assert!(!res.result().is_empty());
 });
 
@@ -128,7 +140,7 @@ fn create_config(kind: CreateKind) -> Result<Config> {
 int_test_async_new!(res; conn; Create; crate::pool::RUARANGO_ASYNC_POOL; collection_create_drop_async, create(&create_config(CreateKind::CollAsync)?) => {
     assert_eq!(res.name(), &*COLL_NAME_ASYNC);
 
-    let res = conn.drop(&COLL_NAME_ASYNC, false).await?;
+    let res = conn.drop_with(&COLL_NAME_ASYNC, DropConfig::default()).await?;
     let res = process_async_result(res, conn).await?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
@@ -137,7 +149,7 @@ int_test_async_new!(res; conn; Create; crate::pool::RUARANGO_ASYNC_POOL; collect
 int_test_sync_new!(res; conn; collection_create_drop, create(&create_config(CreateKind::Coll)?) => {
     assert_eq!(res.name(), &*COLL_NAME);
 
-    let either = conn.drop(&COLL_NAME, false).await?;
+    let either = conn.drop_with(&COLL_NAME, DropConfig::default()).await?;
     assert!(either.is_right());
     let res = either.right_safe()?;
     assert!(!res.error());
@@ -185,7 +197,7 @@ int_test_sync_new!(res; collection_count, count(TEST_COLL) => {
     assert!(*res.count() >= 1);
 });
 
-int_test_async_new!(res; Figures; collection_figures_async, figures(TEST_COLL) => {
+int_test_async_new!(res; Figures; collection_figures_async, figures(TEST_COLL, false) => {
     assert!(*res.figures().indexes().count() >= 1);
     // assert!(*res.figures().indexes().size() > 0);
     // assert!(*res.figures().documents_size() >= 0);
@@ -194,7 +206,7 @@ int_test_async_new!(res; Figures; collection_figures_async, figures(TEST_COLL) =
     assert_eq!(*res.figures().cache_usage(), 0);
 });
 
-int_test_sync_new!(res; collection_figures, figures(TEST_COLL) => {
+int_test_sync_new!(res; collection_figures, figures(TEST_COLL, false) => {
     assert!(*res.figures().indexes().count() >= 1);
     // assert!(*res.figures().indexes().size() > 0);
     // assert!(*res.figures().documents_size() > 0);
@@ -203,6 +215,11 @@ int_test_sync_new!(res; collection_figures, figures(TEST_COLL) => {
     assert_eq!(*res.figures().cache_usage(), 0);
 });
 
+int_test_sync_new!(res; collection_figures_details, figures(TEST_COLL, true) => {
+    assert!(*res.figures().indexes().count() >= 1);
+    assert!(res.figures().engine().is_some());
+});
+
 int_test_async_new!(res; Revision; collection_revision_async, revision(TEST_COLL) => {
     assert!(!res.revision().is_empty());
 });
@@ -281,7 +298,7 @@ int_test_async_new!(res; conn; Create; crate::pool::RUARANGO_ASYNC_POOL; collect
     assert_eq!(*res.code(), 200);
     assert_eq!(res.name(), &*RENAME_NEW_NAME_ASYNC);
 
-    let either = conn.drop(&RENAME_NEW_NAME_ASYNC, false).await?;
+    let either = conn.drop_with(&RENAME_NEW_NAME_ASYNC, DropConfig::default()).await?;
     let res = process_async_result(either, conn).await?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
@@ -296,7 +313,7 @@ int_test_sync_new!(res; conn; collection_rename, create(&create_config(CreateKin
     assert_eq!(*res.code(), 200);
     assert_eq!(res.name(), &*RENAME_NEW_NAME);
 
-    let either = conn.drop(&RENAME_NEW_NAME, false).await?;
+    let either = conn.drop_with(&RENAME_NEW_NAME, DropConfig::default()).await?;
     let res = process_sync_result(either)?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
@@ -310,7 +327,7 @@ int_test_async_new!(res; conn; Create; crate::pool::RUARANGO_ASYNC_POOL; collect
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
 
-    let either = conn.drop(&TRUNCATE_NAME_ASYNC, false).await?;
+    let either = conn.drop_with(&TRUNCATE_NAME_ASYNC, DropConfig::default()).await?;
     let res = process_async_result(either, conn).await?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
@@ -324,7 +341,7 @@ int_test_sync_new!(res; conn; collection_truncate, create(&create_config(CreateK
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
 
-    let either = conn.drop(&TRUNCATE_NAME, false).await?;
+    let either = conn.drop_with(&TRUNCATE_NAME, DropConfig::default()).await?;
     let res = process_sync_result(either)?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
@@ -336,7 +353,7 @@ int_test_async_new!(res; conn; Create; crate::pool::RUARANGO_ASYNC_POOL; collect
     let either = conn.unload(&UNLOAD_NAME_ASYNC).await?;
     let _res = process_async_result(either, conn).await?;
 
-    let either = conn.drop(&UNLOAD_NAME_ASYNC, false).await?;
+    let either = conn.drop_with(&UNLOAD_NAME_ASYNC, DropConfig::default()).await?;
     let res = process_async_result(either, conn).await?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
@@ -348,8 +365,112 @@ int_test_sync_new!(res; conn; collection_unload, create(&create_config(CreateKin
     let either = conn.unload(&UNLOAD_NAME).await?;
     let _res = process_sync_result(either)?;
 
-    let either = conn.drop(&UNLOAD_NAME, false).await?;
+    let either = conn.drop_with(&UNLOAD_NAME, DropConfig::default()).await?;
     let res = process_sync_result(either)?;
     assert!(!res.error());
     assert_eq!(*res.code(), 200);
 });
+
+int_test_sync_new!(res; collection_compact, compact(TEST_COLL) => {
+    assert_eq!(res.name(), TEST_COLL);
+    assert_eq!(*res.kind(), CollectionKind::Document);
+});
+
+#[tokio::test]
+async fn collection_create_computed_values() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let computed = ComputedValueBuilder::default()
+        .name("fullName")
+        .expression("RETURN CONCAT(@doc.first, ' ', @doc.last)")
+        .overwrite(true)
+        .compute_on(vec![ComputeOn::Insert])
+        .build()?;
+    let config = ConfigBuilder::default()
+        .name(rand_name())
+        .computed_values(vec![computed])
+        .build()?;
+    let res = Collection::create(conn, &config).await?;
+    let coll = process_sync_result(res)?;
+
+    let doc = json!({ "first": "Jason", "last": "Ozias" });
+    let meta = create_random_document(conn, coll.name(), doc).await?;
+    let read_config = ReadConfigBuilder::default()
+        .collection(coll.name())
+        .key(meta.key())
+        .build()?;
+    let res: ArangoEither<Value> = ruarango::Document::read(conn, read_config).await?;
+    let doc = process_sync_result(res)?;
+    assert_eq!(Some(&json!("Jason Ozias")), doc.get("fullName"));
+
+    delete_random_collection(conn, coll.name()).await
+}
+
+#[tokio::test]
+async fn collection_create_if_absent() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = ConfigBuilder::default().name(rand_name()).build()?;
+    let first = process_sync_result(conn.create_if_absent(&config).await?)?;
+    let second = process_sync_result(conn.create_if_absent(&config).await?)?;
+    assert_eq!(first.name(), second.name());
+    assert_eq!(first.id(), second.id());
+
+    delete_random_collection(conn, first.name()).await
+}
+
+#[tokio::test]
+async fn collection_checksum_with_data() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = ConfigBuilder::default().name(rand_name()).build()?;
+    let coll = process_sync_result(Collection::create(conn, &config).await?)?;
+    let _meta = create_random_document(conn, coll.name(), json!({ "test": "checksum" })).await?;
+
+    let without_data = process_sync_result(conn.checksum(coll.name(), false, false).await?)?;
+    let with_data = process_sync_result(conn.checksum(coll.name(), false, true).await?)?;
+    assert_ne!(without_data.checksum(), with_data.checksum());
+
+    delete_random_collection(conn, coll.name()).await
+}
+
+#[tokio::test]
+async fn collection_all() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = ConfigBuilder::default().name(rand_name()).build()?;
+    let coll = process_sync_result(Collection::create(conn, &config).await?)?;
+    for i in 0..25 {
+        let _meta = create_random_document(conn, coll.name(), json!({ "test": i })).await?;
+    }
+
+    let config = AllConfigBuilder::default()
+        .batch_size(10)
+        .attribute("test")
+        .build()?;
+    let docs: Vec<Value> = conn.all(coll.name(), config).try_collect().await?;
+    let count = process_sync_result(conn.count(coll.name()).await?)?;
+    assert_eq!(*count.count(), docs.len());
+    assert!(docs.iter().all(|doc| doc.get("_key").is_none()));
+
+    delete_random_collection(conn, coll.name()).await
+}
+
+#[tokio::test]
+async fn collection_export() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = ConfigBuilder::default().name(rand_name()).build()?;
+    let coll = process_sync_result(Collection::create(conn, &config).await?)?;
+    for i in 0..25 {
+        let _meta = create_random_document(conn, coll.name(), json!({ "test": i })).await?;
+    }
+
+    let config = ExportConfigBuilder::default()
+        .collection(coll.name())
+        .batch_size(10)
+        .flush(true)
+        .restrict(Restrict::include(vec!["test"]))
+        .build()?;
+    let docs: Vec<Value> = conn.export(config).try_collect().await?;
+    let count = process_sync_result(conn.count(coll.name()).await?)?;
+    assert_eq!(*count.count(), docs.len());
+    assert!(docs.iter().all(|doc| doc.get("_key").is_none()));
+
+    delete_random_collection(conn, coll.name()).await
+}
@@ -1,13 +1,14 @@
 use crate::{
+    common::process_async_result,
     model::{unwrap_doc, OutputDoc, TestDoc},
-    pool::RUARANGO_POOL,
+    pool::{RUARANGO_ASYNC_POOL, RUARANGO_POOL},
 };
 use anyhow::Result;
 use ruarango::{
     cursor::{
         input::{
             CreateConfigBuilder, DeleteConfigBuilder, NextConfigBuilder, OptionsBuilder,
-            ProfileKind,
+            ProfileKind, Rules,
         },
         output::CursorMeta,
     },
@@ -19,6 +20,23 @@ use ruarango::{
     Error::{self, Cursor as CursorError},
 };
 
+#[tokio::test]
+async fn cursor_create_async() -> Result<()> {
+    let conn = &*RUARANGO_ASYNC_POOL.get()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR d IN test_coll RETURN d")
+        .count(true)
+        .build()?;
+    let res: ArangoEither<CursorMeta<OutputDoc>> = Cursor::create(conn, config).await?;
+    let cursor_meta = process_async_result(res, conn).await?;
+    assert!(cursor_meta.result().is_some());
+    assert!(!cursor_meta.result().as_ref().unwrap().is_empty());
+    assert!(*cursor_meta.count().as_ref().unwrap() >= 1);
+    assert!(!cursor_meta.error());
+    assert_eq!(*cursor_meta.code(), 201);
+    Ok(())
+}
+
 #[tokio::test]
 async fn cursor_create() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -51,6 +69,187 @@ async fn cursor_create() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn cursor_create_full_count() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let options = OptionsBuilder::default().full_count(true).build()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR d IN test_coll LIMIT 2 RETURN d")
+        .options(options)
+        .build()?;
+    let res: ArangoEither<CursorMeta<OutputDoc>> = Cursor::create(conn, config).await?;
+    assert!(res.is_right());
+    let cursor_meta = res.right_safe()?;
+    assert_eq!(cursor_meta.result().as_ref().map(Vec::len), Some(2));
+    assert!(cursor_meta.extra().is_some());
+    let extra = cursor_meta.extra().as_ref().unwrap();
+    assert!(extra.stats().full_count().is_some());
+    assert!(extra.stats().full_count().unwrap() > 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_create_stream() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let options = OptionsBuilder::default().stream(true).build()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR d IN test_coll RETURN d")
+        .batch_size(1)
+        .count(true)
+        .options(options)
+        .build()?;
+    let res: ArangoEither<CursorMeta<OutputDoc>> = Cursor::create(conn, config).await?;
+    assert!(res.is_right());
+    let mut cursor_meta = res.right_safe()?;
+    assert!(cursor_meta.count().is_none());
+
+    let mut total = 0;
+    loop {
+        assert!(cursor_meta.count().is_none());
+        total += cursor_meta.result().as_ref().map_or(0, Vec::len);
+
+        if !cursor_meta.has_more() {
+            break;
+        }
+        let id = cursor_meta.id().clone().unwrap();
+        let config = NextConfigBuilder::default().id(id).build()?;
+        cursor_meta = conn.next(config).await?.right_safe()?;
+    }
+    assert!(total >= 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_create_cache() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let cached_config = || {
+        CreateConfigBuilder::default()
+            .query("FOR d IN test_coll SORT d._key RETURN d._key")
+            .cache(true)
+            .build()
+    };
+    let res: ArangoEither<CursorMeta<String>> = Cursor::create(conn, cached_config()?).await?;
+    assert!(res.is_right());
+
+    // This requires the server query cache mode to be 'on' or 'demand'
+    let res: ArangoEither<CursorMeta<String>> = Cursor::create(conn, cached_config()?).await?;
+    assert!(res.is_right());
+    let cursor_meta = res.right_safe()?;
+    assert!(cursor_meta.cached());
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_query_all() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR i IN 1..10 RETURN i")
+        .batch_size(3)
+        .build()?;
+    let res: ArangoEither<Vec<usize>> = conn.query_all(config).await?;
+    assert!(res.is_right());
+    assert_eq!(res.right_safe()?, (1..=10).collect::<Vec<usize>>());
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_create_memory_limit() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR i IN 1..100000 SORT RAND() RETURN { i, d: CONCAT('test', i) }")
+        .memory_limit(1)
+        .build()?;
+    let res: ArangoResult<CursorMeta<usize>> = Cursor::create(conn, config).await;
+    match res {
+        Ok(_) => panic!("This should be an error!"),
+        Err(e) => match e.downcast_ref::<Error>() {
+            Some(CursorError { err: Some(err) }) => {
+                assert!(err.error());
+                assert_eq!(*err.error_num(), 32);
+            }
+            _ => panic!("This is the wrong error type!"),
+        },
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_next_batch_retry() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let options = OptionsBuilder::default().allow_retry(true).build()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR i IN 1..10 RETURN i")
+        .batch_size(2)
+        .options(options)
+        .build()?;
+    let res: ArangoEither<CursorMeta<usize>> = Cursor::create(conn, config).await?;
+    assert!(res.is_right());
+    let cursor_meta = res.right_safe()?;
+    assert!(cursor_meta.has_more());
+    assert_eq!(Some("2"), cursor_meta.next_batch_id().as_deref());
+    let id = cursor_meta.id().clone().unwrap();
+
+    // Re-fetch the first batch, as if the response had been lost
+    let res: ArangoEither<CursorMeta<usize>> = conn.next_batch(&id, "1").await?;
+    assert!(res.is_right());
+    let retry_meta = res.right_safe()?;
+    assert_eq!(cursor_meta.result(), retry_meta.result());
+    assert_eq!(Some("2"), retry_meta.next_batch_id().as_deref());
+
+    let delete_config = DeleteConfigBuilder::default().id(id).build()?;
+    let _res = Cursor::delete(conn, delete_config).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_create_allow_dirty_reads() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let options = OptionsBuilder::default().allow_dirty_reads(true).build()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR i IN 1..3 RETURN i")
+        .options(options)
+        .build()?;
+    let res: ArangoEither<CursorMeta<usize>> = Cursor::create(conn, config).await?;
+    assert!(res.is_right());
+    let cursor_meta = res.right_safe()?;
+    assert!(!cursor_meta.error());
+    assert_eq!(&Some(vec![1, 2, 3]), cursor_meta.result());
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_create_optimizer_rules() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let mut bind_vars = std::collections::HashMap::new();
+    let _ = bind_vars.insert("key".to_string(), "not_a_key".to_string());
+    let query = "FOR d IN test_coll FILTER d._key == @key RETURN d";
+
+    let config = CreateConfigBuilder::default()
+        .query(query)
+        .bind_vars(bind_vars.clone())
+        .build()?;
+    let res: ArangoEither<CursorMeta<OutputDoc>> = Cursor::create(conn, config).await?;
+    let cursor_meta = res.right_safe()?;
+    let stats = cursor_meta.extra().as_ref().unwrap().stats();
+    assert_eq!(*stats.scanned_full(), 0);
+
+    let config = CreateConfigBuilder::default()
+        .query(query)
+        .bind_vars(bind_vars)
+        .options(
+            OptionsBuilder::default()
+                .optimizer(Rules::disable("use-indexes"))
+                .build()?,
+        )
+        .build()?;
+    let res: ArangoEither<CursorMeta<OutputDoc>> = Cursor::create(conn, config).await?;
+    let cursor_meta = res.right_safe()?;
+    let stats = cursor_meta.extra().as_ref().unwrap().stats();
+    assert!(*stats.scanned_full() >= 1);
+    assert_eq!(*stats.scanned_index(), 0);
+    Ok(())
+}
+
 #[tokio::test]
 async fn cursor_create_profile() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -98,6 +297,47 @@ async fn cursor_create_profile() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn cursor_create_warnings() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let options = OptionsBuilder::default().max_warning_count(5).build()?;
+    let config = CreateConfigBuilder::default()
+        .query("FOR i IN 0..1 RETURN 1 / i")
+        .options(options)
+        .build()?;
+    let res: ArangoEither<CursorMeta<Option<f64>>> = Cursor::create(conn, config).await?;
+    assert!(res.is_right());
+    let cursor_meta = res.right_safe()?;
+    assert!(cursor_meta.extra().is_some());
+    let warnings = cursor_meta.extra().as_ref().unwrap().warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(*warnings[0].code(), 1562);
+    assert_eq!(warnings[0].message(), "division by zero");
+    Ok(())
+}
+
+#[tokio::test]
+async fn cursor_create_fail_on_warning() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let options = OptionsBuilder::default().fail_on_warning(true).build()?;
+    let config = CreateConfigBuilder::default()
+        .query("RETURN 1 / 0")
+        .options(options)
+        .build()?;
+    let res: ArangoResult<CursorMeta<Option<f64>>> = Cursor::create(conn, config).await;
+    match res {
+        Ok(_) => panic!("This call should fail!"),
+        Err(e) => match e.downcast_ref::<Error>() {
+            Some(CursorError { err: Some(err) }) => {
+                assert_eq!(*err.code(), 400);
+                assert_eq!(*err.error_num(), 1562);
+            }
+            _ => panic!("This is the wrong error type!"),
+        },
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn cursor_create_400() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
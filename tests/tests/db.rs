@@ -7,10 +7,10 @@ use lazy_static::lazy_static;
 use ruarango::{
     common::output::Response,
     db::{
-        input::{Create, CreateBuilder},
+        input::{Create, CreateBuilder, OptionsBuilder, UserBuilder},
         output::Current,
     },
-    Database,
+    ConnectionBuilder, Database,
 };
 
 int_test_async_new!(res; Response<Current>; database_current_async, current() => {
@@ -37,6 +37,19 @@ int_test_sync_new!(res; database_current, current() => {
     assert!(res.result().write_concern().is_none());
 });
 
+int_test_sync_new!(res; crate::pool::ROOT_POOL; database_info, info("ruarango") => {
+    assert!(!res.error());
+    assert_eq!(*res.code(), 200);
+    assert_eq!(res.result().name(), "ruarango");
+    assert!(!res.result().is_system());
+});
+
+int_test_sync_new!(res; database_engine, engine() => {
+    assert_eq!(res.name(), "rocksdb");
+    assert!(!res.is_mmfiles());
+    assert!(!res.supports().indexes().is_empty());
+});
+
 int_test_async_new!(res; Response<Vec<String>>; database_user_async, user() => {
     assert_eq!(res.result().len(), 1);
     assert_eq!(res.result()[0], "ruarango");
@@ -47,6 +60,11 @@ int_test_sync_new!(res; database_user, user() => {
     assert_eq!(res.result()[0], "ruarango");
 });
 
+int_test_sync_new!(res; database_list_accessible, list_accessible() => {
+    assert_eq!(res.result().len(), 1);
+    assert_eq!(res.result()[0], "ruarango");
+});
+
 int_test_async_new!(res; Response<Vec<String>>; crate::pool::ROOT_ASYNC_POOL; database_list_async, list() => {
     assert!(!res.result().is_empty());
     assert!(res.result().contains(&"ruarango".to_string()));
@@ -60,6 +78,7 @@ int_test_sync_new!(res; crate::pool::ROOT_POOL; database_list, list() => {
 lazy_static! {
     static ref DB_NAME: String = rand_name();
     static ref DB_NAME_ASYNC: String = rand_name();
+    static ref DB_NAME_OPTIONS: String = rand_name();
 }
 
 enum CreateKind {
@@ -85,6 +104,7 @@ int_test_sync_new!(res; conn; 201; crate::pool::ROOT_POOL; database_create_drop,
 });
 
 int_test_async_new!(res; conn; Response<bool>; crate::pool::ROOT_ASYNC_POOL; database_create_drop_async, create(&create_config(CreateKind::Async)?) => {
+    assert_eq!(*res.code(), 201);
     assert!(res.result());
 
     let res = conn.drop(&DB_NAME_ASYNC).await?;
@@ -93,3 +113,54 @@ int_test_async_new!(res; conn; Response<bool>; crate::pool::ROOT_ASYNC_POOL; dat
     assert_eq!(*res.code(), 200);
     assert!(res.result());
 });
+
+#[tokio::test]
+async fn database_create_with_options() -> Result<()> {
+    let conn = &*crate::pool::ROOT_POOL.get()?;
+    let options = OptionsBuilder::default()
+        .replication_factor(2)
+        .write_concern(2)
+        .build()?;
+    let user = UserBuilder::default()
+        .username(&*DB_NAME_OPTIONS)
+        .password("test")
+        .active(true)
+        .build()?;
+    let config = CreateBuilder::default()
+        .name(&*DB_NAME_OPTIONS)
+        .options(options)
+        .users(vec![user])
+        .build()?;
+    let res = process_sync_result(conn.create(&config).await?)?;
+    assert!(res.result());
+
+    let db_conn = ConnectionBuilder::default()
+        .url(env!("ARANGODB_URL"))
+        .username("root")
+        .password(env!("ARANGODB_ROOT_PASSWORD"))
+        .database(&*DB_NAME_OPTIONS)
+        .build()
+        .await?;
+    let res = process_sync_result(db_conn.current().await?)?;
+    assert_eq!(res.result().name(), &*DB_NAME_OPTIONS);
+    // The clustering options are only reported by a cluster
+    if let Some(write_concern) = res.result().write_concern() {
+        assert_eq!(*write_concern, 2);
+    }
+
+    let res = process_sync_result(conn.drop(&DB_NAME_OPTIONS).await?)?;
+    assert!(res.result());
+    Ok(())
+}
+
+#[tokio::test]
+async fn database_with_database() -> Result<()> {
+    let conn = &*crate::pool::ROOT_POOL.get()?;
+    let res = process_sync_result(conn.list().await?)?;
+    assert!(res.result().iter().any(|name| name == "ruarango"));
+
+    let db_conn = conn.with_database("ruarango")?;
+    let res = process_sync_result(db_conn.current().await?)?;
+    assert_eq!(res.result().name(), "ruarango");
+    Ok(())
+}
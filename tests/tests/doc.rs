@@ -1,21 +1,31 @@
 use crate::{
     common::process_async_doc_result,
+    conn::{conn as new_conn, ConnKind},
     model::{unwrap_doc, OutputDoc, SearchDoc, TestDoc},
     pool::{RUARANGO_ASYNC_POOL, RUARANGO_POOL},
+    rand_util::{
+        create_random_collection, create_random_document, delete_random_collection, rand_name,
+        CollKind,
+    },
 };
 use anyhow::Result;
+use getset::Getters;
 use ruarango::{
     doc::{
         input::{
-            CreateConfigBuilder, CreatesConfigBuilder, DeleteConfigBuilder, DeletesConfigBuilder,
-            ReadConfig, ReadConfigBuilder, ReadsConfigBuilder, ReplaceConfigBuilder,
-            UpdateConfigBuilder, UpdatesConfigBuilder,
+            CreateConfigBuilder, CreatesConfigBuilder, DeleteByQueryConfigBuilder,
+            DeleteConfigBuilder, DeletesConfigBuilder, ImportConfigBuilder, OverwriteMode,
+            ReadConfig, ReadConfigBuilder, ReadProjectedConfigBuilder, ReadsConfigBuilder,
+            ReplaceConfigBuilder, UpdateConfigBuilder, UpdatesConfigBuilder,
         },
-        output::DocMeta,
+        output::{DocMeta, ImportResult},
     },
-    ArangoEither, ArangoResult, ArangoVec, Connection, Document,
+    ArangoEither, ArangoMap, ArangoResult, ArangoVec, Connection, Document,
     Error::{self, NotFound, PreconditionFailed},
 };
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
 
 const TEST_COLL: &str = "test_coll";
 const DOC_KEY: &str = "4316629";
@@ -77,6 +87,136 @@ async fn doc_reads() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn doc_reads_map() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let first = create_random_document(conn, &coll, TestDoc::default()).await?;
+    let second = create_random_document(conn, &coll, TestDoc::default()).await?;
+    let search_docs = vec![
+        SearchDoc::new(first.key()),
+        SearchDoc::new("abcd"),
+        SearchDoc::new(second.key()),
+    ];
+    let config = ReadsConfigBuilder::default()
+        .collection(&coll)
+        .documents(search_docs)
+        .build()?;
+    let res: ArangoEither<ArangoMap<OutputDoc>> = conn.reads_map(config).await?;
+    let docs = res.right_safe()?;
+    assert_eq!(docs.len(), 3);
+    assert_eq!(docs[first.key()].as_ref().unwrap().key(), first.key());
+    assert_eq!(docs[second.key()].as_ref().unwrap().key(), second.key());
+    assert_eq!(*docs["abcd"].as_ref().unwrap_err().error_num(), 1202);
+
+    delete_random_collection(conn, coll).await
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Projected {
+    test: String,
+}
+
+#[tokio::test]
+async fn doc_read_projected() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let config = ReadProjectedConfigBuilder::default()
+        .collection(TEST_COLL)
+        .key(DOC_KEY)
+        .attribute("test")
+        .build()?;
+    let res: ArangoEither<Value> = conn.read_projected(config).await?;
+    let doc = res.right_safe()?;
+    assert_eq!(Some(&json!(TEST_FIELD_VAL)), doc.get("test"));
+    assert!(doc.get("_key").is_none());
+    let config = ReadProjectedConfigBuilder::default()
+        .collection(TEST_COLL)
+        .key(DOC_KEY)
+        .attribute("test")
+        .build()?;
+    let res: ArangoEither<Projected> = conn.read_projected(config).await?;
+    assert_eq!(TEST_FIELD_VAL, res.right_safe()?.test);
+
+    let config = ReadProjectedConfigBuilder::default()
+        .collection(TEST_COLL)
+        .key("abcd")
+        .attribute("test")
+        .build()?;
+    let err = conn.read_projected::<Projected>(config).await.unwrap_err();
+    assert!(matches!(err.downcast_ref::<Error>(), Some(NotFound { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn doc_update_with_retry() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let doc_meta = create_random_document(conn, &coll, json!({ "count": 0 })).await?;
+    let key = doc_meta.key().clone();
+
+    let mut calls = 0;
+    let res = conn
+        .update_with_retry(&coll, &key, 3, |doc: &Value| {
+            calls += 1;
+            if calls == 1 {
+                // Change the document out-of-band between the read and the update
+                let (coll, key) = (coll.clone(), key.clone());
+                std::thread::spawn(move || -> Result<()> {
+                    tokio::runtime::Runtime::new()?.block_on(async {
+                        let conn = new_conn(ConnKind::Ruarango).await?;
+                        let config = UpdateConfigBuilder::default()
+                            .collection(coll)
+                            .key(key)
+                            .document(json!({ "count": 10 }))
+                            .build()?;
+                        let _res: ArangoEither<DocMeta<(), ()>> = conn.update(config).await?;
+                        Ok(())
+                    })
+                })
+                .join()
+                .unwrap()
+                .unwrap();
+            }
+            json!({ "count": doc["count"].as_u64().unwrap_or_default() + 1 })
+        })
+        .await?;
+    assert!(res.right_safe()?.changed());
+    assert_eq!(2, calls);
+
+    let config = ReadConfigBuilder::default()
+        .collection(&coll)
+        .key(&key)
+        .build()?;
+    let doc: Value = conn.read(config).await?.right_safe()?;
+    assert_eq!(json!(11), doc["count"]);
+
+    delete_random_collection(conn, coll).await
+}
+
+#[tokio::test]
+async fn doc_delete_by_query() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    for age in &[35, 40, 20] {
+        let _ = create_random_document(conn, &coll, json!({ "age": age })).await?;
+    }
+    let mut bind_vars = HashMap::new();
+    let _ = bind_vars.insert("age".to_string(), json!(30));
+    let config = DeleteByQueryConfigBuilder::default()
+        .collection(&coll)
+        .filter("d.age > @age")
+        .bind_vars(bind_vars)
+        .build()?;
+    let res = conn.delete_by_query(config).await?;
+    assert_eq!(2, res.right_safe()?);
+    let count = ruarango::Collection::count(conn, &coll)
+        .await?
+        .right_safe()?;
+    assert_eq!(1, *count.count());
+
+    delete_random_collection(conn, coll).await
+}
+
 enum IfNoneMatchKind {
     Match,
     NoneMatch,
@@ -182,7 +322,7 @@ async fn doc_read_if_match_doesnt_match() -> Result<()> {
         Err(e) => {
             let err = e.downcast_ref::<Error>().expect("unanticipated error");
             match err {
-                PreconditionFailed { err } => {
+                PreconditionFailed { err, .. } => {
                     assert!(err.is_some());
                     let pre_cond = err.as_ref().expect("this is bad!");
                     assert!(pre_cond.error());
@@ -300,6 +440,28 @@ async fn doc_create_delete_basic() -> Result<()> {
     delete_doc(conn, &key, "test").await
 }
 
+#[tokio::test]
+async fn doc_create_collection_on_demand() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let coll_name = rand_name();
+
+    let config = CreateConfigBuilder::default()
+        .collection(&coll_name)
+        .document(TestDoc::default())
+        .create_collection(true)
+        .build()?;
+    let res: ArangoEither<DocMeta<(), ()>> = conn.create(config).await?;
+    assert!(res.is_right());
+    let doc_meta = res.right_safe()?;
+    assert!(doc_meta.id().starts_with(&coll_name));
+
+    let res = ruarango::Collection::collection(conn, &coll_name).await?;
+    assert!(res.is_right());
+    assert_eq!(res.right_safe()?.name(), &coll_name);
+
+    delete_random_collection(conn, coll_name).await
+}
+
 #[tokio::test]
 async fn doc_creates_deletes_basic() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -326,6 +488,8 @@ async fn doc_create_overwrite_replace_delete() -> Result<()> {
     let overwrite_res: ArangoEither<DocMeta<(), ()>> = conn.create(overwrite).await?;
     assert!(overwrite_res.is_right());
     let doc_meta = overwrite_res.right_safe()?;
+    assert!(doc_meta.old_rev().is_some());
+    assert!(doc_meta.changed());
     let key = doc_meta.key();
 
     // Delete that document
@@ -351,6 +515,8 @@ async fn doc_create_replace_delete() -> Result<()> {
     let replace_res: ArangoEither<DocMeta<TestDoc, ()>> = conn.replace(replace).await?;
     assert!(replace_res.is_right());
     let doc_meta = replace_res.right_safe()?;
+    assert!(doc_meta.old_rev().is_some());
+    assert!(doc_meta.changed());
     let key = doc_meta.key();
     let doc_opt = doc_meta.new_doc();
     assert!(doc_opt.is_some());
@@ -380,6 +546,8 @@ async fn doc_create_update_delete() -> Result<()> {
     let replace_res: ArangoEither<DocMeta<TestDoc, TestDoc>> = conn.update(update).await?;
     assert!(replace_res.is_right());
     let doc_meta = replace_res.right_safe()?;
+    assert!(doc_meta.old_rev().is_some());
+    assert!(doc_meta.changed());
     let key = doc_meta.key();
     let doc_opt = doc_meta.new_doc();
     assert!(doc_opt.is_some());
@@ -436,3 +604,177 @@ async fn doc_creates_updates_deletes_basic() -> Result<()> {
     // Delete the documents
     delete_docs(conn, keys, "blah").await
 }
+
+#[tokio::test]
+async fn doc_import() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let config = ImportConfigBuilder::default()
+        .collection(coll.clone())
+        .documents(vec![TestDoc::default(); 50])
+        .complete(true)
+        .build()?;
+    let res: ArangoEither<ImportResult> = conn.import(config).await?;
+    assert!(res.is_right());
+    let import = res.right_safe()?;
+    assert_eq!(*import.created(), 50);
+    assert_eq!(*import.errors(), 0);
+    delete_random_collection(conn, coll).await
+}
+
+#[derive(Clone, Debug, Deserialize, Getters, Serialize)]
+#[getset(get = "pub(crate)")]
+struct VersionDoc {
+    #[serde(rename = "_key")]
+    key: String,
+    version: usize,
+}
+
+#[tokio::test]
+async fn doc_create_version_attribute() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let doc = VersionDoc {
+        key: "versioned".to_string(),
+        version: 2,
+    };
+    let config = CreateConfigBuilder::default()
+        .collection(coll.clone())
+        .document(doc)
+        .build()?;
+    let res: ArangoEither<DocMeta<(), ()>> = conn.create(config).await?;
+    assert!(res.is_right());
+
+    let stale = VersionDoc {
+        key: "versioned".to_string(),
+        version: 1,
+    };
+    let config = CreateConfigBuilder::default()
+        .collection(coll.clone())
+        .document(stale)
+        .overwrite_mode(OverwriteMode::Replace)
+        .version_attribute("version")
+        .build()?;
+    let res: ArangoEither<DocMeta<(), ()>> = conn.create(config).await?;
+    assert!(res.is_right());
+
+    let config = ReadConfigBuilder::default()
+        .collection(coll.clone())
+        .key("versioned")
+        .build()?;
+    let res: ArangoEither<VersionDoc> = conn.read(config).await?;
+    assert!(res.is_right());
+    assert_eq!(*res.right_safe()?.version(), 2);
+
+    delete_random_collection(conn, coll).await
+}
+
+#[tokio::test]
+async fn doc_create_raw() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let config = CreateConfigBuilder::default()
+        .collection(coll.clone())
+        .document(r#"{"_key":"raw","version":1}"#.to_string())
+        .build()?;
+    let res: ArangoEither<DocMeta<(), ()>> = conn.create_raw(config).await?;
+    let meta = res.right_safe()?;
+    assert_eq!(meta.key(), "raw");
+    assert_eq!(meta.id(), &format!("{coll}/raw"));
+
+    let config = ReadConfigBuilder::default()
+        .collection(coll.clone())
+        .key("raw")
+        .build()?;
+    let res: ArangoEither<VersionDoc> = conn.read(config).await?;
+    assert_eq!(*res.right_safe()?.version(), 1);
+
+    delete_random_collection(conn, coll).await
+}
+
+#[tokio::test]
+async fn doc_create_overwrite_ignore_return_new() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let doc = VersionDoc {
+        key: "ignored".to_string(),
+        version: 1,
+    };
+    let config = CreateConfigBuilder::default()
+        .collection(coll.clone())
+        .document(doc)
+        .build()?;
+    let res: ArangoEither<DocMeta<(), ()>> = conn.create(config).await?;
+    let original = res.right_safe()?;
+
+    let doc = VersionDoc {
+        key: "ignored".to_string(),
+        version: 2,
+    };
+    let config = CreateConfigBuilder::default()
+        .collection(coll.clone())
+        .document(doc)
+        .overwrite_mode(OverwriteMode::Ignore)
+        .return_new(true)
+        .build()?;
+    let res: ArangoEither<DocMeta<VersionDoc, ()>> = conn.create(config).await?;
+    assert!(res.is_right());
+    let meta = res.right_safe()?;
+    assert!(meta.new_doc().is_none());
+    assert_eq!(meta.rev(), original.rev());
+
+    let config = ReadConfigBuilder::default()
+        .collection(coll.clone())
+        .key("ignored")
+        .build()?;
+    let res: ArangoEither<VersionDoc> = conn.read(config).await?;
+    assert_eq!(*res.right_safe()?.version(), 1);
+
+    delete_random_collection(conn, coll).await
+}
+
+#[tokio::test]
+async fn doc_deletes_rev_mismatch() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let fresh = create_random_document(conn, &coll, TestDoc::default()).await?;
+    let stale = create_random_document(conn, &coll, TestDoc::default()).await?;
+
+    let config = DeletesConfigBuilder::default()
+        .collection(coll.clone())
+        .documents(vec![
+            json!({ "_key": fresh.key(), "_rev": fresh.rev() }),
+            json!({ "_key": stale.key(), "_rev": "_stale_rev" }),
+        ])
+        .ignore_revs(false)
+        .build()?;
+    let res: ArangoEither<ArangoVec<DocMeta<(), ()>>> = conn.deletes(config).await?;
+    assert!(res.is_right());
+    let results = res.right_safe()?;
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_right());
+    assert_eq!(results[0].right_ref()?.key(), fresh.key());
+    assert!(results[1].is_left());
+    assert_eq!(*results[1].left_ref()?.error_num(), 1200);
+
+    delete_random_collection(conn, coll).await
+}
+
+#[tokio::test]
+async fn doc_deletes_silent() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let first = create_random_document(conn, &coll, TestDoc::default()).await?;
+    let second = create_random_document(conn, &coll, TestDoc::default()).await?;
+
+    let config = DeletesConfigBuilder::default()
+        .collection(coll.clone())
+        .documents(vec![first.key().clone(), second.key().clone()])
+        .silent(true)
+        .build()?;
+    let res: ArangoEither<ArangoVec<DocMeta<(), ()>>> = conn.deletes(config).await?;
+    assert!(res.is_right());
+    assert!(res.right_safe()?.is_empty());
+
+    delete_random_collection(conn, coll).await
+}
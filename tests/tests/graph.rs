@@ -1,6 +1,7 @@
 use crate::{
+    common::process_async_result,
     model::TestDoc,
-    pool::RUARANGO_POOL,
+    pool::{RUARANGO_ASYNC_POOL, RUARANGO_POOL},
     rand_util::{
         create_random_collection, create_random_document, create_random_graph,
         delete_random_collection, delete_random_graph, rand_name, CollKind,
@@ -10,19 +11,24 @@ use anyhow::Result;
 use ruarango::{
     graph::{
         input::{
-            CreateEdgeDefConfigBuilder, CreateVertexCollConfigBuilder,
-            CreateVertexCollectionBuilder, CreateVertexConfigBuilder, DeleteEdgeDefConfigBuilder,
-            DeleteVertexCollConfigBuilder, DeleteVertexConfigBuilder, EdgeCreateConfigBuilder,
-            EdgeDeleteConfigBuilder, EdgeReadConfigBuilder, EdgeReplaceConfigBuilder,
-            EdgeUpdateConfigBuilder, FromToBuilder, ReadConfigBuilder, ReadEdgeDefsConfigBuilder,
-            ReadVertexCollsConfigBuilder, ReadVertexConfigBuilder, ReplaceEdgeDefConfigBuilder,
-            UpdateVertexConfigBuilder,
+            CreateConfigBuilder, CreateEdgeDefConfigBuilder, CreateVertexCollConfigBuilder,
+            CreateVertexCollectionBuilder, CreateVertexConfigBuilder, DeleteConfigBuilder,
+            DeleteEdgeDefConfigBuilder, DeleteVertexCollConfigBuilder, DeleteVertexConfigBuilder,
+            EdgeCreateConfigBuilder, EdgeDeleteConfigBuilder, EdgeReadConfigBuilder,
+            EdgeReplaceConfigBuilder, EdgeUpdateConfigBuilder, FromToBuilder, GraphMetaBuilder,
+            ReadConfigBuilder, ReadEdgeDefsConfigBuilder, ReadVertexCollsConfigBuilder,
+            ReadVertexConfigBuilder, ReplaceEdgeDefConfigBuilder, ShortestPathConfigBuilder,
+            TraversalConfigBuilder, UpdateVertexConfigBuilder,
         },
-        EdgeDefinitionBuilder,
+        output::Path,
+        Direction, EdgeDefinitionBuilder,
     },
+    ArangoEither,
+    Error::{NotModified, PreconditionFailed},
     Graph,
 };
 use serde::Serialize;
+use serde_json::{json, Value};
 
 #[tokio::test]
 async fn graph_list_all() -> Result<()> {
@@ -57,6 +63,23 @@ async fn graph_create_delete() -> Result<()> {
     delete_random_graph(conn, graph_meta).await
 }
 
+#[tokio::test]
+async fn graph_read_async() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let async_conn = &*RUARANGO_ASYNC_POOL.get()?;
+    let rand_graph_meta = create_random_graph(conn).await?;
+
+    let config = ReadConfigBuilder::default()
+        .name(rand_graph_meta.graph())
+        .build()?;
+    let res = async_conn.read(config).await?;
+    let graph_meta = process_async_result(res, async_conn).await?;
+    assert!(!graph_meta.error());
+    assert_eq!(graph_meta.graph().name(), rand_graph_meta.graph());
+
+    delete_random_graph(conn, rand_graph_meta).await
+}
+
 #[tokio::test]
 async fn graph_read() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -84,6 +107,47 @@ async fn graph_read() -> Result<()> {
     delete_random_graph(conn, rand_graph_meta).await
 }
 
+#[tokio::test]
+async fn graph_create_with_orphan_collection() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (edge_coll, _) = create_random_collection(conn, CollKind::Edge).await?;
+    let (vertex_coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let (orphan_coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let graph_name = rand_name();
+
+    let ed = EdgeDefinitionBuilder::default()
+        .collection(edge_coll.clone())
+        .from(vec![vertex_coll.clone()])
+        .to(vec![vertex_coll.clone()])
+        .build()?;
+    let graph_meta = GraphMetaBuilder::default()
+        .name(graph_name.clone())
+        .edge_definitions(vec![ed])
+        .orphan_collections(vec![orphan_coll.clone()])
+        .build()?;
+    let config = CreateConfigBuilder::default().graph(graph_meta).build()?;
+    let res = Graph::create(conn, config).await?;
+    assert!(res.is_right());
+
+    let config = ReadConfigBuilder::default()
+        .name(graph_name.clone())
+        .build()?;
+    let res = Graph::read(conn, config).await?;
+    assert!(res.is_right());
+    let graph_meta = res.right_safe()?;
+    assert_eq!(
+        graph_meta.graph().orphan_collections(),
+        &vec![orphan_coll.clone()]
+    );
+
+    let config = DeleteConfigBuilder::default().name(graph_name).build()?;
+    let res = Graph::delete(conn, config).await?;
+    assert!(res.is_right());
+    delete_random_collection(conn, orphan_coll).await?;
+    delete_random_collection(conn, vertex_coll).await?;
+    delete_random_collection(conn, edge_coll).await
+}
+
 #[tokio::test]
 async fn graph_create_delete_edge() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -179,6 +243,30 @@ async fn graph_create_read_delete_edge() -> Result<()> {
     let read_edge = res.right_safe()?;
     assert!(!read_edge.error());
 
+    let read_config = EdgeReadConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .key(key)
+        .if_none_match(edge.rev())
+        .build()?;
+    let err = conn.read_edge(read_config).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ruarango::Error>(),
+        Some(NotModified)
+    ));
+
+    let read_config = EdgeReadConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .key(key)
+        .if_match("_aaaaaaaaa")
+        .build()?;
+    let err = conn.read_edge(read_config).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ruarango::Error>(),
+        Some(PreconditionFailed { .. })
+    ));
+
     let delete_config = EdgeDeleteConfigBuilder::default()
         .graph(graph_name)
         .collection(edge_coll)
@@ -253,6 +341,68 @@ async fn graph_create_update_delete_edge() -> Result<()> {
     delete_random_graph(conn, rand_graph_meta).await
 }
 
+#[tokio::test]
+async fn graph_update_edge_keep_null_false() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let rand_graph_meta = create_random_graph(conn).await?;
+    let graph_name = rand_graph_meta.graph();
+    let edge_coll = rand_graph_meta.edge_coll();
+    let from_coll = rand_graph_meta.from_coll();
+    let to_coll = rand_graph_meta.to_coll();
+    let from_doc = create_random_document(conn, from_coll, TestDoc::default()).await?;
+    let to_doc = create_random_document(conn, to_coll, TestDoc::default()).await?;
+
+    let from_to = FromToBuilder::default()
+        .from(from_doc.id())
+        .to(to_doc.id())
+        .build()?;
+    let config = EdgeCreateConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .mapping(from_to)
+        .build()?;
+    let res = conn.create_edge(config).await?;
+    let create_edge = res.right_safe()?;
+    let key = create_edge.edge().key();
+
+    let update_config = EdgeUpdateConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .key(key)
+        .edge(EdgeStuff { name: "yoda" })
+        .build()?;
+    let _ = conn.update_edge(update_config).await?.right_safe()?;
+
+    let update_config = EdgeUpdateConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .key(key)
+        .keep_null(false)
+        .wait_for_sync(true)
+        .edge(json!({ "name": null }))
+        .build()?;
+    let res = conn.update_edge(update_config).await?;
+    let update_edge = res.right_safe()?;
+    assert!(!update_edge.error());
+
+    let read_config = ruarango::doc::input::ReadConfigBuilder::default()
+        .collection(edge_coll)
+        .key(key)
+        .build()?;
+    let res: ArangoEither<Value> = ruarango::Document::read(conn, read_config).await?;
+    let edge = res.right_safe()?;
+    assert!(edge.get("name").is_none());
+
+    let delete_config = EdgeDeleteConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .key(key)
+        .build()?;
+    let _ = conn.delete_edge(delete_config).await?;
+
+    delete_random_graph(conn, rand_graph_meta).await
+}
+
 #[tokio::test]
 async fn graph_create_replace_delete_edge() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -371,6 +521,12 @@ async fn graph_read_edge_defs() -> Result<()> {
     assert!(!graph_meta.error());
     assert_eq!(*graph_meta.code(), 200);
     assert!(!graph_meta.collections().is_empty());
+    assert!(!graph_meta.edge_definitions().is_empty());
+    for edge_def in graph_meta.edge_definitions() {
+        assert!(graph_meta.collections().contains(edge_def.collection()));
+        assert!(!edge_def.from().is_empty());
+        assert!(!edge_def.to().is_empty());
+    }
 
     delete_random_graph(conn, rand_graph_meta).await
 }
@@ -433,6 +589,49 @@ async fn graph_create_replace_delete_edge_def() -> Result<()> {
     delete_random_graph(conn, rand_graph_meta).await
 }
 
+#[tokio::test]
+async fn graph_replace_edge_def_if_match() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let rand_graph_meta = create_random_graph(conn).await?;
+    let graph_name = rand_graph_meta.graph();
+
+    let config = ReadConfigBuilder::default().name(graph_name).build()?;
+    let res = conn.read(config).await?;
+    assert!(res.is_right());
+    let read = res.right_safe()?;
+    let graph = read.graph();
+    assert!(!graph.id().is_empty());
+    assert!(!graph.key().is_empty());
+    assert!(!graph.rev().is_empty());
+    let current = &graph.edge_definitions()[0];
+    let edge_def = EdgeDefinitionBuilder::default()
+        .collection(current.collection())
+        .from(current.to().clone())
+        .to(current.from().clone())
+        .build()?;
+
+    let stale_config = ReplaceEdgeDefConfigBuilder::default()
+        .graph(graph_name)
+        .edge_def(edge_def.clone())
+        .if_match("1")
+        .build()?;
+    assert!(conn.replace_edge_def(stale_config).await.is_err());
+
+    let replace_config = ReplaceEdgeDefConfigBuilder::default()
+        .graph(graph_name)
+        .edge_def(edge_def)
+        .if_match(graph.rev())
+        .build()?;
+    let res = conn.replace_edge_def(replace_config).await?;
+    assert!(res.is_right());
+    let replace_edge_def = res.right_safe()?;
+    assert!(!replace_edge_def.error());
+    assert_eq!(*replace_edge_def.code(), 202);
+    assert_ne!(replace_edge_def.graph().rev(), graph.rev());
+
+    delete_random_graph(conn, rand_graph_meta).await
+}
+
 #[tokio::test]
 async fn graph_read_vertex_colls() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -545,6 +744,67 @@ async fn graph_create_read_delete_vertex() -> Result<()> {
     delete_random_graph(conn, rand_graph_meta).await
 }
 
+#[tokio::test]
+async fn graph_read_vertex_revision() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let rand_graph_meta = create_random_graph(conn).await?;
+    let graph_name = rand_graph_meta.graph();
+    let from_coll = rand_graph_meta.from_coll();
+
+    let config = CreateVertexConfigBuilder::default()
+        .name(graph_name)
+        .collection(from_coll)
+        .vertex(TestVertex { test: "test" })
+        .build()?;
+    let vertex_meta = conn.create_vertex(config).await?.right_safe()?;
+    let key = vertex_meta.vertex().key();
+    let rev = vertex_meta.vertex().rev();
+
+    let read_config = ReadVertexConfigBuilder::default()
+        .name(graph_name)
+        .collection(from_coll)
+        .key(key)
+        .if_match(rev)
+        .build()?;
+    let read_vertex_meta = conn.read_vertex(read_config).await?.right_safe()?;
+    assert_eq!(read_vertex_meta.vertex().rev(), rev);
+
+    let read_config = ReadVertexConfigBuilder::default()
+        .name(graph_name)
+        .collection(from_coll)
+        .key(key)
+        .if_match("_aaaaaaaaa")
+        .build()?;
+    let err = conn.read_vertex(read_config).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ruarango::Error>(),
+        Some(PreconditionFailed { .. })
+    ));
+
+    let read_config = ReadVertexConfigBuilder::default()
+        .name(graph_name)
+        .collection(from_coll)
+        .key(key)
+        .if_none_match(rev)
+        .build()?;
+    let err = conn.read_vertex(read_config).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ruarango::Error>(),
+        Some(NotModified)
+    ));
+
+    let read_config = ReadVertexConfigBuilder::default()
+        .name(graph_name)
+        .collection(from_coll)
+        .key(key)
+        .if_none_match("_aaaaaaaaa")
+        .build()?;
+    let read_vertex_meta = conn.read_vertex(read_config).await?.right_safe()?;
+    assert_eq!(read_vertex_meta.vertex().rev(), rev);
+
+    delete_random_graph(conn, rand_graph_meta).await
+}
+
 #[tokio::test]
 async fn graph_create_update_delete_vertex() -> Result<()> {
     let conn = &*RUARANGO_POOL.get()?;
@@ -654,3 +914,111 @@ async fn graph_create_replace_delete_vertex() -> Result<()> {
 
     delete_random_graph(conn, rand_graph_meta).await
 }
+
+#[tokio::test]
+async fn graph_traverse_one_hop() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let rand_graph_meta = create_random_graph(conn).await?;
+    let graph_name = rand_graph_meta.graph();
+    let edge_coll = rand_graph_meta.edge_coll();
+    let from_coll = rand_graph_meta.from_coll();
+    let to_coll = rand_graph_meta.to_coll();
+    let from_doc = create_random_document(conn, from_coll, TestDoc::default()).await?;
+    let to_doc = create_random_document(conn, to_coll, TestDoc::default()).await?;
+
+    let from_to = FromToBuilder::default()
+        .from(from_doc.id())
+        .to(to_doc.id())
+        .build()?;
+    let config = EdgeCreateConfigBuilder::default()
+        .graph(graph_name)
+        .collection(edge_coll)
+        .mapping(from_to)
+        .build()?;
+    let res = conn.create_edge(config).await?;
+    assert!(res.is_right());
+
+    let config = TraversalConfigBuilder::default()
+        .graph(graph_name)
+        .start_vertex(from_doc.id())
+        .direction(Direction::Outbound)
+        .build()?;
+    let res: ArangoEither<Vec<Path<Value, Value>>> = conn.traverse(config).await?;
+    assert!(res.is_right());
+    let paths = res.right_safe()?;
+    assert_eq!(paths.len(), 1);
+    let path = paths.first().unwrap();
+    assert_eq!(path.vertices().len(), 2);
+    assert_eq!(path.edges().len(), 1);
+    assert_eq!(path.vertices()[1]["_id"], to_doc.id().as_str());
+
+    delete_random_graph(conn, rand_graph_meta).await
+}
+
+#[tokio::test]
+async fn graph_shortest_path() -> Result<()> {
+    let conn = &*RUARANGO_POOL.get()?;
+    let (vertex_coll, _) = create_random_collection(conn, CollKind::Document).await?;
+    let (edge_coll, _) = create_random_collection(conn, CollKind::Edge).await?;
+    let graph_name = rand_name();
+    let ed = EdgeDefinitionBuilder::default()
+        .collection(edge_coll.clone())
+        .from(vec![vertex_coll.clone()])
+        .to(vec![vertex_coll.clone()])
+        .build()?;
+    let graph_meta = GraphMetaBuilder::default()
+        .name(graph_name.clone())
+        .edge_definitions(vec![ed])
+        .build()?;
+    let config = CreateConfigBuilder::default().graph(graph_meta).build()?;
+    let res = conn.create(config).await?;
+    assert!(res.is_right());
+
+    // a -> b -> c
+    let mut ids = vec![];
+    for _ in 0..3 {
+        let doc = create_random_document(conn, &vertex_coll, TestDoc::default()).await?;
+        ids.push(doc.id().clone());
+    }
+    for pair in ids.windows(2) {
+        let from_to = FromToBuilder::default()
+            .from(&pair[0])
+            .to(&pair[1])
+            .build()?;
+        let config = EdgeCreateConfigBuilder::default()
+            .graph(&graph_name)
+            .collection(&edge_coll)
+            .mapping(from_to)
+            .build()?;
+        let res = conn.create_edge(config).await?;
+        assert!(res.is_right());
+    }
+
+    let config = ShortestPathConfigBuilder::default()
+        .graph(&graph_name)
+        .start(&ids[0])
+        .target(&ids[2])
+        .build()?;
+    let res: ArangoEither<Option<Path<Value, Value>>> = conn.shortest_path(config).await?;
+    assert!(res.is_right());
+    let path_opt = res.right_safe()?;
+    assert!(path_opt.is_some());
+    let path = path_opt.unwrap();
+    assert_eq!(path.vertices().len(), 3);
+    assert_eq!(path.edges().len(), 2);
+
+    let config = ShortestPathConfigBuilder::default()
+        .graph(&graph_name)
+        .start(&ids[2])
+        .target(&ids[0])
+        .build()?;
+    let res: ArangoEither<Option<Path<Value, Value>>> = conn.shortest_path(config).await?;
+    assert!(res.is_right());
+    assert!(res.right_safe()?.is_none());
+
+    let delete_config = DeleteConfigBuilder::default().name(graph_name).build()?;
+    let res = Graph::delete(conn, delete_config).await?;
+    assert!(res.is_right());
+    delete_random_collection(conn, edge_coll).await?;
+    delete_random_collection(conn, vertex_coll).await
+}
@@ -1,5 +1,8 @@
+mod admin;
+mod analyzer;
 mod coll;
 mod cursor;
 mod db;
 mod doc;
 mod graph;
+mod view;
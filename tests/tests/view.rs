@@ -0,0 +1,49 @@
+// Copyright (c) 2021 ruarango developers
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `ruarango` view integration tests
+use crate::{common::process_sync_result, rand_util::rand_name};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use ruarango::{
+    view::{
+        input::{CreateConfig, CreateConfigBuilder, LinkBuilder},
+        ViewKind,
+    },
+    View,
+};
+use std::collections::HashMap;
+
+const TEST_COLL: &str = "test_coll";
+
+lazy_static! {
+    static ref VIEW_NAME: String = rand_name();
+}
+
+fn create_config() -> Result<CreateConfig> {
+    let link = LinkBuilder::default().include_all_fields(true).build()?;
+    let mut links = HashMap::new();
+    let _ = links.insert(TEST_COLL.to_string(), link);
+    Ok(CreateConfigBuilder::default()
+        .name(&*VIEW_NAME)
+        .links(links)
+        .build()?)
+}
+
+int_test_sync_new!(res; conn; view_create_properties_drop, create(create_config()?) => {
+    assert_eq!(res.name(), &*VIEW_NAME);
+    assert_eq!(*res.kind(), ViewKind::ArangoSearch);
+
+    let props = process_sync_result(conn.properties(&VIEW_NAME).await?)?;
+    assert_eq!(props.meta().name(), &*VIEW_NAME);
+    assert!(props.links().contains_key(TEST_COLL));
+
+    let res = process_sync_result(conn.drop(&VIEW_NAME).await?)?;
+    assert!(!res.error());
+    assert!(res.result());
+});